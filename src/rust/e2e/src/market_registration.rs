@@ -1,7 +1,7 @@
 use anyhow::{ensure, Result};
 use aptos_sdk::types::APTOS_COIN_TYPE;
 use e2e_proc_macro::e2e_test;
-use econia_sdk::entry::register_market_base_coin_from_coinstore;
+use econia_sdk::registration::{MarketRegistration, RegistrationOptions};
 use sqlx::query;
 
 use metadata::Metadata;
@@ -16,21 +16,23 @@ pub async fn test_market_registration<'a>(state: &'a State) -> Result<()> {
         .market_size
         .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-    let entry = register_market_base_coin_from_coinstore(
-        state.econia_address.clone(),
-        &state.e_apt,
-        &state.e_usdc,
-        &APTOS_COIN_TYPE,
+    let market = MarketRegistration {
+        base: state.e_apt.clone(),
+        quote: state.e_usdc.clone(),
+        utility_coin: APTOS_COIN_TYPE.clone(),
         lot_size,
         tick_size,
         min_size,
-    )
-    .unwrap();
+    };
 
     let (_, econia_client) =
         account(&state.faucet_client, &state.node_url, state.econia_address).await;
 
-    econia_client.submit_tx(entry).await?;
+    let report = econia_client
+        .register_market(&market, RegistrationOptions::default())
+        .await?;
+
+    ensure!(!report.was_ready(), "Market was already registered");
 
     std::thread::sleep(TIMEOUT);
 
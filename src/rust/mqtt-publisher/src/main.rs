@@ -2,103 +2,15 @@ use std::{collections::HashSet, sync::Arc, time::Duration};
 
 use anyhow::{anyhow, Result};
 use bigdecimal::ToPrimitive;
-use chrono::{DateTime, Utc};
+use econia_types::notif::{
+    CancelOrderNotif, ChangeOrderSizeNotif, FillNotif, PlaceLimitOrderNotif, PlaceMarketOrderNotif,
+    PlaceSwapOrderNotif, PriceLevelNotif,
+};
 use rumqttc::{AsyncClient, EventLoop, MqttOptions, QoS, Transport};
-use serde::{Deserialize, Serialize};
 use sqlx::{Executor, PgConnection};
 use sqlx_postgres::{PgListener, PgPool};
 use tokio::sync::RwLock;
 
-#[derive(Serialize, Deserialize)]
-struct PlaceLimitOrderNotif {
-    txn_version: u128,
-    event_idx: u128,
-    time: DateTime<Utc>,
-    market_id: u128,
-    user: String,
-    custodian_id: u128,
-    order_id: u128,
-    side: bool,
-    integrator: String,
-    initial_size: u128,
-    price: u128,
-    restriction: i16,
-    self_match_behavior: i16,
-    size: u128,
-}
-#[derive(Serialize, Deserialize)]
-struct PlaceMarketOrderNotif {
-    txn_version: u128,
-    event_idx: u128,
-    time: DateTime<Utc>,
-    market_id: u128,
-    user: String,
-    custodian_id: u128,
-    order_id: u128,
-    direction: bool,
-    integrator: String,
-    self_match_behavior: i16,
-    size: u128,
-}
-#[derive(Serialize, Deserialize)]
-struct PlaceSwapOrderNotif {
-    txn_version: u128,
-    event_idx: u128,
-    time: DateTime<Utc>,
-    market_id: u128,
-    order_id: u128,
-    direction: bool,
-    signing_account: String,
-    integrator: String,
-    min_base: u128,
-    max_base: u128,
-    min_quote: u128,
-    max_quote: u128,
-    limit_price: u128,
-}
-#[derive(Serialize, Deserialize)]
-struct ChangeOrderSizeNotif {
-    txn_version: u128,
-    event_idx: u128,
-    time: DateTime<Utc>,
-    market_id: u128,
-    user: String,
-    custodian_id: u128,
-    order_id: u128,
-    side: bool,
-    new_size: u128,
-}
-#[derive(Serialize, Deserialize)]
-struct CancelOrderNotif {
-    txn_version: u128,
-    event_idx: u128,
-    time: DateTime<Utc>,
-    market_id: u128,
-    user: String,
-    custodian_id: u128,
-    order_id: u128,
-    reason: i16,
-}
-#[derive(Serialize, Deserialize)]
-struct FillNotif {
-    txn_version: u128,
-    event_idx: u128,
-    emit_address: String,
-    time: DateTime<Utc>,
-    maker_address: String,
-    maker_custodian_id: u128,
-    maker_order_id: u128,
-    maker_side: bool,
-    market_id: u128,
-    price: u128,
-    sequence_number_for_trade: u128,
-    size: u128,
-    taker_address: String,
-    taker_custodian_id: u128,
-    taker_order_id: u128,
-    taker_quote_fees_paid: u128,
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     let mqtt_url = std::env::var("MQTT_URL")?;
@@ -133,13 +45,6 @@ async fn eventpoll_loop(mut eventloop: EventLoop) -> Result<()> {
     }
 }
 
-#[derive(Serialize)]
-struct PriceLevel {
-    price: u128,
-    size: u128,
-    txn_version: u128,
-}
-
 async fn price_level_loop(db_url: &str, mqtt_client: Arc<RwLock<AsyncClient>>) -> Result<()> {
     let pool = PgPool::connect(&db_url).await?;
 
@@ -165,7 +70,7 @@ async fn price_level_loop(db_url: &str, mqtt_client: Arc<RwLock<AsyncClient>>) -
                 row.direction.ok_or(anyhow!("direction is None"))?,
                 row.level.ok_or(anyhow!("level is None"))?
             );
-            let payload = PriceLevel {
+            let payload = PriceLevelNotif {
                 price: row
                     .price
                     .ok_or(anyhow!("price is None"))?
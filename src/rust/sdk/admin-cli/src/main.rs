@@ -0,0 +1,185 @@
+//! Registers Econia markets and gets them ready for trading, see the `registration` module of the
+//! Econia SDK.
+//!
+//! ```text
+//! # The keystore password is read from `ECONIA_KEYSTORE_PASSWORD` or prompted for.
+//! econia-admin --node-url $NODE --econia-address $ECONIA --keystore econia.json \
+//!     register-market --base $EAPT --quote $EUSDC --lot-size 100000 --tick-size 1000 \
+//!     --min-size 1 --recognize --init-event-handles --dry-run
+//! ```
+
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use aptos_sdk::move_types::language_storage::TypeTag;
+use aptos_sdk::types::account_address::AccountAddress;
+use clap::{Parser, Subcommand};
+use econia_sdk::registration::{MarketRegistration, MarketRegistrationReport, RegistrationOptions};
+use econia_sdk::signer::Keystore;
+use econia_sdk::view::MarketInfoView;
+use econia_sdk::EconiaClient;
+use reqwest::Url;
+
+const PASSWORD_VAR: &str = "ECONIA_KEYSTORE_PASSWORD";
+const APTOS_COIN: &str = "0x1::aptos_coin::AptosCoin";
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Url of the Aptos node.
+    #[arg(long)]
+    node_url: Url,
+    /// API key to use when connecting to the node.
+    #[arg(long)]
+    node_api_key: Option<String>,
+    /// Address of the account that holds the econia modules.
+    #[arg(long)]
+    econia_address: AccountAddress,
+    /// Keystore file holding the private key of the account sending the transactions.
+    #[arg(long)]
+    keystore: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Register a market unless one exists with the same parameters, then print it.
+    RegisterMarket {
+        #[arg(long, value_parser = TypeTag::from_str)]
+        base: TypeTag,
+        #[arg(long, value_parser = TypeTag::from_str)]
+        quote: TypeTag,
+        /// Utility coin the registration fee is paid in.
+        #[arg(long, value_parser = TypeTag::from_str, default_value = APTOS_COIN)]
+        utility_coin: TypeTag,
+        #[arg(long)]
+        lot_size: u64,
+        #[arg(long)]
+        tick_size: u64,
+        #[arg(long)]
+        min_size: u64,
+        /// Recognize the market, the keystore must hold the key of the Econia account.
+        #[arg(long)]
+        recognize: bool,
+        /// Initialize the market account event handles of the sender on the market.
+        #[arg(long)]
+        init_event_handles: bool,
+        /// Simulate the steps without submitting them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let account = Keystore::open(args.keystore, &password()?)?;
+    let client = EconiaClient::connect(
+        args.node_url,
+        args.node_api_key,
+        args.econia_address,
+        account,
+        None,
+    )
+    .await?;
+    match args.command {
+        Command::RegisterMarket {
+            base,
+            quote,
+            utility_coin,
+            lot_size,
+            tick_size,
+            min_size,
+            recognize,
+            init_event_handles,
+            dry_run,
+        } => {
+            let market = MarketRegistration {
+                base,
+                quote,
+                utility_coin,
+                lot_size,
+                tick_size,
+                min_size,
+            };
+            let options = RegistrationOptions {
+                recognize,
+                init_event_handles,
+                dry_run,
+            };
+            let report = client.register_market(&market, options).await?;
+            print_report(&report, dry_run);
+        }
+    }
+    Ok(())
+}
+
+/// Read the keystore password from [`PASSWORD_VAR`], or prompt for it on the terminal without
+/// echoing it.
+fn password() -> Result<String> {
+    if let Ok(password) = std::env::var(PASSWORD_VAR) {
+        return Ok(password);
+    }
+    let password = rpassword::prompt_password("keystore password: ")?;
+    if password.is_empty() {
+        return Err(anyhow!("no keystore password given"));
+    }
+    Ok(password)
+}
+
+fn print_report(report: &MarketRegistrationReport, dry_run: bool) {
+    if dry_run {
+        println!("dry run, nothing was submitted");
+    }
+    for step in &report.skipped {
+        println!("  already done:    {:?}", step);
+    }
+    for (step, transaction) in &report.submitted {
+        println!(
+            "  submitted:       {:?} in {} at version {}",
+            step, transaction.info.hash, transaction.info.version
+        );
+    }
+    if let Some(account) = &report.market_account {
+        for (step, transaction) in &account.submitted {
+            println!(
+                "  submitted:       {:?} in {} at version {}",
+                step, transaction.info.hash, transaction.info.version
+            );
+        }
+    }
+    for step in &report.planned {
+        match report.simulations.iter().find(|(s, _)| s == step) {
+            Some((_, simulation)) => println!(
+                "  would submit:    {:?}, estimated fee {} octas",
+                step,
+                simulation.estimated_fee()
+            ),
+            None => println!("  would submit:    {:?}, not simulated", step),
+        }
+    }
+    match &report.market {
+        Some(market) => print_market(market),
+        None => println!("market {} would be registered", report.market_id),
+    }
+}
+
+fn print_market(market: &MarketInfoView) {
+    println!("market {}", market.market_id);
+    println!(
+        "  base:            {}::{}::{}",
+        market.base_type.package_address, market.base_type.module_name, market.base_type.type_name
+    );
+    println!(
+        "  quote:           {}::{}::{}",
+        market.quote_type.package_address,
+        market.quote_type.module_name,
+        market.quote_type.type_name
+    );
+    println!("  lot size:        {}", market.lot_size);
+    println!("  tick size:       {}", market.tick_size);
+    println!("  min size:        {}", market.min_size);
+    println!("  recognized:      {}", market.is_recognized);
+}
@@ -0,0 +1,290 @@
+//! Reads the signatures of the entry functions of a Move package from the ABIs compiled with it,
+//! `build/<package>/abis/<module>/<function>.abi`, as written by
+//! `aptos move compile --save-metadata`.
+//!
+//! The ABIs are BCS encoded `EntryABI`s of the Move `abi` module. Signer parameters are left out
+//! of them, as they are passed by the transaction sender.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::parse::{Function, Kind, MoveType};
+
+const ENTRY_FUNCTION_ABI: u64 = 1;
+
+/// Read the entry function ABIs of the package built in `package_dir`.
+pub fn read_package(package_dir: &Path) -> Result<Vec<Function>> {
+    let build = package_dir.join("build");
+    let mut abi_dirs = std::fs::read_dir(&build)
+        .with_context(|| format!("reading {}", build.display()))?
+        .map(|entry| entry.map(|e| e.path().join("abis")))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    abi_dirs.retain(|dir| dir.is_dir());
+    let [abi_dir] = abi_dirs.as_slice() else {
+        bail!(
+            "expected the ABIs of a single package under {}, compile it with `--save-metadata`",
+            build.display()
+        );
+    };
+
+    let mut paths = vec![];
+    abi_files(abi_dir, &mut paths)?;
+    paths.sort();
+    let mut functions = vec![];
+    for path in paths {
+        let bytes = std::fs::read(&path)?;
+        if let Some(function) =
+            decode_abi(&bytes).with_context(|| format!("decoding {}", path.display()))?
+        {
+            functions.push(function);
+        }
+    }
+    Ok(functions)
+}
+
+fn abi_files(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            abi_files(&path, paths)?;
+        } else if path.extension().is_some_and(|e| e == "abi") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Decode an `EntryABI`, `None` for the ABI of a script.
+fn decode_abi(bytes: &[u8]) -> Result<Option<Function>> {
+    let mut reader = Reader { bytes };
+    if reader.uleb128()? != ENTRY_FUNCTION_ABI {
+        return Ok(None);
+    }
+    let name = reader.string()?;
+    reader.address()?;
+    let module = reader.string()?;
+    reader.string()?; // Doc comment.
+    let type_params = reader.seq(|r| r.string())?;
+    let params = reader.seq(|r| Ok((r.string()?, r.type_tag()?)))?;
+    if !reader.bytes.is_empty() {
+        bail!("trailing bytes after the ABI of `{}`", name);
+    }
+    Ok(Some(Function {
+        module,
+        name,
+        kind: Kind::Entry,
+        // ABIs do not record visibility, and the Move docs cover every function anyway.
+        public: true,
+        type_params,
+        params,
+        returns: vec![],
+    }))
+}
+
+/// Reads BCS values from the front of a byte slice.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < n {
+            bail!("unexpected end of ABI");
+        }
+        let (taken, rest) = self.bytes.split_at(n);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn uleb128(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("invalid ULEB128 length")
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.uleb128()?;
+        let bytes = self.take(usize::try_from(len)?)?;
+        Ok(String::from_utf8(bytes.to_vec())?)
+    }
+
+    fn address(&mut self) -> Result<&'a [u8]> {
+        self.take(32)
+    }
+
+    fn seq<T>(&mut self, mut item: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        let len = self.uleb128()?;
+        (0..len).map(|_| item(self)).collect()
+    }
+
+    /// Decode a `TypeTag`. Structs are named without their address and module, as in sources.
+    fn type_tag(&mut self) -> Result<MoveType> {
+        Ok(match self.uleb128()? {
+            0 => MoveType::Bool,
+            1 => MoveType::U8,
+            2 => MoveType::U64,
+            3 => MoveType::U128,
+            4 => MoveType::Address,
+            5 => MoveType::Signer,
+            6 => MoveType::Vector(Box::new(self.type_tag()?)),
+            7 => {
+                self.address()?;
+                self.string()?; // Module.
+                let name = self.string()?;
+                let args = self.seq(|r| r.type_tag())?;
+                MoveType::Struct { name, args }
+            }
+            8 => MoveType::U16,
+            9 => MoveType::U32,
+            10 => MoveType::U256,
+            tag => return Err(anyhow!("unknown type tag {}", tag)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// BCS encoding of the ABIs, as written by the Move compiler.
+    #[derive(Default)]
+    struct Writer(Vec<u8>);
+
+    impl Writer {
+        fn uleb128(mut self, mut value: u64) -> Self {
+            while value >= 0x80 {
+                self.0.push((value as u8 & 0x7f) | 0x80);
+                value >>= 7;
+            }
+            self.0.push(value as u8);
+            self
+        }
+
+        fn string(self, s: &str) -> Self {
+            let mut writer = self.uleb128(s.len() as u64);
+            writer.0.extend_from_slice(s.as_bytes());
+            writer
+        }
+
+        fn address(mut self, last_byte: u8) -> Self {
+            self.0.extend_from_slice(&[0; 31]);
+            self.0.push(last_byte);
+            self
+        }
+    }
+
+    fn entry_abi(module: &str, name: &str, type_params: &[&str], params: Writer) -> Vec<u8> {
+        let mut writer = Writer::default()
+            .uleb128(ENTRY_FUNCTION_ABI)
+            .string(name)
+            .address(0xc0)
+            .string(module)
+            .string("Doc comment.")
+            .uleb128(type_params.len() as u64);
+        for type_param in type_params {
+            writer = writer.string(type_param);
+        }
+        writer.0.extend(params.0);
+        writer.0
+    }
+
+    #[test]
+    fn decode_entry_function() {
+        let params = Writer::default()
+            .uleb128(4)
+            .string("market_id")
+            .uleb128(2)
+            .string("side")
+            .uleb128(0)
+            .string("amounts")
+            .uleb128(6)
+            .uleb128(8)
+            .string("name")
+            .uleb128(7)
+            .address(1)
+            .string("string")
+            .string("String")
+            .uleb128(0);
+        let abi = entry_abi(
+            "market",
+            "place_limit_order_user_entry",
+            &["BaseType", "QuoteType"],
+            params,
+        );
+
+        let function = decode_abi(&abi).unwrap().unwrap();
+        assert_eq!(function.module, "market");
+        assert_eq!(function.name, "place_limit_order_user_entry");
+        assert_eq!(function.kind, Kind::Entry);
+        assert_eq!(function.type_params, ["BaseType", "QuoteType"]);
+        let params = function
+            .params
+            .iter()
+            .map(|(name, ty)| format!("{}: {}", name, ty))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            [
+                "market_id: u64",
+                "side: bool",
+                "amounts: vector<u16>",
+                "name: String"
+            ]
+        );
+    }
+
+    #[test]
+    fn skip_scripts() {
+        let abi = Writer::default().uleb128(0).string("script").0;
+        assert!(decode_abi(&abi).unwrap().is_none());
+    }
+
+    #[test]
+    fn reject_invalid_abis() {
+        let params = Writer::default().uleb128(1).string("market_id").uleb128(2);
+        let abi = entry_abi("market", "cancel_all_orders_user", &[], params);
+        assert!(decode_abi(&abi).is_ok());
+
+        let truncated = &abi[..abi.len() - 1];
+        assert!(decode_abi(truncated).is_err());
+        let mut trailing = abi.clone();
+        trailing.push(0);
+        assert!(decode_abi(&trailing).is_err());
+
+        let params = Writer::default().uleb128(1).string("market_id").uleb128(11);
+        let unknown_type = entry_abi("market", "cancel_all_orders_user", &[], params);
+        assert!(decode_abi(&unknown_type).is_err());
+    }
+
+    #[test]
+    fn read_single_package() {
+        let dir = std::env::temp_dir().join(format!("econia-codegen-abis-{}", std::process::id()));
+        let module_dir = dir.join("build").join("Econia").join("abis").join("user");
+        std::fs::create_dir_all(&module_dir).unwrap();
+        std::fs::create_dir_all(dir.join("build").join("Econia").join("sources")).unwrap();
+        let params = Writer::default().uleb128(1).string("market_id").uleb128(2);
+        std::fs::write(
+            module_dir.join("register_market_account.abi"),
+            entry_abi("user", "register_market_account", &["BaseType"], params),
+        )
+        .unwrap();
+        let functions = read_package(&dir);
+
+        // A second package with ABIs is ambiguous.
+        std::fs::create_dir_all(dir.join("build").join("Other").join("abis")).unwrap();
+        let ambiguous = read_package(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let functions = functions.unwrap();
+        assert_eq!(functions.len(), 1);
+        assert_eq!(functions[0].name, "register_market_account");
+        assert!(ambiguous.is_err());
+    }
+}
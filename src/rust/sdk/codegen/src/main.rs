@@ -0,0 +1,123 @@
+//! Generates the entry and view function builders of the Econia SDK from the Econia Move
+//! package, so that the SDK covers every function the package exposes.
+//!
+//! Entry functions are read from the ABIs compiled with the package, for which the package is
+//! compiled with the Aptos CLI first. View functions are read from its sources, as ABIs are not
+//! compiled for them.
+//!
+//! The generated files are checked in under `sdk/src/generated`. Run with `--check` to verify
+//! that they are up to date instead of writing them.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+
+mod abi;
+mod parse;
+mod render;
+
+/// Address the package is compiled at, the generated builders take the address as an argument.
+const ECONIA_ADDRESS: &str = "0xc0deb00c";
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Directory of the Econia Move package.
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../../../move/econia"))]
+    package_dir: PathBuf,
+
+    /// Directory to write the generated files to.
+    #[arg(long, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/generated"))]
+    out_dir: PathBuf,
+
+    /// Path to the `aptos` executable, used to compile the package.
+    #[arg(long, default_value = "aptos")]
+    aptos_cli: PathBuf,
+
+    /// Read the ABIs of a previous compilation of the package instead of compiling it.
+    #[arg(long)]
+    skip_compile: bool,
+
+    /// Fail if the generated files differ from the ones in the output directory instead of
+    /// writing them.
+    #[arg(long)]
+    check: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if !args.skip_compile {
+        compile(&args.aptos_cli, &args.package_dir)?;
+    }
+    let mut functions = abi::read_package(&args.package_dir)?;
+    functions.extend(parse::parse_package(&args.package_dir)?);
+    let files = [
+        ("entry.rs", render::entry_file(&functions)?),
+        ("view.rs", render::view_file(&functions)?),
+    ];
+
+    let mut stale = vec![];
+    for (name, source) in files {
+        let source = rustfmt(&source)?;
+        let path = args.out_dir.join(name);
+        if args.check {
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(source.as_str()) {
+                stale.push(path);
+            }
+        } else {
+            std::fs::write(&path, source).with_context(|| format!("writing {}", path.display()))?;
+        }
+    }
+    if !stale.is_empty() {
+        let stale = stale
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>();
+        bail!(
+            "generated files are out of date, run `cargo run -p econia-sdk-codegen`: {}",
+            stale.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Compile the package with its ABIs, into its `build` directory.
+fn compile(aptos_cli: &Path, package_dir: &Path) -> Result<()> {
+    let output = Command::new(aptos_cli)
+        .args(["move", "compile", "--save-metadata", "--package-dir"])
+        .arg(package_dir)
+        .arg("--named-addresses")
+        .arg(format!("econia={}", ECONIA_ADDRESS))
+        .output()
+        .with_context(|| format!("running {}", aptos_cli.display()))?;
+    if !output.status.success() {
+        bail!(
+            "compiling the package failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(())
+}
+
+/// Format `source` with `rustfmt`, which must be installed.
+fn rustfmt(source: &str) -> Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("running rustfmt")?;
+    child
+        .stdin
+        .take()
+        .ok_or_else(|| anyhow!("rustfmt stdin unavailable"))?
+        .write_all(source.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!("rustfmt failed on the generated source");
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
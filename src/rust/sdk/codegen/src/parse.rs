@@ -0,0 +1,456 @@
+//! Extracts the signatures of the view functions of a Move package from its sources.
+//!
+//! Entry functions are read from the compiled ABIs instead, see [`abi`](crate::abi). ABIs are only
+//! compiled for entry functions, and do not describe return types.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context, Result};
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Entry,
+    View,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    U256,
+    Address,
+    Signer,
+    Reference(Box<MoveType>),
+    Vector(Box<MoveType>),
+    Struct { name: String, args: Vec<MoveType> },
+    Param(String),
+}
+
+impl fmt::Display for MoveType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::U8 => write!(f, "u8"),
+            Self::U16 => write!(f, "u16"),
+            Self::U32 => write!(f, "u32"),
+            Self::U64 => write!(f, "u64"),
+            Self::U128 => write!(f, "u128"),
+            Self::U256 => write!(f, "u256"),
+            Self::Address => write!(f, "address"),
+            Self::Signer => write!(f, "signer"),
+            Self::Reference(to) => write!(f, "&{}", to),
+            Self::Vector(items) => write!(f, "vector<{}>", items),
+            Self::Struct { name, args } if args.is_empty() => write!(f, "{}", name),
+            Self::Struct { name, args } => {
+                let args = args.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                write!(f, "{}<{}>", name, args.join(", "))
+            }
+            Self::Param(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl MoveType {
+    fn parse(s: &str, type_params: &[String]) -> Result<Self> {
+        let s = s.trim();
+        if let Some(to) = s.strip_prefix("&mut ").or_else(|| s.strip_prefix('&')) {
+            return Ok(Self::Reference(Box::new(Self::parse(to, type_params)?)));
+        }
+        let (name, args) = match s.find('<') {
+            Some(i) => {
+                let inner = s[i + 1..]
+                    .strip_suffix('>')
+                    .ok_or_else(|| anyhow!("unbalanced type `{}`", s))?;
+                (s[..i].trim(), split_top_level(inner))
+            }
+            None => (s, vec![]),
+        };
+        let args = args
+            .iter()
+            .map(|a| Self::parse(a, type_params))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(match (name, args.len()) {
+            ("bool", 0) => Self::Bool,
+            ("u8", 0) => Self::U8,
+            ("u16", 0) => Self::U16,
+            ("u32", 0) => Self::U32,
+            ("u64", 0) => Self::U64,
+            ("u128", 0) => Self::U128,
+            ("u256", 0) => Self::U256,
+            ("address", 0) => Self::Address,
+            ("signer", 0) => Self::Signer,
+            ("vector", 1) => Self::Vector(Box::new(args[0].clone())),
+            (name, 0) if type_params.iter().any(|p| p == name) => Self::Param(name.to_string()),
+            (name, _) => Self::Struct {
+                name: name.to_string(),
+                args,
+            },
+        })
+    }
+
+    /// Whether the type is a signer, which is passed by the transaction sender.
+    pub fn is_signer(&self) -> bool {
+        match self {
+            Self::Signer => true,
+            Self::Reference(to) => to.is_signer(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub module: String,
+    pub name: String,
+    pub kind: Kind,
+    /// Whether the function is public, only public functions are sure to be in the package docs.
+    pub public: bool,
+    pub type_params: Vec<String>,
+    pub params: Vec<(String, MoveType)>,
+    pub returns: Vec<MoveType>,
+}
+
+/// Parse the view functions of every `.move` file of the `sources` directory of a package.
+pub fn parse_package(package_dir: &Path) -> Result<Vec<Function>> {
+    let sources = package_dir.join("sources");
+    let mut paths = std::fs::read_dir(&sources)
+        .with_context(|| format!("reading {}", sources.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    paths.retain(|p| p.extension().is_some_and(|e| e == "move"));
+    paths.sort();
+
+    let mut functions = vec![];
+    for path in paths {
+        let source = std::fs::read_to_string(&path)?;
+        functions
+            .extend(parse_module(&source).with_context(|| format!("parsing {}", path.display()))?);
+    }
+    Ok(functions)
+}
+
+fn parse_module(source: &str) -> Result<Vec<Function>> {
+    let module_re = Regex::new(r"module\s+\w+::(\w+)")?;
+    let function_re = Regex::new(r"^(public(\(friend\))?\s+)?(entry\s+)?fun\s+(\w+)")?;
+    let Some(module) = module_re.captures(source) else {
+        return Ok(vec![]);
+    };
+    let module = module[1].to_string();
+
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut functions = vec![];
+    let mut attributes: Vec<&str> = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim();
+        if line.starts_with("#[") {
+            attributes.push(line);
+            continue;
+        }
+        if line.starts_with("//") {
+            continue;
+        }
+        let attrs = std::mem::take(&mut attributes);
+        let Some(captures) = function_re.captures(line) else {
+            continue;
+        };
+        let Some(name) = captures.get(4) else {
+            continue;
+        };
+        if attrs.iter().any(|a| a.contains("test")) || !attrs.contains(&"#[view]") {
+            continue;
+        }
+
+        let signature = take_signature(&line[name.end()..], &lines[i + 1..])
+            .ok_or_else(|| anyhow!("no body found for `{}`", name.as_str()))?;
+        let mut function = parse_signature(&module, name.as_str(), &signature)?;
+        function.public = captures.get(1).is_some();
+        functions.push(function);
+    }
+    Ok(functions)
+}
+
+/// The signature of a function, from the end of its name up to the opening brace of its body,
+/// which may span several lines. Comments are left out.
+fn take_signature(first_line: &str, lines: &[&str]) -> Option<String> {
+    let mut signature = String::new();
+    for line in std::iter::once(first_line).chain(lines.iter().copied()) {
+        let line = line.split_once("//").map_or(line, |(code, _)| code);
+        if let Some(end) = line.find('{') {
+            signature.push_str(&line[..end]);
+            return Some(signature);
+        }
+        signature.push_str(line);
+        signature.push(' ');
+    }
+    None
+}
+
+/// Parse a signature, as returned by [`take_signature`].
+fn parse_signature(module: &str, name: &str, signature: &str) -> Result<Function> {
+    let rest = signature.trim_start();
+    let (type_params, rest) = if rest.starts_with('<') {
+        let (inner, rest) = take_delimited(rest, '<', '>')?;
+        let type_params = split_top_level(inner)
+            .iter()
+            .map(|p| {
+                p.split_once(':')
+                    .map_or(p.as_str(), |(p, _)| p)
+                    .trim()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        (type_params, rest)
+    } else {
+        (vec![], rest)
+    };
+
+    let (inner, rest) = take_delimited(rest.trim_start(), '(', ')')?;
+    let params = split_top_level(inner)
+        .iter()
+        .map(|p| {
+            let (name, ty) = p
+                .split_once(':')
+                .ok_or_else(|| anyhow!("invalid parameter `{}` of `{}`", p, name))?;
+            Ok((name.trim().to_string(), MoveType::parse(ty, &type_params)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let rest = rest.trim_start();
+    let returns = match rest.strip_prefix(':') {
+        Some(returns) => {
+            let returns = returns
+                .split_once(" acquires")
+                .map_or(returns, |(returns, _)| returns)
+                .trim();
+            let returns = match returns.strip_prefix('(') {
+                Some(tuple) => split_top_level(
+                    tuple
+                        .strip_suffix(')')
+                        .ok_or_else(|| anyhow!("unbalanced return type of `{}`", name))?,
+                ),
+                None => vec![returns.to_string()],
+            };
+            returns
+                .iter()
+                .map(|r| MoveType::parse(r, &type_params))
+                .collect::<Result<Vec<_>>>()?
+        }
+        None => vec![],
+    };
+
+    Ok(Function {
+        module: module.to_string(),
+        name: name.to_string(),
+        kind: Kind::View,
+        public: false,
+        type_params,
+        params,
+        returns,
+    })
+}
+
+/// Split `s` starting with `open` at its matching `close`, returning the content in between and
+/// the remainder after `close`.
+fn take_delimited(s: &str, open: char, close: char) -> Result<(&str, &str)> {
+    if !s.starts_with(open) {
+        bail!("expected `{}` in `{}`", open, s);
+    }
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Ok((&s[1..i], &s[i + 1..]));
+            }
+        }
+    }
+    bail!("unbalanced `{}` in `{}`", open, s)
+}
+
+/// Split a comma separated list, ignoring commas nested in angle brackets and empty items.
+fn split_top_level(s: &str) -> Vec<String> {
+    let mut items = vec![];
+    let mut depth = 0;
+    let mut item = String::new();
+    for c in s.chars() {
+        match c {
+            '<' | '(' => depth += 1,
+            '>' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(std::mem::take(&mut item));
+                continue;
+            }
+            _ => {}
+        }
+        item.push(c);
+    }
+    items.push(item);
+    items
+        .into_iter()
+        .map(|i| i.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|i| !i.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(body: &str) -> Result<Vec<Function>> {
+        parse_module(&format!("module econia::market {{\n{}\n}}", body))
+    }
+
+    fn strukt(name: &str, args: Vec<MoveType>) -> MoveType {
+        MoveType::Struct {
+            name: name.to_string(),
+            args,
+        }
+    }
+
+    #[test]
+    fn parse_view_functions() {
+        let functions = parse(
+            r#"
+    #[view]
+    /// Return a `MarketEventHandleCreationInfo` for `market_id`, if
+    /// Econia resource account has event handles for indicated market.
+    fun get_market_event_handle_creation_info(
+        market_id: u64
+    ): Option<MarketEventHandleCreationInfo>
+    acquires MarketEventHandles {
+        option::none()
+    }
+
+    #[view]
+    public fun get_open_orders_paginated(
+        market_id: u64,
+        n_asks_to_index_max: u64, // Comment with a `{`.
+        starting_ask_order_id: u128,
+    ): (
+        OrdersView,
+        u128,
+        u128,
+    ) acquires OrderBooks {
+        verify_pagination_order_ids( // Verify order IDs.
+            market_id, starting_ask_order_id, starting_bid_order_id);
+    }
+
+    #[view]
+    public fun get_MAX_PRICE(): u64 { MAX_PRICE }
+
+    public fun get_TICKS(): bool {TICKS}"#,
+        )
+        .unwrap();
+        let names = functions
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "get_market_event_handle_creation_info",
+                "get_open_orders_paginated",
+                "get_MAX_PRICE"
+            ]
+        );
+        assert!(functions.iter().all(|f| f.kind == Kind::View));
+
+        assert!(!functions[0].public);
+        assert_eq!(
+            functions[0].returns,
+            [strukt(
+                "Option",
+                vec![strukt("MarketEventHandleCreationInfo", vec![])]
+            )]
+        );
+        assert_eq!(functions[1].params.len(), 3);
+        assert_eq!(
+            functions[1].returns,
+            [strukt("OrdersView", vec![]), MoveType::U128, MoveType::U128]
+        );
+        assert_eq!(functions[2].returns, [MoveType::U64]);
+    }
+
+    #[test]
+    fn parse_type_params() {
+        let functions = parse(
+            r#"
+    #[view]
+    public fun get_integrator_fee_store_amounts<
+        QuoteCoinType: store,
+        UtilityCoinType
+    >(
+        integrator: &signer,
+        market_id: u64,
+        amounts: vector<vector<Coin<QuoteCoinType>>>,
+    ): u64 {
+    }"#,
+        )
+        .unwrap();
+        let function = &functions[0];
+        assert_eq!(function.type_params, ["QuoteCoinType", "UtilityCoinType"]);
+        assert!(function.params[0].1.is_signer());
+        assert_eq!(
+            function.params[2].1,
+            MoveType::Vector(Box::new(MoveType::Vector(Box::new(strukt(
+                "Coin",
+                vec![MoveType::Param("QuoteCoinType".to_string())]
+            )))))
+        );
+    }
+
+    #[test]
+    fn skip_test_entry_and_internal_functions() {
+        let functions = parse(
+            r#"
+    #[test_only]
+    #[view]
+    public fun get_test_info(): u64 {
+    }
+
+    public entry fun cancel_all_orders_user(
+        user: &signer,
+        market_id: u64,
+        side: bool,
+    ) {
+    }
+
+    #[test]
+    #[expected_failure(abort_code = E_MIN_BASE_NOT_TRADED)]
+    /// Verify failure for minimum base amount not traded.
+    fun test_match_min_base_not_traded()
+    acquires
+        MarketEventHandles,
+        OrderBooks
+    {
+    }
+
+    public(friend) fun get_market_info_for_market_account(
+        market_id: u64,
+    ): (u64, u64) {
+    }"#,
+        )
+        .unwrap();
+        assert!(functions.is_empty());
+    }
+
+    #[test]
+    fn reject_invalid_signatures() {
+        for body in [
+            "#[view]\npublic fun no_body(market_id: u64): u64",
+            "#[view]\npublic fun unbalanced<T(market_id: u64): u64 {}",
+            "#[view]\npublic fun no_type(market_id): u64 {}",
+            "#[view]\npublic fun unbalanced_returns(): (u64, u64 {}",
+        ] {
+            assert!(parse(body).is_err(), "{}", body);
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Renders the builders of the entry and view functions as Rust source, formatted by `main`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use crate::parse::{Function, Kind, MoveType};
+
+const DOC_URL: &str = "https://github.com/econia-labs/econia/blob/main/src/move/econia/doc";
+
+/// Render `generated/entry.rs`, with a builder per entry function.
+pub fn entry_file(functions: &[Function]) -> Result<String> {
+    let mut out = header("Entry function builders");
+    out.push_str(
+        "use aptos_sdk::bcs;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::ident_str;
+use aptos_sdk::move_types::language_storage::TypeTag;
+use aptos_sdk::types::transaction::EntryFunction;
+
+use super::{module_id, EntrySignature, MoveArgType};
+use crate::EconiaResult;
+",
+    );
+    let modules = by_module(functions, Kind::Entry);
+    for (module, functions) in &modules {
+        writeln!(out, "\npub mod {} {{\nuse super::*;", module)?;
+        for function in functions {
+            out.push('\n');
+            entry_function(&mut out, function)?;
+        }
+        out.push_str("}\n");
+    }
+    out.push_str(
+        "\n/// Signature of every entry function, to decode the arguments of a payload.
+pub const ENTRY_SIGNATURES: &[EntrySignature] = &[\n",
+    );
+    for (module, functions) in &modules {
+        for function in functions {
+            entry_signature(&mut out, module, function)?;
+        }
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
+
+/// Render `generated/view.rs`, with a request builder per view function.
+pub fn view_file(functions: &[Function]) -> Result<String> {
+    let mut out = header("View function request builders");
+    out.push_str(
+        "use aptos_api_types::{MoveType, ViewRequest};
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::language_storage::TypeTag;
+use serde_json::json;
+
+use super::function_id;
+use crate::EconiaResult;
+",
+    );
+    for (module, functions) in by_module(functions, Kind::View) {
+        writeln!(out, "\npub mod {} {{\nuse super::*;", module)?;
+        for function in functions {
+            out.push('\n');
+            view_function(&mut out, function)?;
+        }
+        out.push_str("}\n");
+    }
+    Ok(out)
+}
+
+fn header(title: &str) -> String {
+    format!(
+        "//! {} of the Econia package, grouped by Move module.
+//!
+//! This file is generated by `econia-sdk-codegen` from the `src/move/econia` package, do not
+//! edit it by hand. To regenerate it, run `cargo run -p econia-sdk-codegen` from `src/rust`.
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+",
+        title
+    )
+}
+
+fn by_module(functions: &[Function], kind: Kind) -> BTreeMap<&str, Vec<&Function>> {
+    let mut modules = BTreeMap::<_, Vec<_>>::new();
+    for function in functions.iter().filter(|f| f.kind == kind) {
+        modules
+            .entry(function.module.as_str())
+            .or_default()
+            .push(function);
+    }
+    for functions in modules.values_mut() {
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+    modules
+}
+
+fn entry_function(out: &mut String, function: &Function) -> Result<()> {
+    let params = value_params(function);
+    writeln!(out, "/// Create the `EntryFunction` for {}", link(function))?;
+    doc_arguments(out, function, &params)?;
+
+    let mut args = vec!["econia_address: AccountAddress".to_string()];
+    for type_param in &function.type_params {
+        args.push(format!("{}: &TypeTag", snake_case(type_param)));
+    }
+    for (name, ty) in &params {
+        args.push(format!("{}: {}", name, rust_type(ty)?));
+    }
+    let type_args = function
+        .type_params
+        .iter()
+        .map(|p| format!("{}.clone()", snake_case(p)))
+        .collect::<Vec<_>>();
+    let bcs_args = params
+        .iter()
+        .map(|(name, _)| format!("bcs::to_bytes(&{})?", name))
+        .collect::<Vec<_>>();
+    writeln!(
+        out,
+        "pub fn {}({}) -> EconiaResult<EntryFunction> {{
+Ok(EntryFunction::new(
+module_id(econia_address, \"{}\")?,
+ident_str!(\"{}\").to_owned(),
+vec![{}],
+vec![{}],
+))
+}}",
+        function.name,
+        args.join(", "),
+        function.module,
+        function.name,
+        type_args.join(", "),
+        bcs_args.join(", "),
+    )?;
+    Ok(())
+}
+
+fn entry_signature(out: &mut String, module: &str, function: &Function) -> Result<()> {
+    let params = value_params(function)
+        .iter()
+        .map(|(name, ty)| Ok(format!("(\"{}\", {})", name, arg_type(ty)?)))
+        .collect::<Result<Vec<_>>>()?;
+    writeln!(
+        out,
+        "EntrySignature {{ module: \"{}\", name: \"{}\", params: &[{}] }},",
+        module,
+        function.name,
+        params.join(", ")
+    )?;
+    Ok(())
+}
+
+fn view_function(out: &mut String, function: &Function) -> Result<()> {
+    let params = value_params(function);
+    writeln!(out, "/// Create the `ViewRequest` for {}", link(function))?;
+    doc_arguments(out, function, &params)?;
+    let returns = function
+        .returns
+        .iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>();
+    match returns.len() {
+        0 => {}
+        1 => writeln!(out, "///\n/// Returns `{}`.", returns[0])?,
+        _ => writeln!(out, "///\n/// Returns `({})`.", returns.join(", "))?,
+    }
+
+    let mut args = vec!["econia_address: AccountAddress".to_string()];
+    for type_param in &function.type_params {
+        args.push(format!("{}: &TypeTag", snake_case(type_param)));
+    }
+    for (name, ty) in &params {
+        args.push(format!("{}: {}", name, rust_type(ty)?));
+    }
+    let type_args = function
+        .type_params
+        .iter()
+        .map(|p| format!("MoveType::from({})", snake_case(p)))
+        .collect::<Vec<_>>();
+    let json_args = params
+        .iter()
+        .map(|(name, ty)| json_arg(name, ty))
+        .collect::<Result<Vec<_>>>()?;
+    writeln!(
+        out,
+        "pub fn {}({}) -> EconiaResult<ViewRequest> {{
+Ok(ViewRequest {{
+function: function_id(econia_address, \"{}\", \"{}\")?,
+type_arguments: vec![{}],
+arguments: vec![{}],
+}})
+}}",
+        function.name,
+        args.join(", "),
+        function.module,
+        function.name,
+        type_args.join(", "),
+        json_args.join(", "),
+    )?;
+    Ok(())
+}
+
+/// Parameters passed as arguments, signers are passed by the transaction sender instead.
+fn value_params(function: &Function) -> Vec<&(String, MoveType)> {
+    function
+        .params
+        .iter()
+        .filter(|(_, ty)| !ty.is_signer())
+        .collect()
+}
+
+/// Link to the documentation of a public function, private functions may not be documented.
+fn link(function: &Function) -> String {
+    if function.public {
+        format!(
+            "[{name}]({}/{module}.md#0xc0deb00c_{module}_{name})",
+            DOC_URL,
+            name = function.name,
+            module = function.module,
+        )
+    } else {
+        format!("`{}`", function.name)
+    }
+}
+
+fn doc_arguments(
+    out: &mut String,
+    function: &Function,
+    params: &[&(String, MoveType)],
+) -> Result<()> {
+    out.push_str(
+        "///
+/// Arguments:
+/// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+",
+    );
+    for type_param in &function.type_params {
+        writeln!(
+            out,
+            "/// * `{}`: Aptos `TypeTag` for the `{}` type parameter.",
+            snake_case(type_param),
+            type_param
+        )?;
+    }
+    for (name, ty) in params {
+        writeln!(out, "/// * `{}`: Move argument of type `{}`.", name, ty)?;
+    }
+    Ok(())
+}
+
+/// Rust type of an argument, serialized by BCS for entry functions and as JSON for view
+/// functions.
+fn rust_type(ty: &MoveType) -> Result<String> {
+    Ok(match ty {
+        MoveType::Bool => "bool".to_string(),
+        MoveType::U8 => "u8".to_string(),
+        MoveType::U16 => "u16".to_string(),
+        MoveType::U32 => "u32".to_string(),
+        MoveType::U64 => "u64".to_string(),
+        MoveType::U128 => "u128".to_string(),
+        MoveType::Address => "AccountAddress".to_string(),
+        MoveType::Vector(items) => format!("Vec<{}>", rust_type(items)?),
+        MoveType::Struct { name, args } if name == "String" && args.is_empty() => {
+            "String".to_string()
+        }
+        ty => bail!("unsupported argument type `{}`", ty),
+    })
+}
+
+/// `MoveArgType` of an entry function argument, the types supported by [`rust_type`].
+fn arg_type(ty: &MoveType) -> Result<String> {
+    Ok(match ty {
+        MoveType::Bool => "MoveArgType::Bool".to_string(),
+        MoveType::U8 => "MoveArgType::U8".to_string(),
+        MoveType::U16 => "MoveArgType::U16".to_string(),
+        MoveType::U32 => "MoveArgType::U32".to_string(),
+        MoveType::U64 => "MoveArgType::U64".to_string(),
+        MoveType::U128 => "MoveArgType::U128".to_string(),
+        MoveType::Address => "MoveArgType::Address".to_string(),
+        MoveType::Vector(items) => format!("MoveArgType::Vector(&{})", arg_type(items)?),
+        MoveType::Struct { name, args } if name == "String" && args.is_empty() => {
+            "MoveArgType::String".to_string()
+        }
+        ty => bail!("unsupported argument type `{}`", ty),
+    })
+}
+
+/// JSON encoding of a view function argument, integers wider than 32 bits are strings.
+fn json_arg(name: &str, ty: &MoveType) -> Result<String> {
+    Ok(match ty {
+        MoveType::Bool | MoveType::U8 | MoveType::U16 | MoveType::U32 => {
+            format!("json!({})", name)
+        }
+        MoveType::Struct { name: ty, args } if ty == "String" && args.is_empty() => {
+            format!("json!({})", name)
+        }
+        MoveType::U64 | MoveType::U128 | MoveType::Address => {
+            format!("json!({}.to_string())", name)
+        }
+        ty => bail!("unsupported view argument type `{}`", ty),
+    })
+}
+
+/// `BaseType` to `base_type`.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
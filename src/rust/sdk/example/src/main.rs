@@ -11,11 +11,14 @@ use aptos_sdk::{
         account_address::AccountAddress, transaction::EntryFunction, LocalAccount, APTOS_COIN_TYPE,
     },
 };
+use bigdecimal::BigDecimal;
 use clap::Parser;
 use econia_sdk::{
     entry::*,
     errors::EconiaError,
+    registration::{MarketRegistration, RegistrationOptions},
     types::order::{Restriction, SelfMatchBehavior, Side},
+    units::MarketUnits,
     view::{EconiaViewClient, PriceLevel},
     EconiaClient, EconiaResult,
 };
@@ -326,22 +329,28 @@ async fn main() -> EconiaResult<()> {
 
     print_title!("Create a market for eAPT/eUSDC");
 
-    let lot_size = 10u64.pow(8 - 3); // eAPT has 8 decimals, want 1/1000th granularity
-    let tick_size = 10u64.pow(6 - 3); // eAPT has 6 decimals, want 1/1000th granularity
+    // Lots of 0.001 eAPT, and ticks of 0.001 eUSDC per lot.
+    let granularity = BigDecimal::new(1.into(), 3);
+    let e_apt_decimals = econia_client.get_coin_decimals(&e_apt).await?;
+    let e_usdc_decimals = econia_client.get_coin_decimals(&e_usdc).await?;
+    let lot_size = MarketUnits::lot_size_for(&granularity, e_apt_decimals)?;
+    let tick_size = MarketUnits::tick_size_for(&granularity, e_usdc_decimals)?;
     let min_size = 1;
 
-    let market_id = econia_client
-        .view_client()
-        .get_market_id_base_coin(
-            e_apt.clone().into(),
-            e_usdc.clone().into(),
-            lot_size,
-            tick_size,
-            min_size,
-        )
+    let market = MarketRegistration {
+        base: e_apt.clone(),
+        quote: e_usdc.clone(),
+        utility_coin: APTOS_COIN_TYPE.clone(),
+        lot_size,
+        tick_size,
+        min_size,
+    };
+    let report = econia_client
+        .register_market(&market, RegistrationOptions::default())
         .await?;
+    let market_id = report.market_id;
 
-    let market_id = if let Some(market_id) = market_id {
+    if report.was_ready() {
         println!("Market already exists, ID: {market_id}");
 
         let (_, mut econia_client) =
@@ -390,41 +399,9 @@ async fn main() -> EconiaResult<()> {
         }
 
         println!("Cleared all previous orders.");
-
-        market_id
     } else {
-        let entry = register_market_base_coin_from_coinstore(
-            econia_address,
-            &e_apt,
-            &e_usdc,
-            &APTOS_COIN_TYPE,
-            lot_size,
-            tick_size,
-            min_size,
-        )
-        .unwrap();
-
-        econia_client.submit_tx(entry).await?;
-
-        let market_id = econia_client
-            .view_client()
-            .get_market_id_base_coin(
-                e_apt.clone().into(),
-                e_usdc.clone().into(),
-                lot_size,
-                tick_size,
-                min_size,
-            )
-            .await?;
-
-        let market_id = if let Some(market_id) = market_id {
-            println!("Market created with ID: {market_id}");
-            market_id
-        } else {
-            panic!("Could not create market."); // Should not happen
-        };
-        market_id
-    };
+        println!("Market created with ID: {market_id}");
+    }
 
     wait_for_enter!("Set up account A");
 
@@ -536,7 +513,7 @@ async fn main() -> EconiaResult<()> {
     let events = econia_client_b
         .get_events_by_creation_number(
             market_event_handle_creation_numbers.fill_events_handle_creation_num,
-            econia_client_b.user_account.address(),
+            econia_client_b.user_address(),
             None,
             None,
         )
@@ -583,7 +560,7 @@ async fn main() -> EconiaResult<()> {
     let events = econia_client_b
         .get_events_by_creation_number(
             market_event_handle_creation_numbers.fill_events_handle_creation_num,
-            econia_client_b.user_account.address(),
+            econia_client_b.user_address(),
             None,
             None,
         )
@@ -604,7 +581,7 @@ async fn main() -> EconiaResult<()> {
     let events = econia_client_a
         .get_events_by_creation_number(
             market_event_handle_creation_numbers.cancel_order_events_handle_creation_num,
-            econia_client_a.user_account.address(),
+            econia_client_a.user_address(),
             None,
             None,
         )
@@ -627,7 +604,7 @@ async fn main() -> EconiaResult<()> {
     let events = econia_client_a
         .get_events_by_creation_number(
             market_event_handle_creation_numbers.cancel_order_events_handle_creation_num,
-            econia_client_a.user_account.address(),
+            econia_client_a.user_address(),
             None,
             None,
         )
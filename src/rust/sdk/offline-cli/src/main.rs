@@ -0,0 +1,347 @@
+//! Prepares, signs and submits Econia transactions whose private key stays on an air-gapped
+//! machine, see the `offline` module of the Econia SDK.
+//!
+//! ```text
+//! # Online, without the private key.
+//! econia-offline prepare --node-url $NODE --econia-address $ECONIA --sender $TREASURY \
+//!     --out withdraw.json withdraw-to-coinstore --coin 0x1::aptos_coin::AptosCoin \
+//!     --market-id 1 --amount 1000
+//! # Air-gapped, the keystore password being read from `ECONIA_KEYSTORE_PASSWORD` or prompted for.
+//! econia-offline inspect withdraw.json
+//! econia-offline sign --keystore treasury.json --input withdraw.json --out withdraw.signed.json
+//! # Online.
+//! econia-offline submit --node-url $NODE --econia-address $ECONIA --input withdraw.signed.json
+//! ```
+
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use aptos_sdk::move_types::language_storage::TypeTag;
+use aptos_sdk::rest_client::{AptosBaseUrl, Client};
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::transaction::{EntryFunction, TransactionPayload};
+use clap::{Parser, Subcommand};
+use econia_sdk::generated::entry::incentives;
+use econia_sdk::offline::{
+    self, OfflineTransaction, PrepareOptions, SignedOfflineTransaction, DEFAULT_EXPIRATION,
+};
+use econia_sdk::signer::Keystore;
+use econia_sdk::{entry, MAX_GAS_AMOUNT};
+use reqwest::Url;
+
+const PASSWORD_VAR: &str = "ECONIA_KEYSTORE_PASSWORD";
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Prepare an unsigned transaction and write it to a file.
+    Prepare {
+        /// Url of the Aptos node.
+        #[arg(long)]
+        node_url: Url,
+        /// Address of the account that holds the econia modules.
+        #[arg(long)]
+        econia_address: AccountAddress,
+        /// Address of the account sending the transaction.
+        #[arg(long)]
+        sender: AccountAddress,
+        /// File to write the unsigned transaction to.
+        #[arg(long)]
+        out: PathBuf,
+        /// Sequence number of the transaction, fetched from the chain if not set.
+        #[arg(long)]
+        sequence_number: Option<u64>,
+        /// Gas unit price in octas, estimated by the node if not set.
+        #[arg(long)]
+        gas_unit_price: Option<u64>,
+        #[arg(long, default_value_t = MAX_GAS_AMOUNT)]
+        max_gas_amount: u64,
+        /// Seconds after which the transaction expires.
+        #[arg(long, default_value_t = DEFAULT_EXPIRATION.as_secs())]
+        expiration_secs: u64,
+        /// Free form description of the transaction, for review.
+        #[arg(long, default_value = "")]
+        description: String,
+        #[command(subcommand)]
+        payload: Payload,
+    },
+    /// Print a transaction file, signed or not, along with the time left before it expires.
+    Inspect { file: PathBuf },
+    /// Sign an unsigned transaction with the key of a keystore file.
+    Sign {
+        /// Keystore file holding the private key of the sender.
+        #[arg(long)]
+        keystore: PathBuf,
+        /// Unsigned transaction file.
+        #[arg(long)]
+        input: PathBuf,
+        /// File to write the signed transaction to.
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Give an unsigned transaction a new expiration, it must then be signed again.
+    Renew {
+        /// Url of the Aptos node.
+        #[arg(long)]
+        node_url: Url,
+        /// Unsigned transaction file, renewed in place unless `--out` is set.
+        #[arg(long)]
+        input: PathBuf,
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Seconds after which the transaction expires.
+        #[arg(long, default_value_t = DEFAULT_EXPIRATION.as_secs())]
+        expiration_secs: u64,
+    },
+    /// Submit a signed transaction and wait for it to be committed.
+    Submit {
+        /// Url of the Aptos node.
+        #[arg(long)]
+        node_url: Url,
+        /// Address of the account that holds the econia modules.
+        #[arg(long)]
+        econia_address: AccountAddress,
+        /// Signed transaction file.
+        #[arg(long)]
+        input: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum Payload {
+    /// Withdraw coins from a market account to the coin store of the sender.
+    WithdrawToCoinstore {
+        #[arg(long, value_parser = TypeTag::from_str)]
+        coin: TypeTag,
+        #[arg(long)]
+        market_id: u64,
+        #[arg(long)]
+        amount: u64,
+    },
+    /// Withdraw the fees of an integrator fee store to the coin store of the sender.
+    WithdrawIntegratorFees {
+        #[arg(long, value_parser = TypeTag::from_str)]
+        quote_coin: TypeTag,
+        #[arg(long, value_parser = TypeTag::from_str)]
+        utility_coin: TypeTag,
+        #[arg(long)]
+        market_id: u64,
+    },
+    /// Withdraw the Econia fees of a market, all of them unless `--amount` is set.
+    WithdrawEconiaFees {
+        #[arg(long, value_parser = TypeTag::from_str)]
+        quote_coin: TypeTag,
+        #[arg(long)]
+        market_id: u64,
+        #[arg(long)]
+        amount: Option<u64>,
+    },
+    /// Withdraw the utility coins collected by Econia, all of them unless `--amount` is set.
+    WithdrawUtilityCoins {
+        #[arg(long, value_parser = TypeTag::from_str)]
+        utility_coin: TypeTag,
+        #[arg(long)]
+        amount: Option<u64>,
+    },
+}
+
+impl Payload {
+    fn entry_function(&self, econia_address: AccountAddress) -> Result<EntryFunction> {
+        Ok(match self {
+            Payload::WithdrawToCoinstore {
+                coin,
+                market_id,
+                amount,
+            } => entry::withdraw_to_coinstore(econia_address, coin, *market_id, *amount)?,
+            Payload::WithdrawIntegratorFees {
+                quote_coin,
+                utility_coin,
+                market_id,
+            } => entry::withdraw_integrator_fees_via_coinstores(
+                econia_address,
+                quote_coin,
+                utility_coin,
+                *market_id,
+            )?,
+            Payload::WithdrawEconiaFees {
+                quote_coin,
+                market_id,
+                amount: Some(amount),
+            } => incentives::withdraw_econia_fees_to_coin_store(
+                econia_address,
+                quote_coin,
+                *market_id,
+                *amount,
+            )?,
+            Payload::WithdrawEconiaFees {
+                quote_coin,
+                market_id,
+                amount: None,
+            } => incentives::withdraw_econia_fees_all_to_coin_store(
+                econia_address,
+                quote_coin,
+                *market_id,
+            )?,
+            Payload::WithdrawUtilityCoins {
+                utility_coin,
+                amount: Some(amount),
+            } => incentives::withdraw_utility_coins_to_coin_store(
+                econia_address,
+                utility_coin,
+                *amount,
+            )?,
+            Payload::WithdrawUtilityCoins {
+                utility_coin,
+                amount: None,
+            } => {
+                incentives::withdraw_utility_coins_all_to_coin_store(econia_address, utility_coin)?
+            }
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    match Args::parse().command {
+        Command::Prepare {
+            node_url,
+            econia_address,
+            sender,
+            out,
+            sequence_number,
+            gas_unit_price,
+            max_gas_amount,
+            expiration_secs,
+            description,
+            payload,
+        } => {
+            let entry = payload.entry_function(econia_address)?;
+            let options = PrepareOptions {
+                sequence_number,
+                max_gas_amount,
+                gas_unit_price,
+                expiration: Duration::from_secs(expiration_secs),
+            };
+            let transaction = offline::prepare(
+                &aptos_client(node_url),
+                sender,
+                TransactionPayload::EntryFunction(entry),
+                &description,
+                Some(options),
+            )
+            .await?;
+            transaction.save(&out)?;
+            print_unsigned(&transaction);
+        }
+        Command::Inspect { file } => {
+            if let Ok(transaction) = OfflineTransaction::load(&file) {
+                transaction.raw_transaction()?;
+                print_unsigned(&transaction);
+            } else {
+                let transaction = SignedOfflineTransaction::load(&file)?;
+                transaction.signed_transaction()?;
+                print_signed(&transaction);
+            }
+        }
+        Command::Sign {
+            keystore,
+            input,
+            out,
+        } => {
+            let transaction = OfflineTransaction::load(&input)?;
+            print_unsigned(&transaction);
+            let account = Keystore::open(keystore, &password()?)?;
+            let signed = transaction.sign(&account).await?;
+            signed.save(&out)?;
+            print_signed(&signed);
+        }
+        Command::Renew {
+            node_url,
+            input,
+            out,
+            expiration_secs,
+        } => {
+            let transaction = OfflineTransaction::load(&input)?
+                .renew(
+                    &aptos_client(node_url),
+                    Duration::from_secs(expiration_secs),
+                )
+                .await?;
+            transaction.save(out.unwrap_or(input))?;
+            print_unsigned(&transaction);
+        }
+        Command::Submit {
+            node_url,
+            econia_address,
+            input,
+        } => {
+            let transaction = SignedOfflineTransaction::load(&input)?;
+            if transaction.expires_in().is_none() {
+                bail!("the transaction has expired, renew and sign it again");
+            }
+            let committed =
+                offline::submit(&aptos_client(node_url), econia_address, &transaction).await?;
+            println!(
+                "committed {} at version {}: {}",
+                committed.info.hash, committed.info.version, committed.info.vm_status
+            );
+        }
+    }
+    Ok(())
+}
+
+fn aptos_client(node_url: Url) -> Client {
+    Client::builder(AptosBaseUrl::Custom(node_url)).build()
+}
+
+/// Read the keystore password from [`PASSWORD_VAR`], or prompt for it on the terminal without
+/// echoing it.
+fn password() -> Result<String> {
+    if let Ok(password) = std::env::var(PASSWORD_VAR) {
+        return Ok(password);
+    }
+    let password = rpassword::prompt_password("keystore password: ")?;
+    if password.is_empty() {
+        return Err(anyhow!("no keystore password given"));
+    }
+    Ok(password)
+}
+
+fn print_unsigned(transaction: &OfflineTransaction) {
+    println!("unsigned transaction");
+    println!("  description:     {}", transaction.description);
+    println!("  function:        {}", transaction.function);
+    for argument in &transaction.arguments {
+        println!("    {}", argument);
+    }
+    println!("  chain id:        {}", transaction.chain_id);
+    println!("  sender:          {}", transaction.sender);
+    println!("  sequence number: {}", transaction.sequence_number);
+    println!("  max gas amount:  {}", transaction.max_gas_amount);
+    println!("  gas unit price:  {}", transaction.gas_unit_price);
+    print_expiration(transaction.expires_in());
+}
+
+fn print_signed(transaction: &SignedOfflineTransaction) {
+    println!("signed transaction");
+    println!("  description:     {}", transaction.description);
+    println!("  hash:            {}", transaction.hash);
+    println!("  sender:          {}", transaction.sender);
+    println!("  sequence number: {}", transaction.sequence_number);
+    print_expiration(transaction.expires_in());
+}
+
+fn print_expiration(expires_in: Option<Duration>) {
+    match expires_in {
+        Some(duration) => println!("  expires in:      {}s", duration.as_secs()),
+        None => println!("  expired"),
+    }
+}
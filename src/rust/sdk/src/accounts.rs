@@ -0,0 +1,797 @@
+//! The [`accounts`](crate::accounts) module exposes a [`MultiAccountClient`] which manages several
+//! user accounts at once, and a [`CustodianModule`] to act on behalf of custodian-managed market
+//! accounts.
+//!
+//! A user may hold several market accounts on a same market, one per custodian ID, each of them
+//! identified by a market account ID, see [`MarketAccountId`]. Market accounts with a custodian
+//! ID of [`NO_CUSTODIAN`] are managed by the user itself through the `*_user` functions of the
+//! [`entry`](crate::entry) module.
+//!
+//! Market accounts managed by a custodian can only be acted on with its `CustodianCapability`,
+//! which Econia does not accept from entry functions. The custodian thus needs to publish a
+//! wrapper module which borrows its capability and calls the matching Econia `*_custodian`
+//! function. [`CustodianModule`] builds the `EntryFunction`s for such a module, expecting each
+//! entry function to:
+//!
+//! * Be named after the Econia function it wraps, e.g. `place_limit_order_custodian`.
+//! * Take the custodian `&signer` first, then the arguments of the Econia function, in order, minus
+//!   the trailing `&CustodianCapability`.
+
+use std::collections::BTreeMap;
+
+use aptos_sdk::bcs;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::ident_str;
+use aptos_sdk::move_types::identifier::IdentStr;
+use aptos_sdk::move_types::language_storage::{ModuleId, TypeTag};
+use aptos_sdk::types::transaction::EntryFunction;
+use econia_types::order::{AdvanceStyle, Order, Restriction, SelfMatchBehavior, Side};
+use futures::future::try_join_all;
+
+use crate::errors::EconiaError;
+use crate::view::{self, MarketAccountView};
+use crate::{EconiaClient, EconiaResult, EconiaTransaction};
+
+/// Custodian ID of market accounts managed by the user itself.
+pub const NO_CUSTODIAN: u64 = 0;
+
+/// A market account, identified by its user, market and custodian.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MarketAccountId {
+    pub user: AccountAddress,
+    pub market_id: u64,
+    pub custodian_id: u64,
+}
+
+impl MarketAccountId {
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID of the market account.
+    /// * `custodian_id` - Custodian ID of the market account, [`NO_CUSTODIAN`] if managed by the user.
+    pub fn new(user: AccountAddress, market_id: u64, custodian_id: u64) -> Self {
+        Self {
+            user,
+            market_id,
+            custodian_id,
+        }
+    }
+
+    /// Build the market account of a user from its market account ID.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_account_id` - Market account ID, as returned by [`MarketAccountId::market_account_id`].
+    pub fn from_market_account_id(user: AccountAddress, market_account_id: u128) -> Self {
+        Self::new(
+            user,
+            view::get_market_id(market_account_id),
+            view::get_custodian_id(market_account_id),
+        )
+    }
+
+    /// Market account ID, unique among the market accounts of the user.
+    pub fn market_account_id(&self) -> u128 {
+        view::get_market_account_id(self.market_id, self.custodian_id)
+    }
+
+    /// Whether the market account is managed by a custodian.
+    pub fn is_custodial(&self) -> bool {
+        self.custodian_id != NO_CUSTODIAN
+    }
+}
+
+/// A market account along with its balances and open orders.
+#[derive(Debug, Clone)]
+pub struct UserMarketAccount {
+    pub id: MarketAccountId,
+    pub account: MarketAccountView,
+}
+
+/// Manages the [`EconiaClient`]s of several user accounts, see the
+/// [module documentation](crate::accounts).
+#[derive(Debug, Default)]
+pub struct MultiAccountClient {
+    clients: BTreeMap<AccountAddress, EconiaClient>,
+}
+
+impl MultiAccountClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the client of a user account, returning the client it replaces if any.
+    ///
+    /// Clients for other accounts on the same node can be created with
+    /// [`EconiaClient::with_signer`].
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] of the user account.
+    pub fn insert(&mut self, client: EconiaClient) -> Option<EconiaClient> {
+        self.clients.insert(client.user_address(), client)
+    }
+
+    /// Remove the client of a user account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    pub fn remove(&mut self, user: AccountAddress) -> Option<EconiaClient> {
+        self.clients.remove(&user)
+    }
+
+    /// Get the client of a user account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    pub fn client(&self, user: AccountAddress) -> EconiaResult<&EconiaClient> {
+        self.clients
+            .get(&user)
+            .ok_or(EconiaError::UnknownAccount(user))
+    }
+
+    /// Addresses of the managed user accounts, in ascending order.
+    pub fn addresses(&self) -> impl Iterator<Item = AccountAddress> + '_ {
+        self.clients.keys().copied()
+    }
+
+    /// Amount of managed user accounts.
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Submit a transaction signed by one of the user accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user signing the transaction.
+    /// * `entry` - `EntryFunction` to be submitted as part of the transaction to the blockchain.
+    pub async fn submit_tx(
+        &self,
+        user: AccountAddress,
+        entry: EntryFunction,
+    ) -> EconiaResult<EconiaTransaction> {
+        self.client(user)?.submit_tx(entry).await
+    }
+
+    /// Get a market account of one of the user accounts.
+    ///
+    /// # Arguments:
+    ///
+    /// * `id` - [`MarketAccountId`] of the market account.
+    pub async fn market_account(&self, id: MarketAccountId) -> EconiaResult<UserMarketAccount> {
+        let account = self
+            .client(id.user)?
+            .view_client()
+            .get_market_account(id.user, id.market_id, id.custodian_id)
+            .await?;
+        Ok(UserMarketAccount { id, account })
+    }
+
+    /// Get every market account of every user account, ordered by user then market account ID.
+    pub async fn market_accounts(&self) -> EconiaResult<Vec<UserMarketAccount>> {
+        let accounts = try_join_all(self.clients.iter().map(|(user, client)| async move {
+            let view_client = client.view_client();
+            let accounts = view_client.get_market_accounts(*user).await?;
+            Ok::<_, EconiaError>(
+                accounts
+                    .into_iter()
+                    .map(|account| UserMarketAccount {
+                        id: MarketAccountId::new(*user, account.market_id, account.custodian_id),
+                        account,
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        }))
+        .await?;
+        let mut accounts = accounts.into_iter().flatten().collect::<Vec<_>>();
+        accounts.sort_by_key(|a| a.id);
+        Ok(accounts)
+    }
+
+    /// Get the open orders of every market account of every user account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market_id` - Only return the orders of this market, if `None` orders of every market
+    ///   are returned.
+    pub async fn open_orders(&self, market_id: Option<u64>) -> EconiaResult<Vec<Order>> {
+        Ok(self
+            .market_accounts()
+            .await?
+            .into_iter()
+            .filter(|a| market_id.map_or(true, |id| id == a.id.market_id))
+            .flat_map(|a| a.account.asks.into_iter().chain(a.account.bids))
+            .collect())
+    }
+}
+
+/// Builds the `EntryFunction`s of a custodian wrapper module, see the
+/// [module documentation](crate::accounts).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustodianModule {
+    /// Aptos `ModuleId` of the wrapper module.
+    pub module: ModuleId,
+    /// Custodian ID of the `CustodianCapability` borrowed by the wrapper module.
+    pub custodian_id: u64,
+}
+
+impl CustodianModule {
+    /// # Arguments:
+    ///
+    /// * `module` - Aptos `ModuleId` of the wrapper module.
+    /// * `custodian_id` - Custodian ID of the `CustodianCapability` borrowed by the wrapper module.
+    pub fn new(module: ModuleId, custodian_id: u64) -> Self {
+        Self {
+            module,
+            custodian_id,
+        }
+    }
+
+    /// [`MarketAccountId`] of the market account of a user managed by this custodian.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID of the market account.
+    pub fn market_account(&self, user: AccountAddress, market_id: u64) -> MarketAccountId {
+        MarketAccountId::new(user, market_id, self.custodian_id)
+    }
+
+    fn entry(
+        &self,
+        function: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<Vec<u8>>,
+    ) -> EntryFunction {
+        EntryFunction::new(self.module.clone(), function.to_owned(), ty_args, args)
+    }
+
+    /// Create the `EntryFunction` wrapping [cancel_all_orders_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_cancel_all_orders_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `side` - Order [`Side`].
+    pub fn cancel_all_orders(
+        &self,
+        user: AccountAddress,
+        market_id: u64,
+        side: Side,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("cancel_all_orders_custodian"),
+            vec![],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&side)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [cancel_order_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_cancel_order_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `side` - Order [`Side`].
+    /// * `market_order_id` - ID of the order to cancel.
+    pub fn cancel_order(
+        &self,
+        user: AccountAddress,
+        market_id: u64,
+        side: Side,
+        market_order_id: u128,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("cancel_order_custodian"),
+            vec![],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&market_order_id)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [change_order_size_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_change_order_size_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `side` - Order [`Side`].
+    /// * `market_order_id` - ID of the order to change.
+    /// * `new_size` - New size of the order.
+    pub fn change_order_size(
+        &self,
+        user: AccountAddress,
+        market_id: u64,
+        side: Side,
+        market_order_id: u128,
+        new_size: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("change_order_size_custodian"),
+            vec![],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&market_order_id)?,
+                bcs::to_bytes(&new_size)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [place_limit_order_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_limit_order_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `base` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for base coin.
+    /// * `quote` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for quote coin.
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `integrator` - Integrator's `AccountAddress`.
+    /// * `side` - Order [`Side`].
+    /// * `size` - Size of the order in lots.
+    /// * `price` - Price of the order.
+    /// * `restriction` - The [`Restriction`] of the order.
+    /// * `self_match_behavior` - The [`SelfMatchBehavior`] of the order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order(
+        &self,
+        base: &TypeTag,
+        quote: &TypeTag,
+        user: AccountAddress,
+        market_id: u64,
+        integrator: &AccountAddress,
+        side: Side,
+        size: u64,
+        price: u64,
+        restriction: Restriction,
+        self_match_behavior: SelfMatchBehavior,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("place_limit_order_custodian"),
+            vec![base.clone(), quote.clone()],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(integrator)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&price)?,
+                bcs::to_bytes(&restriction)?,
+                bcs::to_bytes(&self_match_behavior)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [place_limit_order_passive_advance_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_limit_order_passive_advance_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `base` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for base coin.
+    /// * `quote` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for quote coin.
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `integrator` - Integrator's `AccountAddress`.
+    /// * `side` - Order [`Side`].
+    /// * `size` - Size of the order in lots.
+    /// * `advance_style` - The [`AdvanceStyle`] of the order.
+    /// * `target_advance_amount` - Target advance amount.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order_passive_advance(
+        &self,
+        base: &TypeTag,
+        quote: &TypeTag,
+        user: AccountAddress,
+        market_id: u64,
+        integrator: &AccountAddress,
+        side: Side,
+        size: u64,
+        advance_style: AdvanceStyle,
+        target_advance_amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("place_limit_order_passive_advance_custodian"),
+            vec![base.clone(), quote.clone()],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(integrator)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&advance_style)?,
+                bcs::to_bytes(&target_advance_amount)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [place_market_order_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_market_order_custodian)
+    ///
+    /// # Arguments:
+    ///
+    /// * `base` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for base coin.
+    /// * `quote` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for quote coin.
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `integrator` - Integrator's `AccountAddress`.
+    /// * `direction` - Order direction, as a [`Side`].
+    /// * `size` - Size of the order in lots.
+    /// * `self_match_behavior` - The [`SelfMatchBehavior`] of the order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_market_order(
+        &self,
+        base: &TypeTag,
+        quote: &TypeTag,
+        user: AccountAddress,
+        market_id: u64,
+        integrator: &AccountAddress,
+        direction: Side,
+        size: u64,
+        self_match_behavior: SelfMatchBehavior,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("place_market_order_custodian"),
+            vec![base.clone(), quote.clone()],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(integrator)?,
+                bcs::to_bytes(&direction)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&self_match_behavior)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` wrapping [withdraw_coins_custodian](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_withdraw_coins_custodian)
+    ///
+    /// Where the withdrawn coins go is up to the wrapper module.
+    ///
+    /// # Arguments:
+    ///
+    /// * `coin` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for withdrawal coin.
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID for corresponding market.
+    /// * `amount` - Amount of coin to withdraw.
+    pub fn withdraw_coins(
+        &self,
+        coin: &TypeTag,
+        user: AccountAddress,
+        market_id: u64,
+        amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(self.entry(
+            ident_str!("withdraw_coins_custodian"),
+            vec![coin.clone()],
+            vec![
+                bcs::to_bytes(&user)?,
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&amount)?,
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aptos_sdk::crypto::ed25519::Ed25519PrivateKey;
+    use aptos_sdk::types::{AccountKey, LocalAccount};
+    use serde::Serialize;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::generated::view::user;
+    use crate::tests::{mock_client, ECONIA};
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn address(hex: &str) -> AccountAddress {
+        AccountAddress::from_hex_literal(hex).unwrap()
+    }
+
+    fn order(order_id: u128, market_id: u64, side: Side, user: &str) -> Value {
+        json!({
+            "order_id": order_id,
+            "market_id": market_id,
+            "side": side,
+            "size": 10,
+            "remaining_size": 10,
+            "price": 100,
+            "user_address": user,
+            "custodian_id": null,
+            "order_state": "open",
+            "created_at": "2023-10-18T00:00:00Z",
+        })
+    }
+
+    /// Market account whose base total is `base_total`, with a single open order.
+    fn market_account(market_id: u64, custodian_id: u64, base_total: u64, order: Value) -> Value {
+        let (asks, bids) = match order["side"].as_bool() {
+            Some(true) => (vec![order], vec![]),
+            _ => (vec![], vec![order]),
+        };
+        json!({
+            "market_id": market_id.to_string(),
+            "custodian_id": custodian_id.to_string(),
+            "asks": asks,
+            "bids": bids,
+            "base_total": base_total.to_string(),
+            "base_available": base_total.to_string(),
+            "base_ceiling": base_total.to_string(),
+            "quote_total": "0",
+            "quote_available": "0",
+            "quote_ceiling": "0",
+        })
+    }
+
+    /// Client of 0xa11ce and 0xb0b, whose market accounts are served by the mock.
+    async fn multi_account_client() -> MultiAccountClient {
+        let (transport, alice) = mock_client().await;
+        let private_key = Ed25519PrivateKey::try_from(&[8u8; 32][..]).unwrap();
+        let account = LocalAccount::new(address("0xb0b"), AccountKey::from(private_key), 0);
+        let bob = alice.with_signer(account, None).await.unwrap();
+
+        let alice_accounts = vec![
+            market_account(2, NO_CUSTODIAN, 20, order(21, 2, Side::Ask, "0xa11ce")),
+            market_account(1, 3, 13, order(11, 1, Side::Bid, "0xa11ce")),
+        ];
+        let bob_accounts = vec![market_account(
+            1,
+            NO_CUSTODIAN,
+            10,
+            order(12, 1, Side::Ask, "0xb0b"),
+        )];
+        for (owner, accounts) in [("0xa11ce", alice_accounts), ("0xb0b", bob_accounts)] {
+            let request = user::get_market_accounts(econia(), address(owner)).unwrap();
+            transport
+                .push_view(&request, vec![json!(accounts)])
+                .unwrap();
+        }
+        let request = user::get_market_account(econia(), address("0xa11ce"), 1, 3).unwrap();
+        transport
+            .push_view(
+                &request,
+                vec![market_account(1, 3, 13, order(11, 1, Side::Bid, "0xa11ce"))],
+            )
+            .unwrap();
+
+        let mut client = MultiAccountClient::new();
+        assert!(client.insert(alice).is_none());
+        assert!(client.insert(bob).is_none());
+        client
+    }
+
+    fn bytes<T: Serialize>(value: T) -> Vec<u8> {
+        bcs::to_bytes(&value).unwrap()
+    }
+
+    #[test]
+    fn market_account_ids() {
+        let id = MarketAccountId::new(address("0xa11ce"), 7, 3);
+        assert_eq!(id.market_account_id(), (7 << 64) | 3);
+        assert_eq!(
+            MarketAccountId::from_market_account_id(id.user, id.market_account_id()),
+            id
+        );
+        assert!(id.is_custodial());
+        assert!(!MarketAccountId::new(id.user, 7, NO_CUSTODIAN).is_custodial());
+    }
+
+    #[tokio::test]
+    async fn aggregate_market_accounts() {
+        let client = multi_account_client().await;
+        assert_eq!(client.len(), 2);
+        assert_eq!(
+            client.addresses().collect::<Vec<_>>(),
+            [address("0xa11ce"), address("0xb0b")]
+        );
+
+        let accounts = client.market_accounts().await.unwrap();
+        let summary = accounts
+            .iter()
+            .map(|a| (a.id, a.account.base_total))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (MarketAccountId::new(address("0xa11ce"), 1, 3), 13),
+                (
+                    MarketAccountId::new(address("0xa11ce"), 2, NO_CUSTODIAN),
+                    20
+                ),
+                (MarketAccountId::new(address("0xb0b"), 1, NO_CUSTODIAN), 10),
+            ]
+        );
+
+        let account = client
+            .market_account(MarketAccountId::new(address("0xa11ce"), 1, 3))
+            .await
+            .unwrap();
+        assert_eq!(account.account.base_total, 13);
+        assert_eq!(account.account.bids[0].order_id, 11);
+    }
+
+    #[tokio::test]
+    async fn aggregate_open_orders() {
+        let client = multi_account_client().await;
+        let ids = |orders: Vec<Order>| orders.iter().map(|o| o.order_id).collect::<Vec<_>>();
+        assert_eq!(ids(client.open_orders(None).await.unwrap()), [11, 21, 12]);
+        assert_eq!(ids(client.open_orders(Some(1)).await.unwrap()), [11, 12]);
+        assert!(client.open_orders(Some(3)).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn reject_unknown_accounts() {
+        let mut client = multi_account_client().await;
+        let carol = address("0xca501");
+        assert!(matches!(
+            client.client(carol),
+            Err(EconiaError::UnknownAccount(a)) if a == carol
+        ));
+        let entry = crate::generated::entry::market::cancel_all_orders_user(econia(), 1, true);
+        assert!(matches!(
+            client.submit_tx(carol, entry.unwrap()).await,
+            Err(EconiaError::UnknownAccount(_))
+        ));
+        assert!(client.remove(address("0xb0b")).is_some());
+        assert!(client.client(address("0xb0b")).is_err());
+    }
+
+    /// Arguments must follow the parameters of the Econia `*_custodian` functions, minus the
+    /// capability.
+    #[test]
+    fn encode_custodian_arguments() {
+        let module = ModuleId::new(address("0xc057"), ident_str!("wrapper").to_owned());
+        let custodian = CustodianModule::new(module.clone(), 3);
+        let (user, integrator) = (address("0xa11ce"), address("0xbeef"));
+        let (base, quote) = (TypeTag::U8, TypeTag::U64);
+        let coins = vec![base.clone(), quote.clone()];
+        let cases = [
+            (
+                custodian.cancel_all_orders(user, 1, Side::Ask).unwrap(),
+                "cancel_all_orders_custodian",
+                vec![],
+                // user_address: address, market_id: u64, side: bool
+                vec![bytes(user), bytes(1u64), bytes(true)],
+            ),
+            (
+                custodian.cancel_order(user, 1, Side::Bid, 7).unwrap(),
+                "cancel_order_custodian",
+                vec![],
+                // ..., side: bool, market_order_id: u128
+                vec![bytes(user), bytes(1u64), bytes(false), bytes(7u128)],
+            ),
+            (
+                custodian
+                    .change_order_size(user, 1, Side::Ask, 7, 5)
+                    .unwrap(),
+                "change_order_size_custodian",
+                vec![],
+                // ..., market_order_id: u128, new_size: u64
+                vec![
+                    bytes(user),
+                    bytes(1u64),
+                    bytes(true),
+                    bytes(7u128),
+                    bytes(5u64),
+                ],
+            ),
+            (
+                custodian
+                    .place_limit_order(
+                        &base,
+                        &quote,
+                        user,
+                        1,
+                        &integrator,
+                        Side::Bid,
+                        10,
+                        100,
+                        Restriction::PostOrAbort,
+                        SelfMatchBehavior::CancelMaker,
+                    )
+                    .unwrap(),
+                "place_limit_order_custodian",
+                coins.clone(),
+                // user_address, market_id, integrator: address, side: bool, size: u64,
+                // price: u64, restriction: u8, self_match_behavior: u8
+                vec![
+                    bytes(user),
+                    bytes(1u64),
+                    bytes(integrator),
+                    bytes(false),
+                    bytes(10u64),
+                    bytes(100u64),
+                    bytes(3u8),
+                    bytes(2u8),
+                ],
+            ),
+            (
+                custodian
+                    .place_limit_order_passive_advance(
+                        &base,
+                        &quote,
+                        user,
+                        1,
+                        &integrator,
+                        Side::Ask,
+                        10,
+                        AdvanceStyle::Percent,
+                        50,
+                    )
+                    .unwrap(),
+                "place_limit_order_passive_advance_custodian",
+                coins.clone(),
+                // ..., size: u64, advance_style: bool, target_advance_amount: u64
+                vec![
+                    bytes(user),
+                    bytes(1u64),
+                    bytes(integrator),
+                    bytes(true),
+                    bytes(10u64),
+                    bytes(true),
+                    bytes(50u64),
+                ],
+            ),
+            (
+                custodian
+                    .place_market_order(
+                        &base,
+                        &quote,
+                        user,
+                        1,
+                        &integrator,
+                        Side::Bid,
+                        10,
+                        SelfMatchBehavior::CancelTaker,
+                    )
+                    .unwrap(),
+                "place_market_order_custodian",
+                coins,
+                // ..., direction: bool, size: u64, self_match_behavior: u8
+                vec![
+                    bytes(user),
+                    bytes(1u64),
+                    bytes(integrator),
+                    bytes(false),
+                    bytes(10u64),
+                    bytes(3u8),
+                ],
+            ),
+            (
+                custodian.withdraw_coins(&base, user, 1, 500).unwrap(),
+                "withdraw_coins_custodian",
+                vec![base],
+                // user_address: address, market_id: u64, amount: u64
+                vec![bytes(user), bytes(1u64), bytes(500u64)],
+            ),
+        ];
+        for (entry, function, ty_args, args) in cases {
+            assert_eq!(entry.module(), &module);
+            assert_eq!(entry.function().as_str(), function);
+            assert_eq!(entry.ty_args(), ty_args.as_slice(), "{}", function);
+            assert_eq!(entry.args(), args.as_slice(), "{}", function);
+        }
+        assert_eq!(
+            custodian.market_account(user, 1),
+            MarketAccountId::new(user, 1, 3)
+        );
+    }
+}
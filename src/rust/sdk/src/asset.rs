@@ -0,0 +1,377 @@
+//! The [`asset`](crate::asset) module reads balances of both Aptos asset standards: legacy coins,
+//! held in a `0x1::coin::CoinStore<T>`, and fungible assets, held in primary fungible stores.
+//!
+//! An [`Asset`] is either a coin, identified by its type, or a fungible asset, identified by the
+//! address of its `0x1::fungible_asset::Metadata` object. Coins can be paired with a fungible
+//! asset, in which case their balance is split between the coin store and the primary fungible
+//! store of the paired asset. [`AssetBalance`] reports both parts along with their sum.
+//!
+//! Econia markets trade coins. Depositing a coin to a market account withdraws it with
+//! `0x1::coin::withdraw`, which also draws from the paired primary fungible store when the
+//! coin store does not hold enough, so the [`AssetBalance::total`] of a coin can be deposited.
+
+use std::str::FromStr;
+
+use aptos_api_types::{EntryFunctionId, MoveType, ViewRequest};
+use aptos_sdk::move_types::language_storage::TypeTag;
+use aptos_sdk::move_types::vm_status::StatusCode;
+use aptos_sdk::rest_client::aptos::Balance;
+use aptos_sdk::rest_client::error::RestError;
+use aptos_sdk::types::account_address::AccountAddress;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::errors::EconiaError;
+use crate::{EconiaClient, EconiaResult};
+
+pub const FUNGIBLE_ASSET_METADATA: &str = "0x1::fungible_asset::Metadata";
+
+/// An Aptos asset, see the [`asset`](crate::asset) module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Asset {
+    /// Coin, identified by its type, e.g. `0x1::aptos_coin::AptosCoin`.
+    Coin(TypeTag),
+    /// Fungible asset, identified by the address of its metadata object.
+    FungibleAsset(AccountAddress),
+}
+
+/// `0x1::fungible_asset::Metadata` of a fungible asset.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FungibleAssetMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub icon_uri: String,
+    pub project_uri: String,
+}
+
+/// Balance of an account in an [`Asset`], across its coin store and its primary fungible store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetBalance {
+    pub asset: Asset,
+    /// Decimals of the asset, the ones of the coin for a paired coin.
+    pub decimals: u8,
+    /// Balance of the `CoinStore` of the account, `None` if the asset is not a coin or if the
+    /// account is not registered for it.
+    pub coin_store: Option<u64>,
+    /// Address of the metadata of the fungible asset, or of the fungible asset paired with the
+    /// coin. `None` for a coin not paired with any fungible asset.
+    pub metadata: Option<AccountAddress>,
+    /// Balance of the primary fungible store of the account, `None` if it does not exist.
+    pub primary_store: Option<u64>,
+}
+
+impl AssetBalance {
+    /// Balance held by the account, both standards included. It can exceed a `u64`, as each part
+    /// is one.
+    pub fn total(&self) -> u128 {
+        u128::from(self.coin_store.unwrap_or_default())
+            + u128::from(self.primary_store.unwrap_or_default())
+    }
+
+    /// Whether the account can hold the asset, i.e. has a coin store or a primary store for it.
+    pub fn is_registered(&self) -> bool {
+        self.coin_store.is_some() || self.primary_store.is_some()
+    }
+}
+
+#[derive(Deserialize)]
+struct ObjectRef {
+    inner: AccountAddress,
+}
+
+#[derive(Deserialize)]
+struct MoveOption<T> {
+    vec: Vec<T>,
+}
+
+/// Build a request calling a view function of the Aptos framework.
+fn framework_view(
+    function: &str,
+    type_arguments: Vec<MoveType>,
+    arguments: Vec<Value>,
+) -> ViewRequest {
+    ViewRequest {
+        function: EntryFunctionId::from_str(function).expect("valid framework function"),
+        type_arguments,
+        arguments,
+    }
+}
+
+fn metadata_type() -> MoveType {
+    MoveType::from_str(FUNGIBLE_ASSET_METADATA).expect("valid framework type")
+}
+
+impl EconiaClient {
+    /// Checks if an asset exists on the aptos chain.
+    ///
+    /// # Arguments:
+    ///
+    /// * `asset` - [`Asset`] to look for.
+    pub async fn does_asset_exist(&self, asset: &Asset) -> EconiaResult<bool> {
+        match asset {
+            Asset::Coin(coin) => self.does_coin_exist(coin).await,
+            Asset::FungibleAsset(metadata) => self
+                .fetch_resource(*metadata, FUNGIBLE_ASSET_METADATA)
+                .await
+                .map(|r| r.is_some()),
+        }
+    }
+
+    /// Returns the metadata of a fungible asset.
+    ///
+    /// # Arguments:
+    ///
+    /// * `metadata` - Address of the metadata object of the fungible asset.
+    pub async fn get_fungible_asset_metadata(
+        &self,
+        metadata: AccountAddress,
+    ) -> EconiaResult<FungibleAssetMetadata> {
+        let resource = self
+            .fetch_resource(metadata, FUNGIBLE_ASSET_METADATA)
+            .await?
+            .ok_or_else(|| {
+                EconiaError::FungibleAssetError(format!(
+                    "no fungible asset metadata at: `{}`",
+                    metadata.to_hex_literal()
+                ))
+            })?;
+        Ok(serde_json::from_value(resource.data)?)
+    }
+
+    /// Returns the address of the metadata of the fungible asset paired with a coin, `None` if
+    /// the coin is not paired, or if the framework of the chain has no `paired_metadata` function.
+    ///
+    /// # Arguments:
+    ///
+    /// * `coin` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for the given coin.
+    pub async fn get_paired_metadata(
+        &self,
+        coin: &TypeTag,
+    ) -> EconiaResult<Option<AccountAddress>> {
+        let request = framework_view("0x1::coin::paired_metadata", vec![coin.into()], vec![]);
+        match self
+            .view_client()
+            .call::<MoveOption<ObjectRef>>(&request)
+            .await
+        {
+            Ok(paired) => Ok(paired.vec.into_iter().next().map(|o| o.inner)),
+            // Frameworks predating fungible assets have no `paired_metadata` function, any other
+            // failure is not a proof that the coin is not paired.
+            Err(EconiaError::AptosError(RestError::Api(a)))
+                if a.error.vm_error_code
+                    == Some(StatusCode::FUNCTION_RESOLUTION_FAILURE as u64)
+                    || a.error.message.contains("FUNCTION_RESOLUTION_FAILURE") =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the balance of the primary fungible store of an account, `None` if the account has
+    /// no primary store for the fungible asset.
+    ///
+    /// # Arguments:
+    ///
+    /// * `owner` - Aptos `AccountAddress` of the account.
+    /// * `metadata` - Address of the metadata object of the fungible asset.
+    pub async fn get_primary_store_balance(
+        &self,
+        owner: AccountAddress,
+        metadata: AccountAddress,
+    ) -> EconiaResult<Option<u64>> {
+        let exists = framework_view(
+            "0x1::primary_fungible_store::primary_store_exists",
+            vec![metadata_type()],
+            vec![
+                json!(owner.to_hex_literal()),
+                json!(metadata.to_hex_literal()),
+            ],
+        );
+        if !self.view_client().call::<bool>(&exists).await? {
+            return Ok(None);
+        }
+        self.primary_store_balance(owner, metadata).await.map(Some)
+    }
+
+    /// Balance of the primary fungible store of an account, 0 if it does not exist.
+    pub(crate) async fn primary_store_balance(
+        &self,
+        owner: AccountAddress,
+        metadata: AccountAddress,
+    ) -> EconiaResult<u64> {
+        let balance = framework_view(
+            "0x1::primary_fungible_store::balance",
+            vec![metadata_type()],
+            vec![
+                json!(owner.to_hex_literal()),
+                json!(metadata.to_hex_literal()),
+            ],
+        );
+        let balance = self
+            .view_client()
+            .call::<aptos_api_types::U64>(&balance)
+            .await?;
+        Ok(balance.0)
+    }
+
+    /// Balance of the `CoinStore` of an account, `None` if it is not registered for the coin.
+    pub(crate) async fn coin_store_balance(
+        &self,
+        owner: AccountAddress,
+        coin: &TypeTag,
+    ) -> EconiaResult<Option<u64>> {
+        Ok(self
+            .fetch_resource(owner, &format!("0x1::coin::CoinStore<{}>", coin))
+            .await?
+            .map(|r| serde_json::from_value::<Balance>(r.data))
+            .transpose()?
+            .map(|b| b.coin.value.0))
+    }
+
+    /// Returns the balance of an account in an asset, across its coin store and its primary
+    /// fungible store.
+    ///
+    /// # Arguments:
+    ///
+    /// * `owner` - Aptos `AccountAddress` of the account.
+    /// * `asset` - [`Asset`] to get the balance of.
+    pub async fn get_asset_balance_of(
+        &self,
+        owner: AccountAddress,
+        asset: &Asset,
+    ) -> EconiaResult<AssetBalance> {
+        let (decimals, coin_store, metadata) = match asset {
+            Asset::Coin(coin) => {
+                let coin_store = self.coin_store_balance(owner, coin).await?;
+                let decimals = self.get_coin_decimals(coin).await?;
+                (decimals, coin_store, self.get_paired_metadata(coin).await?)
+            }
+            Asset::FungibleAsset(metadata) => {
+                let decimals = self.get_fungible_asset_metadata(*metadata).await?.decimals;
+                (decimals, None, Some(*metadata))
+            }
+        };
+        let primary_store = match metadata {
+            Some(metadata) => self.get_primary_store_balance(owner, metadata).await?,
+            None => None,
+        };
+        Ok(AssetBalance {
+            asset: asset.clone(),
+            decimals,
+            coin_store,
+            metadata,
+            primary_store,
+        })
+    }
+
+    /// Returns the balance of the user in an asset, across its coin store and its primary
+    /// fungible store.
+    ///
+    /// # Arguments:
+    ///
+    /// * `asset` - [`Asset`] to get the balance of.
+    pub async fn get_asset_balance(&self, asset: &Asset) -> EconiaResult<AssetBalance> {
+        self.get_asset_balance_of(self.user_address(), asset).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_client;
+
+    const COIN: &str = "0xc0de::coin::C";
+    const METADATA: &str = "0xfa";
+
+    fn paired_metadata(coin: &TypeTag) -> ViewRequest {
+        framework_view("0x1::coin::paired_metadata", vec![coin.into()], vec![])
+    }
+
+    fn primary_store_view(function: &str, owner: AccountAddress) -> ViewRequest {
+        framework_view(
+            function,
+            vec![metadata_type()],
+            vec![json!(owner.to_hex_literal()), json!(METADATA)],
+        )
+    }
+
+    #[test]
+    fn total_both_standards() {
+        let balance = AssetBalance {
+            asset: Asset::FungibleAsset(AccountAddress::ONE),
+            decimals: 8,
+            coin_store: Some(u64::MAX),
+            metadata: None,
+            primary_store: Some(u64::MAX),
+        };
+        assert_eq!(balance.total(), 2 * u128::from(u64::MAX));
+    }
+
+    #[tokio::test]
+    async fn coin_balance_without_coin_info() {
+        let (transport, client) = mock_client().await;
+        let coin = TypeTag::from_str(COIN).unwrap();
+        let owner = client.user_address();
+        transport
+            .set_resource(
+                owner,
+                &format!("0x1::coin::CoinStore<{}>", COIN),
+                json!({ "coin": { "value": "5" } }),
+            )
+            .unwrap();
+        transport
+            .push_view(
+                &paired_metadata(&coin),
+                vec![json!({ "vec": [{ "inner": METADATA }] })],
+            )
+            .unwrap();
+        transport
+            .push_view(
+                &primary_store_view("0x1::primary_fungible_store::balance", owner),
+                vec![json!("7")],
+            )
+            .unwrap();
+
+        // The coin has no `CoinInfo`, which the balance does not need.
+        assert_eq!(client.get_coin_balance(&coin).await.unwrap().0, 12);
+        assert!(matches!(
+            client.get_asset_balance(&Asset::Coin(coin)).await,
+            Err(EconiaError::CoinNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn coin_balance_of_unregistered_account() {
+        let (transport, client) = mock_client().await;
+        let coin = TypeTag::from_str(COIN).unwrap();
+        let owner = client.user_address();
+        transport
+            .push_view(
+                &paired_metadata(&coin),
+                vec![json!({ "vec": [{ "inner": METADATA }] })],
+            )
+            .unwrap();
+        transport
+            .push_view(
+                &primary_store_view("0x1::primary_fungible_store::primary_store_exists", owner),
+                vec![json!(false)],
+            )
+            .unwrap();
+
+        assert!(!client.is_registered_for_coin(&coin).await.unwrap());
+        assert!(matches!(
+            client.get_coin_balance(&coin).await,
+            Err(EconiaError::AccountNotRegisteredForCoin(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn propagate_paired_metadata_failure() {
+        let (_, client) = mock_client().await;
+        let coin = TypeTag::from_str(COIN).unwrap();
+        // No response is queued, so the view fails with an error other than a missing function.
+        assert!(client.get_paired_metadata(&coin).await.is_err());
+    }
+}
@@ -0,0 +1,826 @@
+//! The [`batch`](crate::batch) module composes several payloads from the [`entry`](crate::entry)
+//! module into a single transaction.
+//!
+//! Every payload from the [`entry`](crate::entry) module calls a single entry function, so
+//! cancelling 20 orders and placing 20 new ones takes 40 transactions. A [`BatchBuilder`] instead
+//! renders a Move script calling the entry functions in order, from the template bundled with the
+//! SDK. The script is compiled by a [`ScriptCompiler`] and submitted as a single transaction with
+//! [`EconiaClient::submit_batch`](crate::EconiaClient::submit_batch).
+//!
+//! The script is executed atomically: if any of its calls aborts, none of them take effect. The
+//! events of a committed batch are attributed back to the entry functions which emitted them, see
+//! [`BatchScript::split_events`].
+//!
+//! Compiling requires the [Aptos CLI](https://aptos.dev/tools/aptos-cli/) and a local copy of the
+//! Econia Move package. The script only depends on which entry functions are called, their
+//! arguments being passed as script arguments, so compiled scripts are cached and reused.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use anyhow::anyhow;
+use aptos_api_types::{MoveFunction, MoveType};
+use aptos_sdk::bcs;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::language_storage::ModuleId;
+use aptos_sdk::move_types::transaction_argument::TransactionArgument;
+use aptos_sdk::types::transaction::{EntryFunction, Script};
+use econia_types::events::EconiaEvent;
+use econia_types::order::CancelReason;
+
+use crate::errors::EconiaError;
+use crate::outcome::concerns_order;
+use crate::transport::AptosTransport;
+use crate::{EconiaResult, EconiaTransaction};
+
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/batch/Move.toml");
+const SCRIPT_TEMPLATE: &str = include_str!("../templates/batch/script.move");
+
+/// Used to give every compilation its own package directory.
+static NEXT_PACKAGE_DIR: AtomicU64 = AtomicU64::new(0);
+
+/// Collects entry functions to be called in order by a single transaction.
+#[derive(Debug, Clone, Default)]
+pub struct BatchBuilder {
+    entries: Vec<EntryFunction>,
+}
+
+impl BatchBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an entry function to the batch.
+    ///
+    /// # Arguments:
+    ///
+    /// * `entry` - `EntryFunction` to call, e.g. created with the [`entry`](crate::entry) module.
+    pub fn push(&mut self, entry: EntryFunction) -> &mut Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Entry functions of the batch, in the order they are called.
+    pub fn entries(&self) -> &[EntryFunction] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Render the script calling every entry function of the batch and compile it.
+    ///
+    /// The signature of every entry function is read from the ABI of its module, which must
+    /// take a single signer as its first parameter.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`AptosTransport`] used to fetch the ABI of the called modules.
+    /// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `compiler` - `ScriptCompiler` used to compile the script.
+    pub async fn build(
+        &self,
+        client: &dyn AptosTransport,
+        econia_address: AccountAddress,
+        compiler: &ScriptCompiler,
+    ) -> EconiaResult<BatchScript> {
+        if self.entries.is_empty() {
+            return Err(EconiaError::EmptyBatch);
+        }
+
+        let mut abis: HashMap<ModuleId, Vec<MoveFunction>> = HashMap::new();
+        for entry in &self.entries {
+            let module = entry.module();
+            if abis.contains_key(module) {
+                continue;
+            }
+            let bytecode = client
+                .get_account_module(*module.address(), module.name().as_str())
+                .await?
+                .try_parse_abi()?;
+            let abi = bytecode.abi.ok_or(EconiaError::InvalidResponse)?;
+            abis.insert(module.clone(), abi.exposed_functions);
+        }
+
+        let mut type_parameters = vec![];
+        let mut parameters = vec![];
+        let mut calls = vec![];
+        let mut ty_args = vec![];
+        let mut args = vec![];
+        for (i, entry) in self.entries.iter().enumerate() {
+            let module = entry.module();
+            let name = format!("{}::{}", module, entry.function());
+            let function = abis[module]
+                .iter()
+                .find(|f| f.name.0.as_str() == entry.function().as_str())
+                .ok_or_else(|| EconiaError::InvalidBatchEntry(name.clone()))?;
+
+            let mut call_type_parameters = vec![];
+            for (j, param) in function.generic_type_params.iter().enumerate() {
+                let type_parameter = format!("T{}_{}", i, j);
+                let constraints = param
+                    .constraints
+                    .iter()
+                    .map(|c| c.to_string())
+                    .collect::<Vec<_>>();
+                if constraints.is_empty() {
+                    type_parameters.push(type_parameter.clone());
+                } else {
+                    type_parameters.push(format!(
+                        "{}: {}",
+                        type_parameter,
+                        constraints.join(" + ")
+                    ));
+                }
+                call_type_parameters.push(type_parameter);
+            }
+            ty_args.extend(entry.ty_args().iter().cloned());
+
+            // Scripts are signed by the user alone, which is the first parameter of the call.
+            let (signer, params) = function
+                .params
+                .split_first()
+                .ok_or_else(|| EconiaError::InvalidBatchEntry(name.clone()))?;
+            if !is_signer(signer)
+                || params.iter().any(is_signer)
+                || params.len() != entry.args().len()
+                || function.generic_type_params.len() != entry.ty_args().len()
+            {
+                return Err(EconiaError::InvalidBatchEntry(name));
+            }
+
+            let mut call_arguments = vec!["user".to_string()];
+            for (j, (param, arg)) in params.iter().zip(entry.args()).enumerate() {
+                let parameter = format!("a{}_{}", i, j);
+                parameters.push(format!("{}: {}", parameter, param));
+                args.push(transaction_argument(param, arg)?);
+                call_arguments.push(parameter);
+            }
+
+            let type_arguments = if call_type_parameters.is_empty() {
+                String::new()
+            } else {
+                format!("<{}>", call_type_parameters.join(", "))
+            };
+            calls.push(format!(
+                "        {}::{}::{}{}({});",
+                module.address().to_hex_literal(),
+                module.name(),
+                entry.function(),
+                type_arguments,
+                call_arguments.join(", "),
+            ));
+        }
+
+        let type_parameters = if type_parameters.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", type_parameters.join(", "))
+        };
+        let parameters = parameters
+            .iter()
+            .map(|p| format!(", {}", p))
+            .collect::<String>();
+        let source = SCRIPT_TEMPLATE
+            .replace("{{type_parameters}}", &type_parameters)
+            .replace("{{parameters}}", &parameters)
+            .replace("{{calls}}", &calls.join("\n"));
+
+        let code = compiler.compile(econia_address, &source).await?;
+        let operations = self
+            .entries
+            .iter()
+            .map(|e| Operation::decode(&econia_address, e))
+            .collect();
+
+        Ok(BatchScript {
+            script: Script::new(code, ty_args, args),
+            source,
+            operations,
+        })
+    }
+}
+
+/// Compiled script of a [`BatchBuilder`].
+#[derive(Debug, Clone)]
+pub struct BatchScript {
+    /// Script calling every entry function of the batch, with their arguments.
+    pub script: Script,
+    /// Move source the script was compiled from.
+    pub source: String,
+    operations: Vec<Operation>,
+}
+
+impl BatchScript {
+    /// Split the events of the batch transaction per entry function.
+    ///
+    /// Returns one list of events per entry function, in the order they were added to the batch.
+    /// The list is empty for entry functions which emit no events, e.g. deposits. A script
+    /// cannot mark where the events of one call end, so they are attributed as follows:
+    ///
+    /// * An entry function opens with the event of the order it places, cancels or resizes,
+    ///   matched on its arguments, e.g. the `PlaceLimitOrderEvent` of a limit order.
+    /// * Fill and cancel events of an order placed by the batch belong to the entry function
+    ///   which placed it, matched on the order ID as for the [`outcome`](crate::outcome) module.
+    /// * Any other event belongs to the last entry function opened, e.g. the cancel events of
+    ///   `cancel_all_orders_user`. Events emitted before any entry function opens are left out.
+    ///
+    /// Orders only emit events for market accounts with initialized event handles, see
+    /// [`init_market_event_handles_if_missing`](crate::entry::init_market_event_handles_if_missing).
+    ///
+    /// # Arguments:
+    ///
+    /// * `events` - [`EconiaEvent`]s emitted by the batch transaction, in order.
+    pub fn split_events(&self, events: &[EconiaEvent]) -> Vec<Vec<EconiaEvent>> {
+        let mut split = vec![vec![]; self.operations.len()];
+        // Entry function which placed each order, by market ID and order ID.
+        let mut placed: HashMap<(u64, u128), usize> = HashMap::new();
+        let mut current: Option<usize> = None;
+        for event in events {
+            let continued = current.filter(|&i| self.operations[i].continues(event));
+            let opened = || {
+                let start = current.map_or(0, |i| i + 1);
+                (start..self.operations.len()).find(|&i| self.operations[i].opens(event))
+            };
+            let owner = match continued.or_else(opened) {
+                Some(i) => {
+                    current = Some(i);
+                    if let Some(order) = placed_order(event) {
+                        placed.insert(order, i);
+                    }
+                    Some(i)
+                }
+                None => placed
+                    .iter()
+                    .find(|((market_id, order_id), _)| concerns_order(event, *market_id, *order_id))
+                    .map(|(_, &i)| i)
+                    .or(current),
+            };
+            if let Some(i) = owner {
+                split[i].push(event.clone());
+            }
+        }
+        split
+    }
+}
+
+/// A committed batch, see [`EconiaClient::submit_batch`](crate::EconiaClient::submit_batch).
+#[derive(Debug, Clone)]
+pub struct BatchTransaction {
+    /// The committed [`EconiaTransaction`], holding the events of every entry function.
+    pub transaction: EconiaTransaction,
+    /// [`EconiaEvent`]s emitted by each entry function of the batch, in order.
+    pub operations: Vec<Vec<EconiaEvent>>,
+}
+
+/// Compiles batch scripts with the Aptos CLI.
+#[derive(Debug)]
+pub struct ScriptCompiler {
+    aptos_cli: PathBuf,
+    econia_package_dir: PathBuf,
+    cache: Mutex<HashMap<(AccountAddress, String), Vec<u8>>>,
+}
+
+impl ScriptCompiler {
+    /// Create a script compiler.
+    ///
+    /// # Arguments:
+    ///
+    /// * `aptos_cli` - Path to the `aptos` executable.
+    /// * `econia_package_dir` - Path to the Econia Move package, the directory of its `Move.toml`.
+    pub fn new(aptos_cli: impl Into<PathBuf>, econia_package_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            aptos_cli: aptos_cli.into(),
+            econia_package_dir: econia_package_dir.into(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Compile a script, returning its bytecode.
+    ///
+    /// # Arguments:
+    ///
+    /// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `source` - Move source of the script.
+    pub async fn compile(
+        &self,
+        econia_address: AccountAddress,
+        source: &str,
+    ) -> EconiaResult<Vec<u8>> {
+        let key = (econia_address, source.to_string());
+        if let Some(code) = self.cache.lock().unwrap().get(&key) {
+            return Ok(code.clone());
+        }
+
+        let dir = std::env::temp_dir().join(format!(
+            "econia-batch-{}-{}",
+            std::process::id(),
+            NEXT_PACKAGE_DIR.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = self.compile_in(&dir, econia_address, source).await;
+        let _ = std::fs::remove_dir_all(&dir);
+        let code = result?;
+
+        self.cache.lock().unwrap().insert(key, code.clone());
+        Ok(code)
+    }
+
+    async fn compile_in(
+        &self,
+        dir: &Path,
+        econia_address: AccountAddress,
+        source: &str,
+    ) -> EconiaResult<Vec<u8>> {
+        let econia_package_dir = self.econia_package_dir.to_str().ok_or_else(|| {
+            EconiaError::Custom(anyhow!(
+                "the econia package directory is not valid unicode: {}",
+                self.econia_package_dir.display()
+            ))
+        })?;
+        let manifest = MANIFEST_TEMPLATE
+            .replace("{{econia_address}}", &econia_address.to_hex_literal())
+            .replace("{{econia_package_dir}}", &toml_string(econia_package_dir));
+        std::fs::create_dir_all(dir.join("sources"))
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        std::fs::write(dir.join("Move.toml"), manifest)
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        std::fs::write(dir.join("sources").join("batch.move"), source)
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+
+        let output_file = dir.join("batch.mv");
+        let output = tokio::process::Command::new(&self.aptos_cli)
+            .args(["move", "compile-script", "--package-dir"])
+            .arg(dir)
+            .arg("--output-file")
+            .arg(&output_file)
+            .output()
+            .await
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        if !output.status.success() {
+            return Err(EconiaError::ScriptCompilationFailed(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        std::fs::read(output_file).map_err(|e| EconiaError::Custom(anyhow!(e)))
+    }
+}
+
+/// Quote a string as a TOML basic string, escaping it.
+fn toml_string(value: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04X}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn is_signer(typ: &MoveType) -> bool {
+    match typ {
+        MoveType::Signer => true,
+        MoveType::Reference { to, .. } => matches!(**to, MoveType::Signer),
+        _ => false,
+    }
+}
+
+/// Turn the BCS encoded argument of an entry function into a script argument.
+fn transaction_argument(typ: &MoveType, arg: &[u8]) -> EconiaResult<TransactionArgument> {
+    Ok(match typ {
+        MoveType::Bool => TransactionArgument::Bool(bcs::from_bytes(arg)?),
+        MoveType::U8 => TransactionArgument::U8(bcs::from_bytes(arg)?),
+        MoveType::U16 => TransactionArgument::U16(bcs::from_bytes(arg)?),
+        MoveType::U32 => TransactionArgument::U32(bcs::from_bytes(arg)?),
+        MoveType::U64 => TransactionArgument::U64(bcs::from_bytes(arg)?),
+        MoveType::U128 => TransactionArgument::U128(bcs::from_bytes(arg)?),
+        MoveType::Address => TransactionArgument::Address(bcs::from_bytes(arg)?),
+        MoveType::Vector { items } if matches!(**items, MoveType::U8) => {
+            TransactionArgument::U8Vector(bcs::from_bytes(arg)?)
+        }
+        _ => return Err(EconiaError::UnsupportedBatchArgument(typ.to_string())),
+    })
+}
+
+/// What an entry function of a batch does, used to split the events of the batch.
+#[derive(Debug, Clone)]
+enum Operation {
+    PlaceLimitOrder {
+        market_id: u64,
+        size: u64,
+        price: Option<u64>,
+    },
+    PlaceMarketOrder {
+        market_id: u64,
+        size: u64,
+    },
+    Swap {
+        market_id: u64,
+    },
+    CancelOrder {
+        market_id: u64,
+        order_id: u128,
+    },
+    CancelAllOrders {
+        market_id: u64,
+    },
+    ChangeOrderSize {
+        market_id: u64,
+        order_id: u128,
+    },
+    Other,
+}
+
+impl Operation {
+    fn decode(econia_address: &AccountAddress, entry: &EntryFunction) -> Self {
+        let module = entry.module();
+        if module.address() != econia_address || module.name().as_str() != "market" {
+            return Self::Other;
+        }
+
+        let args = entry.args();
+        let u64_at = |i: usize| args.get(i).and_then(|a| bcs::from_bytes::<u64>(a).ok());
+        let u128_at = |i: usize| args.get(i).and_then(|a| bcs::from_bytes::<u128>(a).ok());
+        let Some(market_id) = u64_at(0) else {
+            return Self::Other;
+        };
+        let operation = match entry.function().as_str() {
+            "place_limit_order_user_entry" => {
+                u64_at(3)
+                    .zip(u64_at(4))
+                    .map(|(size, price)| Self::PlaceLimitOrder {
+                        market_id,
+                        size,
+                        price: Some(price),
+                    })
+            }
+            "place_limit_order_passive_advance_user_entry" => {
+                u64_at(3).map(|size| Self::PlaceLimitOrder {
+                    market_id,
+                    size,
+                    price: None,
+                })
+            }
+            "place_market_order_user_entry" => {
+                u64_at(3).map(|size| Self::PlaceMarketOrder { market_id, size })
+            }
+            "swap_between_coinstores_entry" => Some(Self::Swap { market_id }),
+            "cancel_order_user" => u128_at(2).map(|order_id| Self::CancelOrder {
+                market_id,
+                order_id,
+            }),
+            "cancel_all_orders_user" => Some(Self::CancelAllOrders { market_id }),
+            "change_order_size_user" => u128_at(2).map(|order_id| Self::ChangeOrderSize {
+                market_id,
+                order_id,
+            }),
+            _ => None,
+        };
+        operation.unwrap_or(Self::Other)
+    }
+
+    /// Whether `event` is the first event emitted by this operation.
+    fn opens(&self, event: &EconiaEvent) -> bool {
+        match (self, event) {
+            (
+                Self::PlaceLimitOrder {
+                    market_id,
+                    size,
+                    price,
+                },
+                EconiaEvent::PlaceLimitOrder(e),
+            ) => {
+                e.market_id == *market_id && e.size == *size && price.map_or(true, |p| e.price == p)
+            }
+            (Self::PlaceMarketOrder { market_id, size }, EconiaEvent::PlaceMarketOrder(e)) => {
+                e.market_id == *market_id && e.size == *size
+            }
+            (Self::Swap { market_id }, EconiaEvent::PlaceSwapOrder(e)) => e.market_id == *market_id,
+            (
+                Self::CancelOrder {
+                    market_id,
+                    order_id,
+                },
+                EconiaEvent::CancelOrder(e),
+            ) => {
+                e.market_id == *market_id
+                    && e.order_id == *order_id
+                    && matches!(e.reason, CancelReason::ManualCancel)
+            }
+            (Self::CancelAllOrders { .. }, _) => self.continues(event),
+            (
+                Self::ChangeOrderSize {
+                    market_id,
+                    order_id,
+                },
+                EconiaEvent::ChangeOrderSize(e),
+            ) => e.market_id == *market_id && e.order_id == *order_id,
+            _ => false,
+        }
+    }
+
+    /// Whether `event` is emitted by this operation once it opened, other than the fills and
+    /// cancels of the order it placed.
+    fn continues(&self, event: &EconiaEvent) -> bool {
+        match (self, event) {
+            (Self::CancelAllOrders { market_id }, EconiaEvent::CancelOrder(e)) => {
+                e.market_id == *market_id && matches!(e.reason, CancelReason::ManualCancel)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Market ID and order ID of the order placed by a place event.
+fn placed_order(event: &EconiaEvent) -> Option<(u64, u128)> {
+    match event {
+        EconiaEvent::PlaceLimitOrder(e) => Some((e.market_id, e.order_id)),
+        EconiaEvent::PlaceMarketOrder(e) => Some((e.market_id, e.order_id)),
+        EconiaEvent::PlaceSwapOrder(e) => Some((e.market_id, e.order_id)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aptos_sdk::move_types::language_storage::TypeTag;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::generated::entry;
+    use crate::tests::{mock_client, ECONIA};
+
+    const PLACED_ORDER_ID: u128 = 77;
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn coin() -> TypeTag {
+        TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap()
+    }
+
+    /// Cancel, deposit, limit order and cancel all, in that order.
+    fn batch() -> BatchBuilder {
+        let mut batch = BatchBuilder::new();
+        batch
+            .push(entry::market::cancel_order_user(econia(), 1, true, 5).unwrap())
+            .push(entry::user::deposit_from_coinstore(econia(), &coin(), 1, 0, 100).unwrap())
+            .push(
+                entry::market::place_limit_order_user_entry(
+                    econia(),
+                    &coin(),
+                    &coin(),
+                    1,
+                    AccountAddress::ONE,
+                    false,
+                    10,
+                    100,
+                    0,
+                    0,
+                )
+                .unwrap(),
+            )
+            .push(entry::market::cancel_all_orders_user(econia(), 2, true).unwrap());
+        batch
+    }
+
+    fn cancel_event(market_id: u64, order_id: u128, reason: u8) -> (String, Value) {
+        (
+            format!("{}::user::CancelOrderEvent", ECONIA),
+            json!({
+                "market_id": market_id.to_string(),
+                "order_id": order_id.to_string(),
+                "user": "0xa11ce",
+                "custodian_id": "",
+                "reason": reason,
+            }),
+        )
+    }
+
+    fn place_limit_event() -> (String, Value) {
+        (
+            format!("{}::user::PlaceLimitOrderEvent", ECONIA),
+            json!({
+                "market_id": "1",
+                "user": "0xa11ce",
+                "custodian_id": "",
+                "integrator": "0x1",
+                "side": false,
+                "size": "10",
+                "price": "100",
+                "restriction": 0,
+                "self_match_behavior": 0,
+                "remaining_size": "4",
+                "order_id": PLACED_ORDER_ID.to_string(),
+            }),
+        )
+    }
+
+    /// Fill of the placed order as taker, emitted once to the taker and once to the maker.
+    fn fill_event(size: u64, sequence_number: u64) -> (String, Value) {
+        (
+            format!("{}::user::FillEvent", ECONIA),
+            json!({
+                "market_id": "1",
+                "size": size.to_string(),
+                "price": "100",
+                "maker_side": true,
+                "maker": "0xb0b",
+                "maker_custodian_id": "",
+                "maker_order_id": "6",
+                "taker": "0xa11ce",
+                "taker_custodian_id": "",
+                "taker_order_id": PLACED_ORDER_ID.to_string(),
+                "taker_quote_fees_paid": "1",
+                "sequence_number_for_trade": sequence_number.to_string(),
+            }),
+        )
+    }
+
+    fn econia_event((_, data): &(String, Value)) -> EconiaEvent {
+        serde_json::from_value(data.clone()).unwrap()
+    }
+
+    /// ABI of a module exposing entry functions, as returned by the node.
+    #[cfg(unix)]
+    fn module_abi(name: &str, functions: &[(&str, usize, &[&str])]) -> Value {
+        let functions = functions
+            .iter()
+            .map(|(name, n_type_params, params)| {
+                json!({
+                    "name": name,
+                    "visibility": "public",
+                    "is_entry": true,
+                    "is_view": false,
+                    "generic_type_params": vec![json!({ "constraints": [] }); *n_type_params],
+                    "params": params,
+                    "return": [],
+                })
+            })
+            .collect::<Vec<_>>();
+        json!({
+            "address": ECONIA,
+            "name": name,
+            "friends": [],
+            "exposed_functions": functions,
+            "structs": [],
+        })
+    }
+
+    /// Write a shell script standing in for the Aptos CLI, which compiles every script to
+    /// placeholder bytecode.
+    #[cfg(unix)]
+    fn fake_aptos_cli() -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!("econia-fake-aptos-{}", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"#!/bin/sh
+while [ $# -gt 0 ]; do
+    if [ "$1" = --output-file ]; then printf batch > "$2"; fi
+    shift
+done
+"#,
+        )
+        .unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn split_events_per_entry_function() {
+        let batch = batch();
+        let script = BatchScript {
+            script: Script::new(vec![], vec![], vec![]),
+            source: String::new(),
+            operations: batch
+                .entries()
+                .iter()
+                .map(|e| Operation::decode(&econia(), e))
+                .collect(),
+        };
+        let events = [
+            cancel_event(1, 5, 3),
+            place_limit_event(),
+            fill_event(6, 1),
+            fill_event(6, 1),
+            cancel_event(2, 8, 3),
+            cancel_event(2, 9, 3),
+            // Attributed by order ID, even though the cancel all opened since.
+            cancel_event(1, PLACED_ORDER_ID, 2),
+        ];
+        let events = events.iter().map(econia_event).collect::<Vec<_>>();
+        let split = script.split_events(&events);
+        let lengths = split.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![1, 0, 4, 2]);
+        assert!(
+            matches!(&split[2][0], EconiaEvent::PlaceLimitOrder(e) if e.order_id == PLACED_ORDER_ID)
+        );
+        assert!(
+            matches!(&split[2][3], EconiaEvent::CancelOrder(e) if e.order_id == PLACED_ORDER_ID)
+        );
+        assert!(split[3]
+            .iter()
+            .all(|e| matches!(e, EconiaEvent::CancelOrder(e) if e.market_id == 2)));
+
+        // Events emitted before any entry function opens are left out.
+        let split = script.split_events(&[econia_event(&cancel_event(3, 1, 3))]);
+        assert!(split.iter().all(Vec::is_empty));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn submit_batch_on_mock() {
+        use crate::transport::MockOutcome;
+        use aptos_sdk::types::transaction::TransactionPayload;
+
+        let (transport, client) = mock_client().await;
+        transport
+            .set_module_abi(module_abi(
+                "market",
+                &[
+                    ("cancel_order_user", 0, &["&signer", "u64", "bool", "u128"]),
+                    ("cancel_all_orders_user", 0, &["&signer", "u64", "bool"]),
+                    (
+                        "place_limit_order_user_entry",
+                        2,
+                        &[
+                            "&signer", "u64", "address", "bool", "u64", "u64", "u8", "u8",
+                        ],
+                    ),
+                ],
+            ))
+            .unwrap();
+        transport
+            .set_module_abi(module_abi(
+                "user",
+                &[(
+                    "deposit_from_coinstore",
+                    1,
+                    &["&signer", "u64", "u64", "u64"],
+                )],
+            ))
+            .unwrap();
+        transport.push_outcome(MockOutcome::Committed(vec![
+            cancel_event(1, 5, 3),
+            place_limit_event(),
+            fill_event(6, 1),
+            fill_event(6, 1),
+            cancel_event(2, 8, 3),
+        ]));
+
+        let aptos_cli = fake_aptos_cli();
+        let compiler = ScriptCompiler::new(&aptos_cli, "/tmp/econia");
+        let batch = client.submit_batch(&batch(), &compiler).await;
+        let _ = std::fs::remove_file(aptos_cli);
+        let batch = batch.unwrap();
+
+        assert_eq!(batch.transaction.events.len(), 5);
+        assert_eq!(batch.transaction.orders.len(), 1);
+        let lengths = batch.operations.iter().map(Vec::len).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![1, 0, 3, 1]);
+
+        let submitted = transport.submitted();
+        let TransactionPayload::Script(script) = submitted[0].payload() else {
+            panic!("batch not submitted as a script");
+        };
+        assert_eq!(script.code(), b"batch");
+        // The type arguments and arguments of every call, the signer excluded, in order.
+        assert_eq!(script.ty_args(), &[coin(), coin(), coin()]);
+        assert_eq!(script.args().len(), 3 + 3 + 7 + 2);
+        assert!(matches!(script.args()[2], TransactionArgument::U128(5)));
+    }
+
+    #[test]
+    fn quote_toml_strings() {
+        assert_eq!(toml_string("/home/econia/move"), r#""/home/econia/move""#);
+        assert_eq!(
+            toml_string(r#"C:\Users\a "b"\econia"#),
+            r#""C:\\Users\\a \"b\"\\econia""#
+        );
+        assert_eq!(toml_string("a\tb\n"), r#""a\u0009b\u000A""#);
+    }
+
+    #[test]
+    fn render_manifest() {
+        let manifest = MANIFEST_TEMPLATE
+            .replace("{{econia_address}}", "0xc0deb00c")
+            .replace("{{econia_package_dir}}", &toml_string(r#"/tmp/"econia""#));
+        assert!(manifest.contains(r#"local = "/tmp/\"econia\"""#));
+    }
+}
@@ -0,0 +1,555 @@
+//! The [`book`](crate::book) module exposes an [`OrderBook`] which replicates the order book of a
+//! market locally.
+//!
+//! The order book is bootstrapped from a snapshot of the open orders of the market, then kept up
+//! to date by applying the events emitted after the snapshot, e.g. as streamed by an
+//! [`EventSubscription`](crate::subscription::EventSubscription):
+//!
+//! * `PlaceLimitOrderEvent`s add the orders that post to the book.
+//! * `FillEvent`s reduce the size of maker orders.
+//! * `ChangeOrderSizeEvent`s set the size of orders.
+//! * `CancelOrderEvent`s remove orders.
+//!
+//! Events that do not match the book, such as a fill or a cancellation of an order the book does
+//! not have, reveal that some events were missed. The book then refuses further events until it
+//! is re-snapshotted with [`OrderBook::resync`], which [`OrderBook::apply_or_resync`] does
+//! automatically. As the events of a transaction may be received in any order, a cancellation of
+//! an unknown order is only reported as a gap once an event of a later transaction is applied,
+//! if the order was not placed by the same transaction in the meantime.
+//!
+//! Econia emits order events to the event handles of the market accounts of their users, and to
+//! those of the market for swaps only. The book must hence be fed every event of the market, for
+//! instance from an indexer of every Econia event such as the one of the data service stack, or by
+//! subscribing to the market and to the market account of every user trading on it. An
+//! [`EventSubscription::market`](crate::subscription::EventSubscription::market) alone misses the
+//! orders of market accounts, which then surface as gaps.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+
+use aptos_sdk::move_types::account_address::AccountAddress;
+use econia_types::events::EconiaEvent;
+use econia_types::order::Side;
+
+use crate::errors::{EconiaError, OrderBookGap};
+use crate::view::{EconiaViewClient, OrderView, PriceLevel};
+use crate::EconiaResult;
+
+/// Amount of orders per side to fetch per page when snapshotting the order book.
+pub const SNAPSHOT_PAGE_SIZE: u64 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookOrder {
+    pub order_id: u128,
+    pub side: Side,
+    /// Price, in ticks per lot.
+    pub price: u64,
+    /// Remaining size, in lots.
+    pub remaining_size: u64,
+    pub user: AccountAddress,
+    pub custodian_id: u64,
+}
+
+impl From<OrderView> for BookOrder {
+    fn from(order: OrderView) -> Self {
+        Self {
+            order_id: order.order_id,
+            side: order.side,
+            price: order.price,
+            remaining_size: order.remaining_size,
+            user: order.user,
+            custodian_id: order.custodian_id,
+        }
+    }
+}
+
+/// Local replica of the order book of a market, see the [module documentation](crate::book).
+#[derive(Debug, Clone)]
+pub struct OrderBook {
+    market_id: u64,
+    orders: HashMap<u128, BookOrder>,
+    /// Total size per price, for each side.
+    asks: BTreeMap<u64, u128>,
+    bids: BTreeMap<u64, u128>,
+    snapshot_version: u64,
+    /// Version of the last applied event.
+    version: u64,
+    /// Fills applied at `version`, as fills are emitted to both the maker and the taker.
+    fills: HashSet<(u128, u128, u64)>,
+    /// Orders placed and cancelled at `version`.
+    placed: HashSet<u128>,
+    cancelled: HashSet<u128>,
+    /// Orders cancelled at `version` which are not in the book and were not placed at `version`.
+    unknown_cancels: HashSet<u128>,
+    gap: Option<OrderBookGap>,
+}
+
+impl OrderBook {
+    /// Snapshot the order book of a market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `view_client` - [`EconiaViewClient`] to fetch the open orders with.
+    /// * `market_id` - Market ID of the market.
+    pub async fn snapshot(
+        view_client: &EconiaViewClient<'_>,
+        market_id: u64,
+    ) -> EconiaResult<Self> {
+        let mut book = Self {
+            market_id,
+            orders: HashMap::new(),
+            asks: BTreeMap::new(),
+            bids: BTreeMap::new(),
+            snapshot_version: 0,
+            version: 0,
+            fills: HashSet::new(),
+            placed: HashSet::new(),
+            cancelled: HashSet::new(),
+            unknown_cancels: HashSet::new(),
+            gap: None,
+        };
+        book.resync(view_client).await?;
+        Ok(book)
+    }
+
+    /// Replace the content of the order book with a new snapshot, clearing any gap.
+    ///
+    /// Every page of the snapshot is indexed at the same ledger version, events emitted at or
+    /// before it are then ignored.
+    ///
+    /// # Arguments:
+    ///
+    /// * `view_client` - [`EconiaViewClient`] to fetch the open orders with.
+    pub async fn resync(&mut self, view_client: &EconiaViewClient<'_>) -> EconiaResult<()> {
+        let mut paginator = view_client.open_orders_paginator(
+            self.market_id,
+            SNAPSHOT_PAGE_SIZE,
+            SNAPSHOT_PAGE_SIZE,
+        );
+        let mut orders = vec![];
+        while let Some(page) = paginator.next_page().await? {
+            orders.extend(page.asks);
+            orders.extend(page.bids);
+        }
+
+        self.snapshot_version = paginator
+            .ledger_version()
+            .ok_or(EconiaError::InvalidResponse)?;
+        self.version = self.snapshot_version;
+        self.orders.clear();
+        self.asks.clear();
+        self.bids.clear();
+        self.fills.clear();
+        self.placed.clear();
+        self.cancelled.clear();
+        self.unknown_cancels.clear();
+        self.gap = None;
+        for order in orders {
+            self.insert(order.into());
+        }
+        Ok(())
+    }
+
+    /// Apply an event to the order book.
+    ///
+    /// Events of other markets, events emitted at or before the snapshot and events which do not
+    /// change the order book are ignored. Fills are applied once even if received twice.
+    ///
+    /// Returns [`EconiaError::OrderBookGap`] if the event reveals that events were missed, in
+    /// which case every following event is refused until the order book is resynchronized.
+    ///
+    /// # Arguments:
+    ///
+    /// * `version` - Version of the transaction which emitted the event.
+    /// * `event` - [`EconiaEvent`] to apply.
+    pub fn apply(&mut self, version: u64, event: &EconiaEvent) -> EconiaResult<()> {
+        if let Some(gap) = self.gap {
+            return Err(gap.into());
+        }
+        if version <= self.snapshot_version {
+            return Ok(());
+        }
+        match self.apply_event(version, event) {
+            Err(EconiaError::OrderBookGap(gap)) => {
+                self.gap = Some(gap);
+                Err(gap.into())
+            }
+            result => result,
+        }
+    }
+
+    /// Apply an event to the order book, re-snapshotting it if a gap is detected.
+    ///
+    /// # Arguments:
+    ///
+    /// * `view_client` - [`EconiaViewClient`] to fetch the open orders with.
+    /// * `version` - Version of the transaction which emitted the event.
+    /// * `event` - [`EconiaEvent`] to apply.
+    pub async fn apply_or_resync(
+        &mut self,
+        view_client: &EconiaViewClient<'_>,
+        version: u64,
+        event: &EconiaEvent,
+    ) -> EconiaResult<()> {
+        match self.apply(version, event) {
+            Err(EconiaError::OrderBookGap(_)) => self.resync(view_client).await,
+            result => result,
+        }
+    }
+
+    fn apply_event(&mut self, version: u64, event: &EconiaEvent) -> EconiaResult<()> {
+        // The book may be crossed in the middle of a transaction, as the order placed by a taker
+        // is added before the fills which reduce the makers.
+        if version > self.version {
+            if let (Some(bid), Some(ask)) = (self.best_bid(), self.best_ask()) {
+                if bid.price >= ask.price {
+                    return Err(OrderBookGap::CrossedBook.into());
+                }
+            }
+            if let Some(order_id) = self.unknown_cancels.iter().next() {
+                return Err(OrderBookGap::UnknownOrder(*order_id).into());
+            }
+            self.version = version;
+            self.fills.clear();
+            self.placed.clear();
+            self.cancelled.clear();
+        }
+
+        match event {
+            EconiaEvent::PlaceLimitOrder(e) if e.market_id == self.market_id => {
+                self.place(e.order_id);
+                // Orders which did not post, or which were filled entirely, are not in the book.
+                if e.remaining_size > 0
+                    && !self.orders.contains_key(&e.order_id)
+                    && !self.cancelled.contains(&e.order_id)
+                {
+                    self.insert(BookOrder {
+                        order_id: e.order_id,
+                        side: e.side,
+                        price: e.price,
+                        remaining_size: e.remaining_size,
+                        user: AccountAddress::from_str(&e.user)?,
+                        custodian_id: e.custodian_id.unwrap_or_default(),
+                    });
+                }
+            }
+            EconiaEvent::Fill(e) if e.market_id == self.market_id => {
+                let fill = (
+                    e.maker_order_id,
+                    e.taker_order_id,
+                    e.sequence_number_for_trade,
+                );
+                if !self.fills.insert(fill) {
+                    return Ok(());
+                }
+                let order = self
+                    .orders
+                    .get(&e.maker_order_id)
+                    .ok_or(OrderBookGap::UnknownOrder(e.maker_order_id))?;
+                let remaining_size = order.remaining_size;
+                if remaining_size < e.size {
+                    return Err(OrderBookGap::FillExceedsOrder {
+                        order_id: e.maker_order_id,
+                        size: e.size,
+                    }
+                    .into());
+                }
+                self.resize(e.maker_order_id, remaining_size - e.size);
+            }
+            EconiaEvent::ChangeOrderSize(e) if e.market_id == self.market_id => {
+                if !self.orders.contains_key(&e.order_id) {
+                    return Err(OrderBookGap::UnknownOrder(e.order_id).into());
+                }
+                self.resize(e.order_id, e.new_size);
+            }
+            EconiaEvent::PlaceMarketOrder(e) if e.market_id == self.market_id => {
+                self.place(e.order_id);
+            }
+            EconiaEvent::PlaceSwapOrder(e) if e.market_id == self.market_id => {
+                self.place(e.order_id);
+            }
+            EconiaEvent::CancelOrder(e) if e.market_id == self.market_id => {
+                // Taker orders that did not post are cancelled too, they are not in the book but
+                // are placed by the same transaction.
+                if self.orders.contains_key(&e.order_id) {
+                    self.resize(e.order_id, 0);
+                } else if !self.placed.contains(&e.order_id)
+                    && !self.cancelled.contains(&e.order_id)
+                {
+                    self.unknown_cancels.insert(e.order_id);
+                }
+                self.cancelled.insert(e.order_id);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Record an order placed at the current version.
+    fn place(&mut self, order_id: u128) {
+        self.placed.insert(order_id);
+        self.unknown_cancels.remove(&order_id);
+    }
+
+    fn levels_mut(&mut self, side: Side) -> &mut BTreeMap<u64, u128> {
+        match side {
+            Side::Ask => &mut self.asks,
+            Side::Bid => &mut self.bids,
+        }
+    }
+
+    fn insert(&mut self, order: BookOrder) {
+        *self.levels_mut(order.side).entry(order.price).or_default() +=
+            order.remaining_size as u128;
+        self.orders.insert(order.order_id, order);
+    }
+
+    /// Set the remaining size of an order, removing it if the size is 0.
+    fn resize(&mut self, order_id: u128, size: u64) {
+        let Some(order) = self.orders.get_mut(&order_id) else {
+            return;
+        };
+        let (side, price, previous) = (order.side, order.price, order.remaining_size);
+        if size == 0 {
+            self.orders.remove(&order_id);
+        } else {
+            order.remaining_size = size;
+        }
+
+        let levels = self.levels_mut(side);
+        let level = levels.entry(price).or_default();
+        *level = *level + size as u128 - previous as u128;
+        if *level == 0 {
+            levels.remove(&price);
+        }
+    }
+
+    pub fn market_id(&self) -> u64 {
+        self.market_id
+    }
+
+    /// Ledger version of the last snapshot.
+    pub fn snapshot_version(&self) -> u64 {
+        self.snapshot_version
+    }
+
+    /// The gap detected since the last snapshot, if any.
+    pub fn gap(&self) -> Option<OrderBookGap> {
+        self.gap
+    }
+
+    /// Get an open order.
+    ///
+    /// # Arguments:
+    ///
+    /// * `order_id` - Order ID of the order.
+    pub fn order(&self, order_id: u128) -> Option<&BookOrder> {
+        self.orders.get(&order_id)
+    }
+
+    /// Amount of open orders.
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Best ask price level, with the total size at that price.
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(|(price, size)| PriceLevel {
+            price: *price,
+            size: *size,
+        })
+    }
+
+    /// Best bid price level, with the total size at that price.
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, size)| PriceLevel {
+                price: *price,
+                size: *size,
+            })
+    }
+
+    /// Get the best price levels of a side, best first.
+    ///
+    /// # Arguments:
+    ///
+    /// * `side` - Side of the order book.
+    /// * `n_levels` - Maximum amount of price levels to return.
+    pub fn depth(&self, side: Side, n_levels: usize) -> Vec<PriceLevel> {
+        let level = |(price, size): (&u64, &u128)| PriceLevel {
+            price: *price,
+            size: *size,
+        };
+        match side {
+            Side::Ask => self.asks.iter().take(n_levels).map(level).collect(),
+            Side::Bid => self.bids.iter().rev().take(n_levels).map(level).collect(),
+        }
+    }
+
+    /// Get the open orders of a market account, sorted by order ID.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `custodian_id` - Custodian ID of the market account.
+    pub fn user_orders(&self, user: AccountAddress, custodian_id: u64) -> Vec<&BookOrder> {
+        let mut orders = self
+            .orders
+            .values()
+            .filter(|o| o.user == user && o.custodian_id == custodian_id)
+            .collect::<Vec<_>>();
+        orders.sort_by_key(|o| o.order_id);
+        orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use econia_types::order::CancelReason;
+
+    use super::*;
+    use crate::tests::events;
+
+    fn book(orders: Vec<BookOrder>) -> OrderBook {
+        let mut book = OrderBook {
+            market_id: 1,
+            orders: HashMap::new(),
+            asks: BTreeMap::new(),
+            bids: BTreeMap::new(),
+            snapshot_version: 10,
+            version: 10,
+            fills: HashSet::new(),
+            placed: HashSet::new(),
+            cancelled: HashSet::new(),
+            unknown_cancels: HashSet::new(),
+            gap: None,
+        };
+        for order in orders {
+            book.insert(order);
+        }
+        book
+    }
+
+    fn order(order_id: u128, side: Side, price: u64, remaining_size: u64) -> BookOrder {
+        BookOrder {
+            order_id,
+            side,
+            price,
+            remaining_size,
+            user: AccountAddress::ONE,
+            custodian_id: 0,
+        }
+    }
+
+    fn ask(order_id: u128, price: u64, remaining_size: u64) -> BookOrder {
+        order(order_id, Side::Ask, price, remaining_size)
+    }
+
+    fn place(order_id: u128, size: u64, remaining_size: u64) -> EconiaEvent {
+        events::place_limit(order_id, Side::Ask, size, 100, remaining_size)
+    }
+
+    fn cancel(order_id: u128) -> EconiaEvent {
+        events::cancel(order_id, CancelReason::ImmediateOrCancel)
+    }
+
+    #[test]
+    fn cancel_known_order() {
+        let mut book = book(vec![ask(1, 100, 5), ask(2, 100, 3)]);
+        book.apply(11, &cancel(1)).unwrap();
+        book.apply(12, &cancel(2)).unwrap();
+        assert!(book.is_empty());
+        assert_eq!(book.best_ask(), None);
+    }
+
+    #[test]
+    fn cancel_order_placed_by_same_transaction() {
+        let mut book = book(vec![]);
+        // Events of different handles are not ordered within a transaction.
+        book.apply(11, &cancel(1)).unwrap();
+        book.apply(11, &place(1, 5, 5)).unwrap();
+        book.apply(12, &place(2, 5, 0)).unwrap();
+        book.apply(12, &cancel(2)).unwrap();
+        book.apply(13, &place(3, 5, 5)).unwrap();
+        assert_eq!(book.len(), 1);
+    }
+
+    #[test]
+    fn cancel_unknown_order() {
+        let mut book = book(vec![ask(1, 100, 5)]);
+        book.apply(11, &cancel(2)).unwrap();
+        assert_eq!(
+            book.apply(12, &cancel(1)).unwrap_err().to_string(),
+            EconiaError::from(OrderBookGap::UnknownOrder(2)).to_string()
+        );
+        assert_eq!(book.gap(), Some(OrderBookGap::UnknownOrder(2)));
+        assert!(book.order(1).is_some());
+    }
+
+    #[test]
+    fn apply_fills_once() {
+        let mut book = book(vec![ask(1, 100, 5), ask(2, 101, 5)]);
+        // The taker and maker copies of a fill are received in any order.
+        book.apply(11, &events::fill(1, 7, 2, 100, 0)).unwrap();
+        book.apply(11, &events::fill(1, 7, 2, 100, 0)).unwrap();
+        book.apply(11, &events::fill(2, 7, 1, 101, 1)).unwrap();
+        book.apply(11, &events::fill(1, 7, 2, 100, 2)).unwrap();
+        book.apply(11, &events::fill(2, 7, 1, 101, 1)).unwrap();
+        book.apply(11, &events::fill(1, 7, 2, 100, 2)).unwrap();
+        assert_eq!(book.order(1).unwrap().remaining_size, 1);
+        assert_eq!(book.order(2).unwrap().remaining_size, 4);
+        assert_eq!(book.best_ask().map(|l| (l.price, l.size)), Some((100, 1)));
+
+        // A fill larger than what is left reveals missed events.
+        assert!(matches!(
+            book.apply(12, &events::fill(1, 8, 2, 100, 0)),
+            Err(EconiaError::OrderBookGap(OrderBookGap::FillExceedsOrder {
+                order_id: 1,
+                size: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn change_order_size() {
+        let mut book = book(vec![ask(1, 100, 5), ask(2, 100, 3)]);
+        book.apply(11, &events::change_size(1, Side::Ask, 2))
+            .unwrap();
+        assert_eq!(book.order(1).unwrap().remaining_size, 2);
+        assert_eq!(book.depth(Side::Ask, 1)[0].size, 5);
+
+        assert!(matches!(
+            book.apply(12, &events::change_size(3, Side::Ask, 2)),
+            Err(EconiaError::OrderBookGap(OrderBookGap::UnknownOrder(3)))
+        ));
+        assert_eq!(book.gap(), Some(OrderBookGap::UnknownOrder(3)));
+    }
+
+    #[test]
+    fn cross_within_a_transaction_only() {
+        let mut book = book(vec![ask(1, 100, 5)]);
+        // A bid crossing the ask is added before the fill which consumes the ask.
+        book.apply(11, &events::place_limit(7, Side::Bid, 8, 100, 3))
+            .unwrap();
+        book.apply(11, &events::fill(1, 7, 5, 100, 0)).unwrap();
+        book.apply(12, &cancel(7)).unwrap();
+        assert!(book.is_empty());
+
+        // Without the fill, the book is still crossed once the transaction is over.
+        book.apply(13, &events::place_limit(8, Side::Bid, 5, 101, 5))
+            .unwrap();
+        book.apply(14, &events::place_limit(9, Side::Ask, 5, 101, 5))
+            .unwrap();
+        assert!(matches!(
+            book.apply(15, &cancel(8)),
+            Err(EconiaError::OrderBookGap(OrderBookGap::CrossedBook))
+        ));
+        assert_eq!(book.gap(), Some(OrderBookGap::CrossedBook));
+    }
+}
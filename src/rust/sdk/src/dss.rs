@@ -0,0 +1,727 @@
+//! The [`dss`](crate::dss) module exposes a [`DssClient`] to query the REST API of the Econia
+//! data service stack (DSS), a PostgREST server exposing the indexed history of the exchange.
+//!
+//! Every table of the API is queried with a [`Query`], which maps its filters, ordering and
+//! pagination onto the PostgREST query string syntax:
+//!
+//! ```ignore
+//! let query = Query::new()
+//!     .eq("market_id", 1)
+//!     .eq("user", "0x1234")
+//!     .order_by("created_at", SortOrder::Desc)
+//!     .page(0, 100);
+//! let orders = dss_client.orders(&query).await?;
+//! ```
+//!
+//! renders as `?market_id=eq.1&user=eq.0x1234&order=created_at.desc&limit=100&offset=0`.
+//!
+//! The client only needs the base URL of the API, so it can be pointed at a local mock server.
+//!
+//! Amounts are returned as on chain, in lots, ticks and subunits. Columns PostgREST returns as
+//! arbitrary precision decimals are returned as [`BigDecimal`].
+
+use std::fmt::Display;
+
+use anyhow::anyhow;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use econia_types::order::Side;
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::errors::EconiaError;
+use crate::EconiaResult;
+
+/// Seconds in a day, the period of the 24 hour data of [`DssClient::market_aggregated_info`].
+pub const SECONDS_PER_DAY: u32 = 86_400;
+
+/// PostgREST filter operators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    Neq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Like,
+    ILike,
+    Is,
+    In,
+}
+
+impl Operator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eq => "eq",
+            Self::Neq => "neq",
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+            Self::Like => "like",
+            Self::ILike => "ilike",
+            Self::Is => "is",
+            Self::In => "in",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Filters, ordering and pagination of a request to the DSS API, see the
+/// [module documentation](crate::dss).
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    filters: Vec<(String, String)>,
+    order: Vec<String>,
+    select: Option<String>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the rows whose `column` compares to `value` with `operator`.
+    ///
+    /// # Arguments:
+    ///
+    /// * `column` - Name of the column to filter on.
+    /// * `operator` - PostgREST [`Operator`] to compare with.
+    /// * `value` - Value to compare to, as PostgREST expects it.
+    pub fn filter(mut self, column: &str, operator: Operator, value: impl Display) -> Self {
+        self.filters.push((
+            column.to_string(),
+            format!("{}.{}", operator.as_str(), value),
+        ));
+        self
+    }
+
+    /// Keep the rows whose `column` equals `value`.
+    pub fn eq(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Eq, value)
+    }
+
+    /// Keep the rows whose `column` does not equal `value`.
+    pub fn neq(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Neq, value)
+    }
+
+    /// Keep the rows whose `column` is greater than `value`.
+    pub fn gt(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Gt, value)
+    }
+
+    /// Keep the rows whose `column` is greater than or equal to `value`.
+    pub fn gte(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Gte, value)
+    }
+
+    /// Keep the rows whose `column` is less than `value`.
+    pub fn lt(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Lt, value)
+    }
+
+    /// Keep the rows whose `column` is less than or equal to `value`.
+    pub fn lte(self, column: &str, value: impl Display) -> Self {
+        self.filter(column, Operator::Lte, value)
+    }
+
+    /// Keep the rows whose `column` is one of `values`.
+    pub fn is_in<T: Display>(self, column: &str, values: impl IntoIterator<Item = T>) -> Self {
+        let values = values
+            .into_iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        self.filter(column, Operator::In, format!("({})", values.join(",")))
+    }
+
+    /// Keep the rows whose `column` is null.
+    pub fn is_null(self, column: &str) -> Self {
+        self.filter(column, Operator::Is, "null")
+    }
+
+    /// Sort the rows by `column`, after the columns already sorted by.
+    pub fn order_by(mut self, column: &str, order: SortOrder) -> Self {
+        let order = match order {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        };
+        self.order.push(format!("{}.{}", column, order));
+        self
+    }
+
+    /// Only return `columns`. The response type must then tolerate the missing columns.
+    pub fn select(mut self, columns: &[&str]) -> Self {
+        self.select = Some(columns.join(","));
+        self
+    }
+
+    /// Return at most `limit` rows.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skip the first `offset` rows.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Return the page `page`, starting at 0, of pages of `page_size` rows.
+    pub fn page(self, page: u64, page_size: u64) -> Self {
+        self.limit(page_size).offset(page * page_size)
+    }
+
+    /// Query string parameters of the query, in PostgREST syntax.
+    pub fn params(&self) -> Vec<(String, String)> {
+        let mut params = self.filters.clone();
+        if !self.order.is_empty() {
+            params.push(("order".to_string(), self.order.join(",")));
+        }
+        if let Some(select) = &self.select {
+            params.push(("select".to_string(), select.clone()));
+        }
+        if let Some(limit) = self.limit {
+            params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(offset) = self.offset {
+            params.push(("offset".to_string(), offset.to_string()));
+        }
+        params
+    }
+}
+
+/// Direction of an order, as stored by the DSS. Limit orders have a side, market orders and
+/// swaps a direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderDirection {
+    Bid,
+    Ask,
+    Buy,
+    Sell,
+}
+
+impl OrderDirection {
+    /// [`Side`] of the order, a buy is a bid and a sell an ask.
+    pub fn side(&self) -> Side {
+        match self {
+            Self::Bid | Self::Buy => Side::Bid,
+            Self::Ask | Self::Sell => Side::Ask,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderStatus {
+    Open,
+    Closed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrderType {
+    Limit,
+    Market,
+    Swap,
+}
+
+/// A row of `/fill_events_deduped`, one per fill.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Fill {
+    pub txn_version: u64,
+    pub event_idx: u64,
+    pub emit_address: String,
+    pub time: DateTime<Utc>,
+    pub maker_address: String,
+    pub maker_custodian_id: u64,
+    pub maker_order_id: u128,
+    pub maker_side: Side,
+    pub market_id: u64,
+    pub price: u64,
+    pub sequence_number_for_trade: u64,
+    pub size: u64,
+    pub taker_address: String,
+    pub taker_custodian_id: u64,
+    pub taker_order_id: u128,
+    pub taker_quote_fees_paid: u64,
+}
+
+/// A row of `/orders`, the history of every order placed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OrderHistory {
+    pub market_id: u64,
+    pub order_id: u128,
+    pub created_at: DateTime<Utc>,
+    pub last_updated_at: Option<DateTime<Utc>>,
+    pub integrator: Option<String>,
+    pub total_filled: u64,
+    pub remaining_size: u64,
+    pub order_status: OrderStatus,
+    pub order_type: OrderType,
+    pub user: String,
+    pub direction: OrderDirection,
+    /// Price of limit orders, in ticks per lot.
+    pub price: Option<u64>,
+    pub average_execution_price: Option<BigDecimal>,
+    pub custodian_id: Option<u64>,
+    pub self_match_behavior: Option<u8>,
+    pub restriction: Option<u8>,
+    pub last_increase_stamp: Option<u64>,
+    pub min_base: Option<u64>,
+    pub max_base: Option<u64>,
+    pub min_quote: Option<u64>,
+    pub max_quote: Option<u64>,
+    pub total_fees_paid_in_quote_subunits: u64,
+}
+
+/// A row of `/user_balances`, the latest balances of a market account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserBalance {
+    pub address: String,
+    pub custodian_id: u64,
+    pub market_id: u64,
+    pub handle: String,
+    pub base_total: u64,
+    pub base_available: u64,
+    pub base_ceiling: u64,
+    pub quote_total: u64,
+    pub quote_available: u64,
+    pub quote_ceiling: u64,
+}
+
+/// A row of `/candlesticks`. Prices are `None` for periods without fills.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Candlestick {
+    pub market_id: u64,
+    /// Duration of the candlestick, in seconds.
+    pub resolution: u32,
+    pub start_time: DateTime<Utc>,
+    pub open: Option<u64>,
+    pub high: Option<u64>,
+    pub low: Option<u64>,
+    pub close: Option<u64>,
+    /// Base volume, in lots.
+    pub volume: u64,
+}
+
+/// A row of `/markets`, the parameters of a market along with its 24 hour data.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Market {
+    pub market_id: u64,
+    pub registration_time: DateTime<Utc>,
+    pub base_account_address: Option<String>,
+    pub base_module_name: Option<String>,
+    pub base_struct_name: Option<String>,
+    pub base_name_generic: Option<String>,
+    pub quote_account_address: String,
+    pub quote_module_name: String,
+    pub quote_struct_name: String,
+    pub lot_size: u64,
+    pub tick_size: u64,
+    pub min_size: u64,
+    pub underwriter_id: u64,
+    pub is_recognized: Option<bool>,
+    pub last_fill_price_24hr: Option<BigDecimal>,
+    pub price_change_as_percent_24hr: Option<BigDecimal>,
+    pub price_change_24hr: Option<BigDecimal>,
+    pub min_price_24h: Option<BigDecimal>,
+    pub max_price_24h: Option<BigDecimal>,
+    pub base_volume_24h: Option<BigDecimal>,
+    pub quote_volume_24h: Option<BigDecimal>,
+    pub base_name: Option<String>,
+    pub base_decimals: Option<u8>,
+    pub base_symbol: Option<String>,
+    pub quote_name: Option<String>,
+    pub quote_decimals: Option<u8>,
+    pub quote_symbol: Option<String>,
+}
+
+/// Aggregated data of a market over a period, returned by
+/// [`DssClient::market_aggregated_info`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketAggregatedInfo {
+    pub last_price: Option<BigDecimal>,
+    pub price_change_percentage: Option<BigDecimal>,
+    pub price_change_nominal: Option<BigDecimal>,
+    pub high_price: Option<BigDecimal>,
+    pub low_price: Option<BigDecimal>,
+    pub base_volume: Option<BigDecimal>,
+    pub quote_volume: Option<BigDecimal>,
+}
+
+/// A row of `/competition_leaderboard_users`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LeaderboardUser {
+    pub user: String,
+    /// Volume traded, in quote subunits.
+    pub volume: u128,
+    pub integrators_used: Vec<String>,
+    pub n_trades: u32,
+    pub points: BigDecimal,
+    pub competition_id: i32,
+    pub rank: i64,
+}
+
+/// Queries the DSS REST API, see the [module documentation](crate::dss).
+#[derive(Debug, Clone)]
+pub struct DssClient {
+    client: reqwest::Client,
+    base_url: Url,
+}
+
+impl DssClient {
+    /// # Arguments:
+    ///
+    /// * `base_url` - Url of the DSS REST API, e.g. `http://localhost:3000`.
+    pub fn new(base_url: Url) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url)
+    }
+
+    /// # Arguments:
+    ///
+    /// * `client` - `reqwest::Client` to send the requests with, e.g. to set timeouts.
+    /// * `base_url` - Url of the DSS REST API, e.g. `http://localhost:3000`.
+    pub fn with_client(client: reqwest::Client, mut base_url: Url) -> Self {
+        // Paths are joined to the base url, which must end with a slash to keep its own path.
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+        Self { client, base_url }
+    }
+
+    /// Query the rows of any table or view of the API.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path of the table, e.g. `market_registration_events`.
+    /// * `query` - [`Query`] to filter, order and paginate the rows with.
+    pub async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &Query,
+    ) -> EconiaResult<Vec<T>> {
+        let url = self
+            .base_url
+            .join(path.trim_start_matches('/'))
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        let response = self
+            .client
+            .get(url)
+            .query(&query.params())
+            .send()
+            .await
+            .map_err(|e| EconiaError::DssError(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(EconiaError::DssError(format!("{}: {}", status, body)));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| EconiaError::DssError(e.to_string()))
+    }
+
+    /// Query `/fill_events_deduped`, every fill once.
+    pub async fn fills(&self, query: &Query) -> EconiaResult<Vec<Fill>> {
+        self.get("fill_events_deduped", query).await
+    }
+
+    /// Query `/orders`, the history of every order.
+    pub async fn orders(&self, query: &Query) -> EconiaResult<Vec<OrderHistory>> {
+        self.get("orders", query).await
+    }
+
+    /// Query `/user_balances`, the latest balances of every market account.
+    pub async fn user_balances(&self, query: &Query) -> EconiaResult<Vec<UserBalance>> {
+        self.get("user_balances", query).await
+    }
+
+    /// Query `/candlesticks`, filter on `resolution` to pick their duration.
+    pub async fn candlesticks(&self, query: &Query) -> EconiaResult<Vec<Candlestick>> {
+        self.get("candlesticks", query).await
+    }
+
+    /// Query `/markets`, every registered market along with its 24 hour data.
+    pub async fn markets(&self, query: &Query) -> EconiaResult<Vec<Market>> {
+        self.get("markets", query).await
+    }
+
+    /// Query `/competition_leaderboard_users`, the leaderboard of the trading competitions.
+    pub async fn leaderboard(&self, query: &Query) -> EconiaResult<Vec<LeaderboardUser>> {
+        self.get("competition_leaderboard_users", query).await
+    }
+
+    /// Call `/rpc/market_aggregated_info`, the data of a market over the last `seconds`, e.g.
+    /// [`SECONDS_PER_DAY`] for its 24 hour data.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market_id` - Market ID of the market.
+    /// * `seconds` - Duration of the period, ending now.
+    pub async fn market_aggregated_info(
+        &self,
+        market_id: u64,
+        seconds: u32,
+    ) -> EconiaResult<MarketAggregatedInfo> {
+        let query = Query {
+            filters: vec![
+                ("market".to_string(), market_id.to_string()),
+                ("seconds".to_string(), seconds.to_string()),
+            ],
+            ..Default::default()
+        };
+        self.get("rpc/market_aggregated_info", &query)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EconiaError::DssError("no aggregated info returned".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn pairs(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn render_documented_query() {
+        let query = Query::new()
+            .eq("market_id", 1)
+            .eq("user", "0x1234")
+            .order_by("created_at", SortOrder::Desc)
+            .page(0, 100);
+        assert_eq!(
+            query.params(),
+            pairs(&[
+                ("market_id", "eq.1"),
+                ("user", "eq.0x1234"),
+                ("order", "created_at.desc"),
+                ("limit", "100"),
+                ("offset", "0"),
+            ])
+        );
+    }
+
+    #[test]
+    fn render_filters() {
+        let query = Query::new()
+            .neq("order_status", "open")
+            .gt("price", 10)
+            .gte("size", 1)
+            .lt("txn_version", 200)
+            .lte("time", "2023-11-08T16:29:55Z")
+            .is_in("market_id", [1, 2, 3])
+            .is_null("integrator")
+            .filter("user", Operator::ILike, "0xa11ce*");
+        assert_eq!(
+            query.params(),
+            pairs(&[
+                ("order_status", "neq.open"),
+                ("price", "gt.10"),
+                ("size", "gte.1"),
+                ("txn_version", "lt.200"),
+                ("time", "lte.2023-11-08T16:29:55Z"),
+                ("market_id", "in.(1,2,3)"),
+                ("integrator", "is.null"),
+                ("user", "ilike.0xa11ce*"),
+            ])
+        );
+    }
+
+    #[test]
+    fn render_ordering_and_pagination() {
+        assert!(Query::new().params().is_empty());
+        let query = Query::new()
+            .order_by("market_id", SortOrder::Asc)
+            .order_by("txn_version", SortOrder::Desc)
+            .select(&["market_id", "price"])
+            .page(3, 25);
+        assert_eq!(
+            query.params(),
+            pairs(&[
+                ("order", "market_id.asc,txn_version.desc"),
+                ("select", "market_id,price"),
+                ("limit", "25"),
+                ("offset", "75"),
+            ])
+        );
+        let query = Query::new().offset(10);
+        assert_eq!(query.params(), pairs(&[("offset", "10")]));
+    }
+
+    #[test]
+    fn decode_fill() {
+        let fill: Fill = serde_json::from_str(
+            r#"{
+            "txn_version": 12345,
+            "event_idx": 2,
+            "emit_address": "0xb0b",
+            "time": "2023-11-08T16:29:55.123456+00:00",
+            "maker_address": "0xb0b",
+            "maker_custodian_id": 0,
+            "maker_order_id": 340282366920938463444927863358058659840,
+            "maker_side": true,
+            "market_id": 1,
+            "price": 1000,
+            "sequence_number_for_trade": 0,
+            "size": 50,
+            "taker_address": "0xa11ce",
+            "taker_custodian_id": 0,
+            "taker_order_id": 18446744073709551617,
+            "taker_quote_fees_paid": 25
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(fill.maker_order_id, 340282366920938463444927863358058659840);
+        assert_eq!(fill.taker_order_id, 18446744073709551617);
+        assert_eq!(fill.maker_side, Side::Ask);
+        assert_eq!(fill.time.timestamp_micros(), 1_699_460_995_123_456);
+    }
+
+    #[test]
+    fn decode_orders() {
+        let swap: OrderHistory = serde_json::from_str(
+            r#"{
+            "market_id": 1,
+            "order_id": 18446744073709551617,
+            "created_at": "2023-11-08T16:29:55+00:00",
+            "last_updated_at": null,
+            "integrator": "0x1",
+            "total_filled": 30,
+            "remaining_size": 0,
+            "order_status": "closed",
+            "order_type": "swap",
+            "user": "0xa11ce",
+            "direction": "sell",
+            "price": null,
+            "average_execution_price": 1002.5,
+            "custodian_id": null,
+            "self_match_behavior": null,
+            "restriction": null,
+            "last_increase_stamp": null,
+            "min_base": 0,
+            "max_base": 300,
+            "min_quote": 0,
+            "max_quote": 18446744073709551615,
+            "total_fees_paid_in_quote_subunits": 15
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(swap.order_type, OrderType::Swap);
+        assert_eq!(swap.order_status, OrderStatus::Closed);
+        assert_eq!(swap.direction.side(), Side::Ask);
+        assert_eq!(
+            swap.average_execution_price,
+            Some(BigDecimal::from_str("1002.5").unwrap())
+        );
+        assert_eq!(swap.max_quote, Some(u64::MAX));
+        assert_eq!(swap.price, None);
+
+        let limit: OrderHistory = serde_json::from_str(
+            r#"{
+            "market_id": 1,
+            "order_id": 42,
+            "created_at": "2023-11-08T16:29:55+00:00",
+            "last_updated_at": "2023-11-08T16:30:00+00:00",
+            "integrator": null,
+            "total_filled": 0,
+            "remaining_size": 10,
+            "order_status": "open",
+            "order_type": "limit",
+            "user": "0xa11ce",
+            "direction": "bid",
+            "price": 990,
+            "average_execution_price": null,
+            "custodian_id": 0,
+            "self_match_behavior": 0,
+            "restriction": 0,
+            "last_increase_stamp": 7,
+            "min_base": null,
+            "max_base": null,
+            "min_quote": null,
+            "max_quote": null,
+            "total_fees_paid_in_quote_subunits": 0
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(limit.direction, OrderDirection::Bid);
+        assert_eq!(limit.price, Some(990));
+        assert!(limit.last_updated_at.is_some());
+    }
+
+    #[test]
+    fn decode_candlestick_and_leaderboard() {
+        let candlestick: Candlestick = serde_json::from_str(
+            r#"{
+            "market_id": 1,
+            "resolution": 60,
+            "start_time": "2023-11-08T16:29:00+00:00",
+            "open": null,
+            "high": null,
+            "low": null,
+            "close": null,
+            "volume": 0
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(candlestick.open, None);
+        assert_eq!(candlestick.volume, 0);
+
+        let user: LeaderboardUser = serde_json::from_str(
+            r#"{
+            "user": "0xa11ce",
+            "volume": 36893488147419103232,
+            "integrators_used": ["0x1", "0x2"],
+            "n_trades": 12,
+            "points": 12.5,
+            "competition_id": 1,
+            "rank": 3
+        }"#,
+        )
+        .unwrap();
+        assert_eq!(user.volume, 36893488147419103232);
+        assert_eq!(user.points, BigDecimal::from_str("12.5").unwrap());
+        assert_eq!(user.integrators_used, ["0x1", "0x2"]);
+    }
+
+    #[test]
+    fn reject_invalid_rows() {
+        let row = json!({
+            "market_id": 1,
+            "resolution": 60,
+            "start_time": "not a time",
+            "open": null,
+            "high": null,
+            "low": null,
+            "close": null,
+            "volume": 0,
+        });
+        assert!(serde_json::from_value::<Candlestick>(row).is_err());
+        let row = json!({ "address": "0xa11ce", "market_id": -1 });
+        assert!(serde_json::from_value::<UserBalance>(row).is_err());
+    }
+}
@@ -4,7 +4,8 @@
 //! [`EconiaClient::submit_tx`](crate::EconiaClient::submit_tx).
 //!
 //! For every entry function that the Econia package exposes, there is a corresponding function
-//! here.
+//! here. Builders generated from the Move sources, taking raw Move arguments, are also available
+//! in [`generated::entry`](crate::generated::entry).
 use std::str::FromStr;
 
 use aptos_api_types::MoveModuleId;
@@ -3,77 +3,460 @@
 use aptos_sdk::{
     bcs,
     crypto::CryptoMaterialError,
-    move_types::{account_address::AccountAddressParseError, language_storage::TypeTag},
+    move_types::{
+        account_address::{AccountAddress, AccountAddressParseError},
+        language_storage::TypeTag,
+    },
     rest_client::error::RestError,
 };
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+/// Abort codes of the Econia `market` module.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MarketError {
-    #[error("")]
+    #[error("maximum base trade amount specified as 0")]
     MaxBaseZero = 0,
-    #[error("")]
+    #[error("maximum quote trade amount specified as 0")]
     MaxQuoteZero = 1,
-    #[error("")]
+    #[error("minimum base trade amount exceeds maximum base trade amount")]
     MinBaseExceedsMax = 2,
-    #[error("")]
+    #[error("minimum quote trade amount exceeds maximum quote trade amount")]
     MinQuoteExceedsMax = 3,
-    #[error("")]
+    #[error("filling order would overflow asset received from trade")]
     OverflowAssetIn = 4,
-    #[error("")]
+    #[error("not enough asset to trade away")]
     NotEnoughAssetOut = 5,
-    #[error("")]
+    #[error("no market with given ID")]
     InvalidMarketId = 6,
-    #[error("")]
+    #[error("base asset type is invalid")]
     InvalidBase = 7,
-    #[error("")]
+    #[error("quote asset type is invalid")]
     InvalidQuote = 8,
-    #[error("")]
+    #[error("minimum base asset trade amount requirement not met")]
     MinBaseNotTraded = 9,
-    #[error("")]
+    #[error("minimum quote coin trade amount requirement not met")]
     MinQuoteNotTraded = 10,
-    #[error("")]
+    #[error("order price specified as 0")]
     PriceZero = 11,
-    #[error("")]
+    #[error("order price exceeds maximum allowable price")]
     PriceTooHigh = 12,
-    #[error("")]
+    #[error("post-or-abort limit order price crosses spread")]
     PostOrAbortCrossesSpread = 13,
-    #[error("")]
+    #[error("order size does not meet minimum size for market")]
     SizeTooSmall = 14,
-    #[error("")]
+    #[error("limit order size results in base asset amount overflow")]
     SizeBaseOverflow = 15,
-    #[error("")]
+    #[error("limit order size and price results in ticks amount overflow")]
     SizePriceTicksOverflow = 16,
-    #[error("")]
+    #[error("limit order size and price results in quote amount overflow")]
     SizePriceQuoteOverflow = 17,
-    #[error("")]
+    #[error("invalid restriction flag")]
     InvalidRestriction = 18,
-    #[error("")]
+    #[error("a self match occurs when self match behavior is abort")]
     SelfMatch = 19,
-    #[error("")]
+    #[error("no room to insert order with such low price-time priority")]
     PriceTimePriorityTooLow = 20,
-    #[error("")]
+    #[error("underwriter invalid for given market")]
     InvalidUnderwriter = 21,
-    #[error("")]
+    #[error("market order ID invalid")]
     InvalidMarketOrderId = 22,
-    #[error("")]
+    #[error("custodian not authorized for operation")]
     InvalidCustodian = 23,
-    #[error("")]
+    #[error("invalid user indicated for operation")]
     InvalidUser = 24,
-    #[error("")]
+    #[error("fill-or-abort price does not cross the spread")]
     FillOrAbortNotCrossSpread = 25,
-    #[error("")]
+    #[error("AVL queue head price does not match head order price")]
     HeadKeyPriceMismatch = 26,
-    #[error("")]
+    #[error("simulation query called by invalid account")]
     NotSimulationAccount = 27,
-    #[error("")]
+    #[error("invalid self match behavior flag")]
     InvalidSelfMatchBehavior = 28,
-    #[error("")]
+    #[error("passive advance percent is not less than or equal to 100")]
     InvalidPercent = 29,
-    #[error("")]
+    #[error("order size change requiring insertion resulted in an AVL queue access key mismatch")]
     SizeChangeInsertionError = 30,
-    #[error("")]
+    #[error("order ID corresponds to an order that did not post")]
     OrderDidNotPost = 31,
+    #[error("order price field does not match AVL queue insertion key price")]
+    OrderPriceMismatch = 32,
+    #[error("new order size is less than the minimum order size for market")]
+    SizeChangeBelowMinSize = 33,
+}
+
+impl TryFrom<u64> for MarketError {
+    type Error = u64;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => Self::MaxBaseZero,
+            1 => Self::MaxQuoteZero,
+            2 => Self::MinBaseExceedsMax,
+            3 => Self::MinQuoteExceedsMax,
+            4 => Self::OverflowAssetIn,
+            5 => Self::NotEnoughAssetOut,
+            6 => Self::InvalidMarketId,
+            7 => Self::InvalidBase,
+            8 => Self::InvalidQuote,
+            9 => Self::MinBaseNotTraded,
+            10 => Self::MinQuoteNotTraded,
+            11 => Self::PriceZero,
+            12 => Self::PriceTooHigh,
+            13 => Self::PostOrAbortCrossesSpread,
+            14 => Self::SizeTooSmall,
+            15 => Self::SizeBaseOverflow,
+            16 => Self::SizePriceTicksOverflow,
+            17 => Self::SizePriceQuoteOverflow,
+            18 => Self::InvalidRestriction,
+            19 => Self::SelfMatch,
+            20 => Self::PriceTimePriorityTooLow,
+            21 => Self::InvalidUnderwriter,
+            22 => Self::InvalidMarketOrderId,
+            23 => Self::InvalidCustodian,
+            24 => Self::InvalidUser,
+            25 => Self::FillOrAbortNotCrossSpread,
+            26 => Self::HeadKeyPriceMismatch,
+            27 => Self::NotSimulationAccount,
+            28 => Self::InvalidSelfMatchBehavior,
+            29 => Self::InvalidPercent,
+            30 => Self::SizeChangeInsertionError,
+            31 => Self::OrderDidNotPost,
+            32 => Self::OrderPriceMismatch,
+            33 => Self::SizeChangeBelowMinSize,
+            _ => return Err(code),
+        })
+    }
+}
+
+/// Abort codes of the Econia `user` module.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserError {
+    #[error("market account already exists")]
+    ExistsMarketAccount = 0,
+    #[error("custodian ID has not been registered")]
+    UnregisteredCustodian = 1,
+    #[error("no market accounts resource found")]
+    NoMarketAccounts = 2,
+    #[error("no market account resource found")]
+    NoMarketAccount = 3,
+    #[error("asset type is not in trading pair for market")]
+    AssetNotInPair = 4,
+    #[error("deposit would overflow asset ceiling")]
+    DepositOverflowAssetCeiling = 5,
+    #[error("underwriter is not valid for indicated market")]
+    InvalidUnderwriter = 6,
+    #[error("too little available for withdrawal")]
+    WithdrawTooLittleAvailable = 7,
+    #[error("price is zero")]
+    PriceZero = 8,
+    #[error("price exceeds maximum possible price")]
+    PriceTooHigh = 9,
+    #[error("ticks to fill an order overflows a `u64`")]
+    TicksOverflow = 11,
+    #[error("filling order would overflow asset received from trade")]
+    OverflowAssetIn = 12,
+    #[error("not enough asset to trade away")]
+    NotEnoughAssetOut = 13,
+    #[error("no change in order size")]
+    ChangeOrderNoChange = 14,
+    #[error("market order ID mismatch with user's open order")]
+    InvalidMarketOrderId = 15,
+    #[error("mismatch between coin value and indicated amount")]
+    CoinAmountMismatch = 16,
+    #[error("expected order access key does not match assigned order access key")]
+    AccessKeyMismatch = 17,
+    #[error("coin type is generic asset")]
+    CoinTypeIsGenericAsset = 18,
+    #[error("mismatch between expected size before operation and actual size before operation")]
+    StartSizeMismatch = 19,
+}
+
+impl TryFrom<u64> for UserError {
+    type Error = u64;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => Self::ExistsMarketAccount,
+            1 => Self::UnregisteredCustodian,
+            2 => Self::NoMarketAccounts,
+            3 => Self::NoMarketAccount,
+            4 => Self::AssetNotInPair,
+            5 => Self::DepositOverflowAssetCeiling,
+            6 => Self::InvalidUnderwriter,
+            7 => Self::WithdrawTooLittleAvailable,
+            8 => Self::PriceZero,
+            9 => Self::PriceTooHigh,
+            11 => Self::TicksOverflow,
+            12 => Self::OverflowAssetIn,
+            13 => Self::NotEnoughAssetOut,
+            14 => Self::ChangeOrderNoChange,
+            15 => Self::InvalidMarketOrderId,
+            16 => Self::CoinAmountMismatch,
+            17 => Self::AccessKeyMismatch,
+            18 => Self::CoinTypeIsGenericAsset,
+            19 => Self::StartSizeMismatch,
+            _ => return Err(code),
+        })
+    }
+}
+
+/// Abort codes of the Econia `registry` module.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistryError {
+    #[error("lot size specified as 0")]
+    LotSizeZero = 0,
+    #[error("tick size specified as 0")]
+    TickSizeZero = 1,
+    #[error("minimum order size specified as 0")]
+    MinSizeZero = 2,
+    #[error("quote asset type has not been initialized as a coin")]
+    QuoteNotCoin = 3,
+    #[error("base and quote asset descriptors are identical")]
+    BaseQuoteSame = 4,
+    #[error("market is already registered")]
+    MarketRegistered = 5,
+    #[error("base coin type has not been initialized for a pure coin market")]
+    BaseNotCoin = 6,
+    #[error("generic base asset descriptor has too few characters")]
+    GenericTooFewCharacters = 7,
+    #[error("generic base asset descriptor has too many characters")]
+    GenericTooManyCharacters = 8,
+    #[error("caller is not Econia, but should be")]
+    NotEconia = 9,
+    #[error("trading pair does not have recognized market")]
+    NoRecognizedMarket = 10,
+    #[error("market ID is not recognized for corresponding trading pair")]
+    WrongRecognizedMarket = 11,
+    #[error("market ID is invalid")]
+    InvalidMarketId = 12,
+    #[error("base asset type is invalid")]
+    InvalidBase = 13,
+    #[error("quote asset type is invalid")]
+    InvalidQuote = 14,
+}
+
+impl TryFrom<u64> for RegistryError {
+    type Error = u64;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => Self::LotSizeZero,
+            1 => Self::TickSizeZero,
+            2 => Self::MinSizeZero,
+            3 => Self::QuoteNotCoin,
+            4 => Self::BaseQuoteSame,
+            5 => Self::MarketRegistered,
+            6 => Self::BaseNotCoin,
+            7 => Self::GenericTooFewCharacters,
+            8 => Self::GenericTooManyCharacters,
+            9 => Self::NotEconia,
+            10 => Self::NoRecognizedMarket,
+            11 => Self::WrongRecognizedMarket,
+            12 => Self::InvalidMarketId,
+            13 => Self::InvalidBase,
+            14 => Self::InvalidQuote,
+            _ => return Err(code),
+        })
+    }
+}
+
+/// Abort codes of the Econia `incentives` module.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IncentivesError {
+    #[error("caller is not Econia, but should be")]
+    NotEconia = 0,
+    #[error("type does not correspond to an initialized coin")]
+    NotCoin = 1,
+    #[error("passed fee store tiers vector is empty")]
+    EmptyFeeStoreTiers = 2,
+    #[error("indicated fee share divisor for given tier is too big")]
+    FeeShareDivisorTooBig = 3,
+    #[error("the indicated fee share divisor for a given tier is less than the indicated taker fee divisor")]
+    FeeShareDivisorTooSmall = 4,
+    #[error("market registration fee is less than the minimum")]
+    MarketRegistrationFeeLessThanMin = 5,
+    #[error("custodian registration fee is less than the minimum")]
+    CustodianRegistrationFeeLessThanMin = 6,
+    #[error("taker fee divisor is less than the minimum")]
+    TakerDivisorLessThanMin = 7,
+    #[error("the wrong number of fields are passed for a given tier")]
+    TierFieldsWrongLength = 8,
+    #[error("the indicated tier activation fee is too small")]
+    ActivationFeeTooSmall = 9,
+    #[error("the indicated withdrawal fee is too big")]
+    WithdrawalFeeTooBig = 10,
+    #[error("the indicated withdrawal fee is too small")]
+    WithdrawalFeeTooSmall = 11,
+    #[error("type is not the utility coin type")]
+    InvalidUtilityCoinType = 12,
+    #[error("not enough utility coins provided")]
+    NotEnoughUtilityCoins = 13,
+    #[error("too many integrator fee store tiers indicated")]
+    TooManyTiers = 14,
+    #[error("indicated tier is not higher than existing tier")]
+    NotAnUpgrade = 15,
+    #[error("an update to the incentive parameters set indicates a reduction in fee store tiers")]
+    FewerTiers = 16,
+    #[error("the cost to activate to tier 0 is nonzero")]
+    FirstTierActivationFeeNonzero = 17,
+    #[error("underwriter registration fee is less than the minimum")]
+    UnderwriterRegistrationFeeLessThanMin = 18,
+    #[error("depositing to an integrator fee store would result in an overflow")]
+    IntegratorFeeStoreOverflow = 19,
+    #[error("depositing to an Econia fee store would result in an overflow")]
+    EconiaFeeStoreOverflow = 20,
+    #[error("depositing to a utility coin store would result in an overflow")]
+    UtilityCoinStoreOverflow = 21,
+    #[error("there is no tier with given number")]
+    InvalidTier = 22,
+    #[error("cumulative activation fee for new tier is not greater than that of current tier")]
+    TierCostNotIncrease = 23,
+}
+
+impl TryFrom<u64> for IncentivesError {
+    type Error = u64;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => Self::NotEconia,
+            1 => Self::NotCoin,
+            2 => Self::EmptyFeeStoreTiers,
+            3 => Self::FeeShareDivisorTooBig,
+            4 => Self::FeeShareDivisorTooSmall,
+            5 => Self::MarketRegistrationFeeLessThanMin,
+            6 => Self::CustodianRegistrationFeeLessThanMin,
+            7 => Self::TakerDivisorLessThanMin,
+            8 => Self::TierFieldsWrongLength,
+            9 => Self::ActivationFeeTooSmall,
+            10 => Self::WithdrawalFeeTooBig,
+            11 => Self::WithdrawalFeeTooSmall,
+            12 => Self::InvalidUtilityCoinType,
+            13 => Self::NotEnoughUtilityCoins,
+            14 => Self::TooManyTiers,
+            15 => Self::NotAnUpgrade,
+            16 => Self::FewerTiers,
+            17 => Self::FirstTierActivationFeeNonzero,
+            18 => Self::UnderwriterRegistrationFeeLessThanMin,
+            19 => Self::IntegratorFeeStoreOverflow,
+            20 => Self::EconiaFeeStoreOverflow,
+            21 => Self::UtilityCoinStoreOverflow,
+            22 => Self::InvalidTier,
+            23 => Self::TierCostNotIncrease,
+            _ => return Err(code),
+        })
+    }
+}
+
+/// Abort codes of the Econia `assets` module.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetsError {
+    #[error("caller is not Econia")]
+    NotEconia = 0,
+    #[error("coin capabilities have already been initialized")]
+    HasCapabilities = 1,
+}
+
+impl TryFrom<u64> for AssetsError {
+    type Error = u64;
+
+    fn try_from(code: u64) -> Result<Self, Self::Error> {
+        Ok(match code {
+            0 => Self::NotEconia,
+            1 => Self::HasCapabilities,
+            _ => return Err(code),
+        })
+    }
+}
+
+/// Inconsistencies between events and an [`OrderBook`](crate::book::OrderBook), revealing that
+/// some events were missed.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderBookGap {
+    #[error("order `{0}` is not in the order book")]
+    UnknownOrder(u128),
+    #[error("fill of size `{size}` exceeds the remaining size of order `{order_id}`")]
+    FillExceedsOrder { order_id: u128, size: u64 },
+    #[error("the best bid crosses the best ask")]
+    CrossedBook,
+}
+
+/// Decimal quantities which cannot be converted to the units of a market, see
+/// [`MarketUnits`](crate::units::MarketUnits).
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionError {
+    #[error("size is not a whole amount of lots")]
+    SizeNotOnGrid,
+    #[error("price is not a whole amount of ticks per lot")]
+    PriceNotOnGrid,
+    #[error("amount has more decimals than the coin")]
+    AmountNotOnGrid,
+    #[error("negative quantities cannot be converted")]
+    Negative,
+    #[error("converted quantity does not fit in a u64")]
+    Overflow,
+    #[error("decimal exponent is out of range")]
+    ExponentOutOfRange,
+    #[error("decimals of a generic base asset must be provided")]
+    GenericBaseDecimals,
+}
+
+/// Location and code of a Move abort, parsed from the VM status of a failed transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveAbort {
+    /// Address of the module that aborted.
+    pub address: AccountAddress,
+    /// Name of the module that aborted.
+    pub module: String,
+    /// Abort code.
+    pub code: u64,
+}
+
+impl MoveAbort {
+    /// Parse a VM status such as `Move abort in 0xc0deb00c::market: E_PRICE_TOO_HIGH(0xc): ...`
+    /// or `Move abort in 0xc0deb00c::market: 0xc`.
+    ///
+    /// Returns `None` if the VM status is not a Move abort.
+    pub fn parse(vm_status: &str) -> Option<Self> {
+        let (_, abort) = vm_status.split_once("Move abort in ")?;
+        let (location, rest) = abort.split_once(": ")?;
+        let (address, module) = location.rsplit_once("::")?;
+        let address = AccountAddress::from_hex_literal(address).ok()?;
+        let code = match rest.split_once('(') {
+            Some((_, named)) => named.split(')').next()?,
+            None => rest.split(|c: char| c == ':' || c.is_whitespace()).next()?,
+        };
+        let code = match code.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+            None => code.parse().ok()?,
+        };
+        Some(Self {
+            address,
+            module: module.to_string(),
+            code,
+        })
+    }
+
+    /// Decode the abort into the typed error of the Econia module that raised it.
+    ///
+    /// Returns `None` if the abort was not raised by an Econia module published at
+    /// `econia_address`, or if its code is unknown.
+    pub fn econia_error(&self, econia_address: &AccountAddress) -> Option<EconiaError> {
+        if &self.address != econia_address {
+            return None;
+        }
+        match self.module.as_str() {
+            "market" => MarketError::try_from(self.code).ok().map(EconiaError::from),
+            "user" => UserError::try_from(self.code).ok().map(EconiaError::from),
+            "registry" => RegistryError::try_from(self.code)
+                .ok()
+                .map(EconiaError::from),
+            "incentives" => IncentivesError::try_from(self.code)
+                .ok()
+                .map(EconiaError::from),
+            "assets" => AssetsError::try_from(self.code).ok().map(EconiaError::from),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -96,6 +479,9 @@ pub enum EconiaError {
     #[error("the provided account address is not registered for coin: `{0}`")]
     AccountNotRegisteredForCoin(TypeTag),
 
+    #[error("the coin: `{0}` is not initialized, its CoinInfo does not exist")]
+    CoinNotFound(TypeTag),
+
     #[error(transparent)]
     JsonError(#[from] serde_json::Error),
 
@@ -114,12 +500,248 @@ pub enum EconiaError {
     #[error("failed submitting aptos transaction")]
     FailedSubmittingTransaction,
 
+    #[error("aptos transaction failed with vm status: `{0}`")]
+    TransactionFailed(String),
+
+    #[error("the outcome of aptos transaction: `{0}` is unknown: {1}")]
+    TransactionOutcomeUnknown(String, RestError),
+
     #[error("invalid response from the contract")]
     InvalidResponse,
 
+    #[error("the batch does not contain any entry function")]
+    EmptyBatch,
+
+    #[error("the entry function: `{0}` cannot be called from a batch script")]
+    InvalidBatchEntry(String),
+
+    #[error("arguments of type: `{0}` cannot be passed to a batch script")]
+    UnsupportedBatchArgument(String),
+
+    #[error("failed compiling the batch script: {0}")]
+    ScriptCompilationFailed(String),
+
+    #[error("the requested event handles do not exist")]
+    EventHandlesNotFound,
+
+    #[error("cannot decode event: {0}")]
+    UndecodableEvent(String),
+
+    #[error("invalid aptos config: {0}")]
+    InvalidAptosConfig(String),
+
+    #[error("invalid client config: {0}")]
+    InvalidClientConfig(String),
+
+    #[error("keystore error: {0}")]
+    KeystoreError(String),
+
+    #[error("external signer error: {0}")]
+    SignerError(String),
+
+    #[error("offline transaction error: {0}")]
+    OfflineTransactionError(String),
+
+    #[error("sponsored transaction rejected: {0}")]
+    SponsorError(String),
+
+    #[error("dss api error: {0}")]
+    DssError(String),
+
+    #[error("mqtt error: {0}")]
+    MqttError(String),
+
+    #[error("node failover error: {0}")]
+    FailoverError(String),
+
+    #[error("fungible asset error: {0}")]
+    FungibleAssetError(String),
+
+    #[error("market account onboarding error: {0}")]
+    OnboardingError(String),
+
+    #[error("market registration error: {0}")]
+    MarketRegistrationError(String),
+
+    #[error("no client for the account: `{0}`")]
+    UnknownAccount(AccountAddress),
+
     #[error(transparent)]
     MarketError(#[from] MarketError),
 
+    #[error(transparent)]
+    UserError(#[from] UserError),
+
+    #[error(transparent)]
+    RegistryError(#[from] RegistryError),
+
+    #[error(transparent)]
+    IncentivesError(#[from] IncentivesError),
+
+    #[error(transparent)]
+    AssetsError(#[from] AssetsError),
+
+    #[error(transparent)]
+    OrderBookGap(#[from] OrderBookGap),
+
+    #[error(transparent)]
+    ConversionError(#[from] ConversionError),
+
     #[error("Custom error: {0}")]
     Custom(#[from] anyhow::Error),
 }
+
+impl EconiaError {
+    /// Build the error of a transaction that failed with the given VM status.
+    ///
+    /// Aborts raised by the Econia modules are decoded into their typed error, e.g. a
+    /// [`MarketError`] for the `market` module. Any other failure is returned as a
+    /// [`EconiaError::TransactionFailed`].
+    pub fn from_vm_status(econia_address: &AccountAddress, vm_status: &str) -> Self {
+        MoveAbort::parse(vm_status)
+            .and_then(|abort| abort.econia_error(econia_address))
+            .unwrap_or_else(|| Self::TransactionFailed(vm_status.to_string()))
+    }
+
+    /// Build the error of a failed Aptos REST call.
+    ///
+    /// The REST client reports transactions that were committed but failed execution with their
+    /// VM status in the error message, so Econia aborts are decoded from it.
+    pub fn from_rest_error(econia_address: &AccountAddress, error: RestError) -> Self {
+        match MoveAbort::parse(&error.to_string()).and_then(|a| a.econia_error(econia_address)) {
+            Some(e) => e,
+            None => Self::AptosError(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal("0xc0deb00c").unwrap()
+    }
+
+    /// Codes of an abort enum which decode to a variant, checking each variant has its code as
+    /// discriminant.
+    fn decoded_codes<E: TryFrom<u64>>(discriminant: fn(E) -> u64) -> Vec<u64> {
+        (0..64)
+            .filter(|code| match E::try_from(*code) {
+                Ok(e) => {
+                    assert_eq!(discriminant(e), *code);
+                    true
+                }
+                Err(_) => false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_named_abort() {
+        let abort = MoveAbort::parse(
+            "Move abort in 0xc0deb00c::market: E_PRICE_TOO_HIGH(0xc): Order price exceeds maximum allowable price.",
+        )
+        .unwrap();
+        assert_eq!(
+            abort,
+            MoveAbort {
+                address: econia(),
+                module: "market".to_string(),
+                code: 12,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unnamed_abort() {
+        let abort = MoveAbort::parse("Move abort in 0xc0deb00c::user: 0x11").unwrap();
+        assert_eq!(abort.module, "user");
+        assert_eq!(abort.code, 17);
+        let abort = MoveAbort::parse("Move abort in 0x1::coin: 6 ").unwrap();
+        assert_eq!(abort.address, AccountAddress::ONE);
+        assert_eq!(abort.code, 6);
+    }
+
+    #[test]
+    fn parse_other_statuses() {
+        assert_eq!(MoveAbort::parse("Executed successfully"), None);
+        assert_eq!(MoveAbort::parse("Out of gas"), None);
+        assert_eq!(
+            MoveAbort::parse("Move abort in 0xc0deb00c::market: E_OOPS"),
+            None
+        );
+        assert_eq!(MoveAbort::parse("Move abort in market: 0x1"), None);
+    }
+
+    #[test]
+    fn decode_econia_aborts() {
+        let status = "Move abort in 0xc0deb00c::market: E_PRICE_TOO_HIGH(0xc): ...";
+        assert!(matches!(
+            EconiaError::from_vm_status(&econia(), status),
+            EconiaError::MarketError(MarketError::PriceTooHigh)
+        ));
+        let status = "Move abort in 0xc0deb00c::incentives: 0x12";
+        assert!(matches!(
+            EconiaError::from_vm_status(&econia(), status),
+            EconiaError::IncentivesError(IncentivesError::UnderwriterRegistrationFeeLessThanMin)
+        ));
+        let status = "Move abort in 0xc0deb00c::assets: 0x1";
+        assert!(matches!(
+            EconiaError::from_vm_status(&econia(), status),
+            EconiaError::AssetsError(AssetsError::HasCapabilities)
+        ));
+    }
+
+    #[test]
+    fn keep_foreign_aborts() {
+        // Aborts of other modules, or of an Econia module published elsewhere.
+        for status in [
+            "Move abort in 0x1::coin: 0x10006",
+            "Move abort in 0xc0deb00c::avl_queue: 0x1",
+            "Move abort in 0xbad::market: 0xc",
+            "Move abort in 0xc0deb00c::market: 0x64",
+        ] {
+            match EconiaError::from_vm_status(&econia(), status) {
+                EconiaError::TransactionFailed(s) => assert_eq!(s, status),
+                e => panic!("unexpected error: {e}"),
+            }
+        }
+    }
+
+    #[test]
+    fn map_every_code() {
+        assert_eq!(
+            decoded_codes(|e: MarketError| e as u64),
+            (0..=33).collect::<Vec<_>>()
+        );
+        // The `user` module has no abort code 10.
+        let user: Vec<u64> = (0..=19).filter(|c| *c != 10).collect();
+        assert_eq!(decoded_codes(|e: UserError| e as u64), user);
+        assert_eq!(
+            decoded_codes(|e: RegistryError| e as u64),
+            (0..=14).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decoded_codes(|e: IncentivesError| e as u64),
+            (0..=23).collect::<Vec<_>>()
+        );
+        assert_eq!(decoded_codes(|e: AssetsError| e as u64), vec![0, 1]);
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(
+            MarketError::HeadKeyPriceMismatch.to_string(),
+            "AVL queue head price does not match head order price"
+        );
+        assert_eq!(
+            IncentivesError::UnderwriterRegistrationFeeLessThanMin.to_string(),
+            "underwriter registration fee is less than the minimum"
+        );
+        assert_eq!(
+            IncentivesError::CustodianRegistrationFeeLessThanMin.to_string(),
+            "custodian registration fee is less than the minimum"
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! The [`failover`](crate::failover) module exposes a [`FailoverTransport`], an
+//! [`AptosTransport`] spreading requests over several Aptos nodes.
+//!
+//! Nodes are health-checked every [`FailoverConfig::health_check_interval`] by fetching their
+//! index. A node is healthy if it answers on the chain of the transport, and if its ledger
+//! version lags the most recent one by at most [`FailoverConfig::max_version_lag`]. Requests are
+//! sent to the healthy node with the lowest latency, and fail over to the next one when the node
+//! cannot be reached or reports a server error. Other failures, e.g. a Move abort, are returned
+//! as is, as every node would answer the same.
+//!
+//! The chain of the transport is the one of the first node answering, in the order the nodes are
+//! given in. Nodes on another chain are never used, so that the chain ID of an
+//! [`EconiaClient`](crate::EconiaClient) stays valid.
+//!
+//! Sequence numbers never go backwards when failing over to a lagging node: the transport
+//! remembers the highest sequence number seen for each account, including the ones used by the
+//! transactions it committed. Other reads may still lag by up to
+//! [`FailoverConfig::max_version_lag`] versions.
+//!
+//! Use [`EconiaClient::connect_with_nodes`](crate::EconiaClient::connect_with_nodes) to connect
+//! a client to several nodes.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use aptos_api_types::{
+    AptosErrorCode, IndexResponse, MoveModuleBytecode, PendingTransaction, Transaction,
+    UserTransaction, VersionedEvent, ViewRequest,
+};
+use aptos_sdk::crypto::HashValue;
+use aptos_sdk::rest_client::error::RestError;
+use aptos_sdk::rest_client::{Account, AptosBaseUrl, Client, Resource};
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::transaction::SignedTransaction;
+use async_trait::async_trait;
+use futures::future::join_all;
+use reqwest::Url;
+
+use crate::errors::EconiaError;
+use crate::transport::{AptosTransport, ViewResponse};
+use crate::EconiaResult;
+
+pub const MAX_VERSION_LAG: u64 = 10_000;
+pub const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// An Aptos node to connect to.
+#[derive(Debug, Clone)]
+pub struct NodeEndpoint {
+    /// Url of the Aptos node.
+    pub url: Url,
+    /// API key to use when connecting to the node.
+    pub api_key: Option<String>,
+}
+
+impl NodeEndpoint {
+    pub fn new(url: Url, api_key: Option<String>) -> Self {
+        Self { url, api_key }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FailoverConfig {
+    /// Maximum amount of versions a healthy node can lag behind the most recent node.
+    pub max_version_lag: u64,
+    /// Time after which the health of the nodes is checked again, before the next request.
+    pub health_check_interval: Duration,
+    /// Time after which a node not answering a health check is unhealthy.
+    pub health_check_timeout: Duration,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            max_version_lag: MAX_VERSION_LAG,
+            health_check_interval: HEALTH_CHECK_INTERVAL,
+            health_check_timeout: HEALTH_CHECK_TIMEOUT,
+        }
+    }
+}
+
+/// Health of a node, as of its last health check or failed request.
+#[derive(Debug, Clone)]
+pub struct NodeHealth {
+    /// Name of the node, its url unless given otherwise.
+    pub name: String,
+    /// Chain ID reported by the node, `None` if it never answered.
+    pub chain_id: Option<u8>,
+    /// Ledger version of the node at its last health check.
+    pub ledger_version: Option<u64>,
+    /// Latency of the last health check, `None` if it failed.
+    pub latency: Option<Duration>,
+    pub healthy: bool,
+}
+
+#[derive(Debug)]
+struct Node {
+    transport: Arc<dyn AptosTransport>,
+    health: Mutex<NodeHealth>,
+}
+
+/// [`AptosTransport`] failing over between several nodes, see the
+/// [`failover`](crate::failover) module.
+#[derive(Debug)]
+pub struct FailoverTransport {
+    nodes: Vec<Node>,
+    config: FailoverConfig,
+    chain_id: Mutex<Option<u8>>,
+    last_check: Mutex<Option<Instant>>,
+    /// Highest sequence number seen for each account.
+    sequence_numbers: Mutex<HashMap<AccountAddress, u64>>,
+    /// Node each pending transaction was submitted to.
+    submitted_to: Mutex<HashMap<HashValue, usize>>,
+}
+
+impl FailoverTransport {
+    /// Connect to several Aptos nodes and check their health.
+    ///
+    /// # Arguments:
+    ///
+    /// * `nodes` - [`NodeEndpoint`]s of the nodes, the first ones being preferred to find the
+    ///   chain of the transport.
+    /// * `config` - `FailoverConfig` to configure the transport, if `None` default values will be used.
+    pub async fn connect(
+        nodes: Vec<NodeEndpoint>,
+        config: Option<FailoverConfig>,
+    ) -> EconiaResult<Self> {
+        let mut transports: Vec<(String, Arc<dyn AptosTransport>)> = vec![];
+        for node in nodes {
+            let builder = Client::builder(AptosBaseUrl::Custom(node.url.clone()));
+            let builder = match node.api_key {
+                Some(key) => builder.api_key(&key).map_err(EconiaError::Custom)?,
+                None => builder,
+            };
+            transports.push((node.url.to_string(), Arc::new(builder.build())));
+        }
+        Self::from_transports(transports, config).await
+    }
+
+    /// Fail over between any [`AptosTransport`]s, e.g.
+    /// [`MockTransport`](crate::transport::MockTransport)s in unit tests, and check their health.
+    ///
+    /// # Arguments:
+    ///
+    /// * `transports` - Name and [`AptosTransport`] of each node, the first ones being preferred
+    ///   to find the chain of the transport.
+    /// * `config` - `FailoverConfig` to configure the transport, if `None` default values will be used.
+    pub async fn from_transports(
+        transports: Vec<(String, Arc<dyn AptosTransport>)>,
+        config: Option<FailoverConfig>,
+    ) -> EconiaResult<Self> {
+        if transports.is_empty() {
+            return Err(EconiaError::FailoverError("no node given".to_string()));
+        }
+        let nodes = transports
+            .into_iter()
+            .map(|(name, transport)| Node {
+                transport,
+                health: Mutex::new(NodeHealth {
+                    name,
+                    chain_id: None,
+                    ledger_version: None,
+                    latency: None,
+                    healthy: false,
+                }),
+            })
+            .collect();
+        let transport = Self {
+            nodes,
+            config: config.unwrap_or_default(),
+            chain_id: Mutex::new(None),
+            last_check: Mutex::new(None),
+            sequence_numbers: Mutex::new(HashMap::new()),
+            submitted_to: Mutex::new(HashMap::new()),
+        };
+        if !transport.check_health().await.iter().any(|h| h.healthy) {
+            return Err(EconiaError::FailoverError("no node is healthy".to_string()));
+        }
+        Ok(transport)
+    }
+
+    /// Chain ID of the transport, `None` until a node answers.
+    pub fn chain_id(&self) -> Option<u8> {
+        *self.chain_id.lock().unwrap()
+    }
+
+    /// Health of each node, in the order they were given in.
+    pub fn health(&self) -> Vec<NodeHealth> {
+        self.nodes
+            .iter()
+            .map(|node| node.health.lock().unwrap().clone())
+            .collect()
+    }
+
+    /// Check the health of every node now, returning it.
+    pub async fn check_health(&self) -> Vec<NodeHealth> {
+        let timeout = self.config.health_check_timeout;
+        let results = join_all(self.nodes.iter().map(|node| async move {
+            let start = Instant::now();
+            match tokio::time::timeout(timeout, node.transport.get_index()).await {
+                Ok(Ok(index)) => Some((index, start.elapsed())),
+                _ => None,
+            }
+        }))
+        .await;
+
+        let chain_id = {
+            let mut chain_id = self.chain_id.lock().unwrap();
+            if chain_id.is_none() {
+                *chain_id = results.iter().flatten().map(|(i, _)| i.chain_id).next();
+            }
+            *chain_id
+        };
+        let top_version = results
+            .iter()
+            .flatten()
+            .filter(|(i, _)| Some(i.chain_id) == chain_id)
+            .map(|(i, _)| i.ledger_version.0)
+            .max()
+            .unwrap_or_default();
+        for (node, result) in self.nodes.iter().zip(results) {
+            let mut health = node.health.lock().unwrap();
+            match result {
+                Some((index, latency)) => {
+                    health.chain_id = Some(index.chain_id);
+                    health.ledger_version = Some(index.ledger_version.0);
+                    health.latency = Some(latency);
+                    health.healthy = Some(index.chain_id) == chain_id
+                        && top_version - index.ledger_version.0 <= self.config.max_version_lag;
+                }
+                None => {
+                    health.latency = None;
+                    health.healthy = false;
+                }
+            }
+        }
+        *self.last_check.lock().unwrap() = Some(Instant::now());
+        self.health()
+    }
+
+    /// Nodes to send a request to, in order: healthy nodes by latency, then unhealthy ones on
+    /// the chain of the transport.
+    async fn ranked(&self) -> Vec<usize> {
+        let stale = {
+            let mut last_check = self.last_check.lock().unwrap();
+            let stale =
+                last_check.map_or(true, |t| t.elapsed() >= self.config.health_check_interval);
+            if stale {
+                // Claim the check so that concurrent requests do not run it again.
+                *last_check = Some(Instant::now());
+            }
+            stale
+        };
+        if stale {
+            self.check_health().await;
+        }
+        let chain_id = self.chain_id();
+        let mut ranked = self
+            .health()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, h)| h.chain_id.is_some() && h.chain_id == chain_id)
+            .collect::<Vec<_>>();
+        ranked.sort_by_key(|(_, h)| (!h.healthy, h.latency.unwrap_or(Duration::MAX)));
+        ranked.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Send a request to the best node, failing over to the next ones, and return the node that
+    /// answered along with its answer.
+    ///
+    /// # Arguments:
+    ///
+    /// * `preferred` - Node to try first, whatever its health.
+    /// * `request` - Request to send to a node.
+    async fn route<'a, T, F, Fut>(
+        &'a self,
+        preferred: Option<usize>,
+        request: F,
+    ) -> Result<(usize, T), RestError>
+    where
+        F: Fn(&'a dyn AptosTransport) -> Fut,
+        Fut: Future<Output = Result<T, RestError>>,
+    {
+        let mut ranked = self.ranked().await;
+        if let Some(preferred) = preferred {
+            ranked.retain(|i| *i != preferred);
+            ranked.insert(0, preferred);
+        }
+        let mut last_error = None;
+        for i in ranked {
+            let node = &self.nodes[i];
+            match request(node.transport.as_ref()).await {
+                Err(e) if is_node_failure(&e) => {
+                    node.health.lock().unwrap().healthy = false;
+                    last_error = Some(e);
+                }
+                result => return result.map(|r| (i, r)),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| {
+            RestError::Unknown(anyhow!("no node on the chain of the transport"))
+        }))
+    }
+
+    /// Raise the highest sequence number seen for an account, returning it.
+    fn see_sequence_number(&self, address: AccountAddress, sequence_number: u64) -> u64 {
+        let mut sequence_numbers = self.sequence_numbers.lock().unwrap();
+        let seen = sequence_numbers.entry(address).or_default();
+        *seen = (*seen).max(sequence_number);
+        *seen
+    }
+}
+
+/// Whether a request failed because of the node rather than of the request itself.
+fn is_node_failure(error: &RestError) -> bool {
+    match error {
+        RestError::Api(a) => {
+            matches!(
+                a.error.error_code,
+                AptosErrorCode::MempoolIsFull
+                    | AptosErrorCode::InternalError
+                    | AptosErrorCode::HealthCheckFailed
+            ) || a.status_code.is_server_error()
+                || a.status_code.as_u16() == 429
+        }
+        RestError::Http(..) | RestError::Timeout(_) => true,
+        RestError::Unknown(e) => e.downcast_ref::<reqwest::Error>().is_some(),
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl AptosTransport for FailoverTransport {
+    async fn get_index(&self) -> Result<IndexResponse, RestError> {
+        Ok(self.route(None, |t| t.get_index()).await?.1)
+    }
+
+    async fn get_account(&self, address: AccountAddress) -> Result<Account, RestError> {
+        let (_, mut account) = self.route(None, |t| t.get_account(address)).await?;
+        account.sequence_number = self.see_sequence_number(address, account.sequence_number);
+        Ok(account)
+    }
+
+    async fn get_account_resource(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+    ) -> Result<Option<Resource>, RestError> {
+        Ok(self
+            .route(None, |t| t.get_account_resource(address, resource_type))
+            .await?
+            .1)
+    }
+
+    async fn get_account_module(
+        &self,
+        address: AccountAddress,
+        module_name: &str,
+    ) -> Result<MoveModuleBytecode, RestError> {
+        Ok(self
+            .route(None, |t| t.get_account_module(address, module_name))
+            .await?
+            .1)
+    }
+
+    async fn view(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> Result<ViewResponse, RestError> {
+        Ok(self.route(None, |t| t.view(request, version)).await?.1)
+    }
+
+    async fn estimate_gas_price(&self) -> Result<u64, RestError> {
+        Ok(self.route(None, |t| t.estimate_gas_price()).await?.1)
+    }
+
+    async fn simulate(&self, tx: &SignedTransaction) -> Result<Vec<UserTransaction>, RestError> {
+        Ok(self.route(None, |t| t.simulate(tx)).await?.1)
+    }
+
+    async fn submit(&self, tx: &SignedTransaction) -> Result<PendingTransaction, RestError> {
+        // Submitting the same signed transaction to several nodes is safe, it can only be
+        // committed once.
+        let (node, pending) = self.route(None, |t| t.submit(tx)).await?;
+        self.submitted_to
+            .lock()
+            .unwrap()
+            .insert(pending.hash.into(), node);
+        Ok(pending)
+    }
+
+    async fn wait_for_transaction(
+        &self,
+        pending: &PendingTransaction,
+    ) -> Result<Transaction, RestError> {
+        let hash: HashValue = pending.hash.into();
+        let node = self.submitted_to.lock().unwrap().remove(&hash);
+        let result = self
+            .route(node, |t| t.wait_for_transaction(pending))
+            .await
+            .map(|(_, tx)| tx);
+        let committed = match &result {
+            Ok(_) => true,
+            Err(RestError::Unknown(e)) => e.to_string().contains("failed execution"),
+            Err(_) => false,
+        };
+        if committed {
+            self.see_sequence_number(
+                *pending.request.sender.inner(),
+                pending.request.sequence_number.0 + 1,
+            );
+        }
+        result
+    }
+
+    async fn get_events_by_creation_number(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<VersionedEvent>, RestError> {
+        Ok(self
+            .route(None, |t| {
+                t.get_events_by_creation_number(address, creation_number, start, limit)
+            })
+            .await?
+            .1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aptos_sdk::crypto::ed25519::Ed25519PrivateKey;
+    use aptos_sdk::transaction_builder::TransactionFactory;
+    use aptos_sdk::types::chain_id::ChainId;
+    use aptos_sdk::types::{AccountKey, LocalAccount};
+    use serde_json::json;
+
+    use super::*;
+    use crate::generated::entry;
+    use crate::tests::{api_error, ECONIA};
+    use crate::transport::{MockOutcome, MockTransport};
+
+    fn sender() -> AccountAddress {
+        AccountAddress::from_hex_literal("0xa11ce").unwrap()
+    }
+
+    /// Transport failing over between `nodes`, named after their index. Their health is checked
+    /// again before every request if `recheck`, otherwise only with
+    /// [`FailoverTransport::check_health`].
+    async fn failover(
+        nodes: &[Arc<MockTransport>],
+        max_version_lag: u64,
+        recheck: bool,
+    ) -> FailoverTransport {
+        let transports = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (i.to_string(), node.clone() as Arc<dyn AptosTransport>))
+            .collect();
+        let config = FailoverConfig {
+            max_version_lag,
+            health_check_interval: if recheck {
+                Duration::ZERO
+            } else {
+                Duration::from_secs(60 * 60)
+            },
+            health_check_timeout: HEALTH_CHECK_TIMEOUT,
+        };
+        FailoverTransport::from_transports(transports, Some(config))
+            .await
+            .unwrap()
+    }
+
+    /// Raise the ledger version of a node.
+    fn advance(node: &MockTransport, versions: u64) {
+        let econia = AccountAddress::from_hex_literal(ECONIA).unwrap();
+        for _ in 0..versions {
+            node.push_event(econia, 0, "0xc0deb00c::user::FillEvent", json!({}))
+                .unwrap();
+        }
+    }
+
+    async fn sequence_number(transport: &dyn AptosTransport) -> u64 {
+        transport
+            .get_account(sender())
+            .await
+            .unwrap()
+            .sequence_number
+    }
+
+    fn signed_transaction(sequence_number: u64) -> SignedTransaction {
+        let private_key = Ed25519PrivateKey::try_from(&[7u8; 32][..]).unwrap();
+        let account = LocalAccount::new(sender(), AccountKey::from(private_key), sequence_number);
+        let econia = AccountAddress::from_hex_literal(ECONIA).unwrap();
+        let tx = TransactionFactory::new(ChainId::new(4))
+            .entry_function(entry::market::cancel_all_orders_user(econia, 1, true).unwrap())
+            .sender(sender())
+            .sequence_number(sequence_number)
+            .build();
+        account.sign_transaction(tx)
+    }
+
+    #[test]
+    fn classify_node_failures() {
+        let node_failures = [
+            api_error(503, AptosErrorCode::InternalError),
+            api_error(500, AptosErrorCode::WebFrameworkError),
+            api_error(429, AptosErrorCode::WebFrameworkError),
+            api_error(400, AptosErrorCode::MempoolIsFull),
+            RestError::Timeout("timed out"),
+        ];
+        assert!(node_failures.iter().all(is_node_failure));
+
+        let request_failures = [
+            api_error(400, AptosErrorCode::VmError),
+            api_error(404, AptosErrorCode::ResourceNotFound),
+            RestError::Unknown(anyhow!(
+                "Transaction committed on chain, but failed execution"
+            )),
+        ];
+        assert!(!request_failures.iter().any(is_node_failure));
+    }
+
+    #[tokio::test]
+    async fn lagging_nodes_are_unhealthy() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(4));
+        advance(&a, 3);
+
+        let transport = failover(&[a.clone(), b.clone()], 2, false).await;
+        let health = transport.health();
+        assert!(health[0].healthy);
+        assert!(!health[1].healthy);
+        assert_eq!(health[1].ledger_version, Some(0));
+
+        let transport = failover(&[a, b], 3, false).await;
+        assert!(transport.health().iter().all(|h| h.healthy));
+    }
+
+    #[tokio::test]
+    async fn exclude_nodes_of_another_chain() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(5));
+        // Versions of other chains do not make nodes of the transport lag.
+        advance(&b, 100);
+
+        let transport = failover(&[a.clone(), b.clone()], 0, false).await;
+        assert_eq!(transport.chain_id(), Some(4));
+        let health = transport.health();
+        assert!(health[0].healthy);
+        assert!(!health[1].healthy);
+        assert_eq!(health[1].chain_id, Some(5));
+
+        // Requests never fail over to the other chain.
+        a.push_outcome(MockOutcome::Rejected(api_error(
+            503,
+            AptosErrorCode::InternalError,
+        )));
+        assert!(transport.submit(&signed_transaction(0)).await.is_err());
+        assert!(b.submitted().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fail_over_on_node_failures() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(4));
+        // Requests go to the most recent node first.
+        advance(&a, 3);
+        let transport = failover(&[a.clone(), b.clone()], 0, false).await;
+
+        a.push_outcome(MockOutcome::Rejected(api_error(
+            503,
+            AptosErrorCode::InternalError,
+        )));
+        transport.submit(&signed_transaction(0)).await.unwrap();
+        assert!(a.submitted().is_empty());
+        assert_eq!(b.submitted().len(), 1);
+        assert!(!transport.health()[0].healthy);
+
+        transport.check_health().await;
+        a.push_outcome(MockOutcome::Rejected(RestError::Timeout("timed out")));
+        transport.submit(&signed_transaction(1)).await.unwrap();
+        assert!(a.submitted().is_empty());
+        assert_eq!(b.submitted().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn return_failures_of_requests() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(4));
+        advance(&a, 3);
+        let transport = failover(&[a.clone(), b.clone()], 0, false).await;
+
+        // Every node would abort the same.
+        a.push_outcome(MockOutcome::Rejected(api_error(
+            400,
+            AptosErrorCode::VmError,
+        )));
+        let result = transport.submit(&signed_transaction(0)).await;
+        assert!(matches!(result, Err(RestError::Api(a)) if a.status_code.as_u16() == 400));
+        assert!(b.submitted().is_empty());
+        assert!(transport.health()[0].healthy);
+    }
+
+    #[tokio::test]
+    async fn sequence_numbers_never_go_backwards() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(4));
+        advance(&a, 3);
+        a.set_sequence_number(sender(), 5);
+        b.set_sequence_number(sender(), 3);
+        let transport = failover(&[a.clone(), b.clone()], 0, true).await;
+        assert_eq!(sequence_number(&transport).await, 5);
+
+        // Once `a` lags, requests go to `b`, which has not seen the last transactions yet.
+        advance(&b, 10);
+        assert_eq!(sequence_number(&transport).await, 5);
+        let health = transport.health();
+        assert!(!health[0].healthy);
+        assert!(health[1].healthy);
+
+        b.set_sequence_number(sender(), 7);
+        assert_eq!(sequence_number(&transport).await, 7);
+    }
+
+    #[tokio::test]
+    async fn wait_on_the_node_submitted_to() {
+        let a = Arc::new(MockTransport::new(4));
+        let b = Arc::new(MockTransport::new(4));
+        advance(&a, 3);
+        let transport = failover(&[a.clone(), b.clone()], 0, false).await;
+        a.push_outcome(MockOutcome::Rejected(api_error(
+            503,
+            AptosErrorCode::InternalError,
+        )));
+        let pending = transport.submit(&signed_transaction(0)).await.unwrap();
+
+        // `a` is preferred again, but has never seen the transaction.
+        assert!(transport.check_health().await[0].healthy);
+        transport.wait_for_transaction(&pending).await.unwrap();
+
+        // The committed transaction raises the sequence number read from `a`.
+        assert_eq!(sequence_number(a.as_ref()).await, 0);
+        assert_eq!(sequence_number(&transport).await, 1);
+    }
+}
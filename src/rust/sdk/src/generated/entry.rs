@@ -0,0 +1,898 @@
+//! Entry function builders of the Econia package, grouped by Move module.
+//!
+//! This file is generated by `econia-sdk-codegen` from the `src/move/econia` package, do not
+//! edit it by hand. To regenerate it, run `cargo run -p econia-sdk-codegen` from `src/rust`.
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use aptos_sdk::bcs;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::ident_str;
+use aptos_sdk::move_types::language_storage::TypeTag;
+use aptos_sdk::types::transaction::EntryFunction;
+
+use super::{module_id, EntrySignature, MoveArgType};
+use crate::EconiaResult;
+
+pub mod incentives {
+    use super::*;
+
+    /// Create the `EntryFunction` for [update_incentives](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_update_incentives)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `market_registration_fee`: Move argument of type `u64`.
+    /// * `underwriter_registration_fee`: Move argument of type `u64`.
+    /// * `custodian_registration_fee`: Move argument of type `u64`.
+    /// * `taker_fee_divisor`: Move argument of type `u64`.
+    /// * `integrator_fee_store_tiers`: Move argument of type `vector<vector<u64>>`.
+    pub fn update_incentives(
+        econia_address: AccountAddress,
+        utility_coin_type: &TypeTag,
+        market_registration_fee: u64,
+        underwriter_registration_fee: u64,
+        custodian_registration_fee: u64,
+        taker_fee_divisor: u64,
+        integrator_fee_store_tiers: Vec<Vec<u64>>,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("update_incentives").to_owned(),
+            vec![utility_coin_type.clone()],
+            vec![
+                bcs::to_bytes(&market_registration_fee)?,
+                bcs::to_bytes(&underwriter_registration_fee)?,
+                bcs::to_bytes(&custodian_registration_fee)?,
+                bcs::to_bytes(&taker_fee_divisor)?,
+                bcs::to_bytes(&integrator_fee_store_tiers)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [upgrade_integrator_fee_store_via_coinstore](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_upgrade_integrator_fee_store_via_coinstore)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `new_tier`: Move argument of type `u8`.
+    pub fn upgrade_integrator_fee_store_via_coinstore(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        utility_coin_type: &TypeTag,
+        market_id: u64,
+        new_tier: u8,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("upgrade_integrator_fee_store_via_coinstore").to_owned(),
+            vec![quote_coin_type.clone(), utility_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&new_tier)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_econia_fees_all_to_coin_store](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_withdraw_econia_fees_all_to_coin_store)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    pub fn withdraw_econia_fees_all_to_coin_store(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        market_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("withdraw_econia_fees_all_to_coin_store").to_owned(),
+            vec![quote_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_econia_fees_to_coin_store](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_withdraw_econia_fees_to_coin_store)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `amount`: Move argument of type `u64`.
+    pub fn withdraw_econia_fees_to_coin_store(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        market_id: u64,
+        amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("withdraw_econia_fees_to_coin_store").to_owned(),
+            vec![quote_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&amount)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_integrator_fees_via_coinstores](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_withdraw_integrator_fees_via_coinstores)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    pub fn withdraw_integrator_fees_via_coinstores(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        utility_coin_type: &TypeTag,
+        market_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("withdraw_integrator_fees_via_coinstores").to_owned(),
+            vec![quote_coin_type.clone(), utility_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_utility_coins_all_to_coin_store](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_withdraw_utility_coins_all_to_coin_store)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    pub fn withdraw_utility_coins_all_to_coin_store(
+        econia_address: AccountAddress,
+        utility_coin_type: &TypeTag,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("withdraw_utility_coins_all_to_coin_store").to_owned(),
+            vec![utility_coin_type.clone()],
+            vec![],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_utility_coins_to_coin_store](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_withdraw_utility_coins_to_coin_store)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `amount`: Move argument of type `u64`.
+    pub fn withdraw_utility_coins_to_coin_store(
+        econia_address: AccountAddress,
+        utility_coin_type: &TypeTag,
+        amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "incentives")?,
+            ident_str!("withdraw_utility_coins_to_coin_store").to_owned(),
+            vec![utility_coin_type.clone()],
+            vec![bcs::to_bytes(&amount)?],
+        ))
+    }
+}
+
+pub mod market {
+    use super::*;
+
+    /// Create the `EntryFunction` for [cancel_all_orders_user](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_cancel_all_orders_user)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `side`: Move argument of type `bool`.
+    pub fn cancel_all_orders_user(
+        econia_address: AccountAddress,
+        market_id: u64,
+        side: bool,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("cancel_all_orders_user").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&side)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [cancel_order_user](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_cancel_order_user)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `side`: Move argument of type `bool`.
+    /// * `market_order_id`: Move argument of type `u128`.
+    pub fn cancel_order_user(
+        econia_address: AccountAddress,
+        market_id: u64,
+        side: bool,
+        market_order_id: u128,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("cancel_order_user").to_owned(),
+            vec![],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&market_order_id)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [change_order_size_user](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_change_order_size_user)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `side`: Move argument of type `bool`.
+    /// * `market_order_id`: Move argument of type `u128`.
+    /// * `new_size`: Move argument of type `u64`.
+    pub fn change_order_size_user(
+        econia_address: AccountAddress,
+        market_id: u64,
+        side: bool,
+        market_order_id: u128,
+        new_size: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("change_order_size_user").to_owned(),
+            vec![],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&market_order_id)?,
+                bcs::to_bytes(&new_size)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [index_orders_sdk](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_index_orders_sdk)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `_1`: Move argument of type `u64`.
+    pub fn index_orders_sdk(
+        econia_address: AccountAddress,
+        _1: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("index_orders_sdk").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&_1)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [place_limit_order_passive_advance_user_entry](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_limit_order_passive_advance_user_entry)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `integrator`: Move argument of type `address`.
+    /// * `side`: Move argument of type `bool`.
+    /// * `size`: Move argument of type `u64`.
+    /// * `advance_style`: Move argument of type `bool`.
+    /// * `target_advance_amount`: Move argument of type `u64`.
+    pub fn place_limit_order_passive_advance_user_entry(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        market_id: u64,
+        integrator: AccountAddress,
+        side: bool,
+        size: u64,
+        advance_style: bool,
+        target_advance_amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("place_limit_order_passive_advance_user_entry").to_owned(),
+            vec![base_type.clone(), quote_type.clone()],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&integrator)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&advance_style)?,
+                bcs::to_bytes(&target_advance_amount)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [place_limit_order_user_entry](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_limit_order_user_entry)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `integrator`: Move argument of type `address`.
+    /// * `side`: Move argument of type `bool`.
+    /// * `size`: Move argument of type `u64`.
+    /// * `price`: Move argument of type `u64`.
+    /// * `restriction`: Move argument of type `u8`.
+    /// * `self_match_behavior`: Move argument of type `u8`.
+    pub fn place_limit_order_user_entry(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        market_id: u64,
+        integrator: AccountAddress,
+        side: bool,
+        size: u64,
+        price: u64,
+        restriction: u8,
+        self_match_behavior: u8,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("place_limit_order_user_entry").to_owned(),
+            vec![base_type.clone(), quote_type.clone()],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&integrator)?,
+                bcs::to_bytes(&side)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&price)?,
+                bcs::to_bytes(&restriction)?,
+                bcs::to_bytes(&self_match_behavior)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [place_market_order_user_entry](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_place_market_order_user_entry)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `integrator`: Move argument of type `address`.
+    /// * `direction`: Move argument of type `bool`.
+    /// * `size`: Move argument of type `u64`.
+    /// * `self_match_behavior`: Move argument of type `u8`.
+    pub fn place_market_order_user_entry(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        market_id: u64,
+        integrator: AccountAddress,
+        direction: bool,
+        size: u64,
+        self_match_behavior: u8,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("place_market_order_user_entry").to_owned(),
+            vec![base_type.clone(), quote_type.clone()],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&integrator)?,
+                bcs::to_bytes(&direction)?,
+                bcs::to_bytes(&size)?,
+                bcs::to_bytes(&self_match_behavior)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [register_market_base_coin_from_coinstore](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_register_market_base_coin_from_coinstore)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `utility_type`: Aptos `TypeTag` for the `UtilityType` type parameter.
+    /// * `lot_size`: Move argument of type `u64`.
+    /// * `tick_size`: Move argument of type `u64`.
+    /// * `min_size`: Move argument of type `u64`.
+    pub fn register_market_base_coin_from_coinstore(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        utility_type: &TypeTag,
+        lot_size: u64,
+        tick_size: u64,
+        min_size: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("register_market_base_coin_from_coinstore").to_owned(),
+            vec![base_type.clone(), quote_type.clone(), utility_type.clone()],
+            vec![
+                bcs::to_bytes(&lot_size)?,
+                bcs::to_bytes(&tick_size)?,
+                bcs::to_bytes(&min_size)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [swap_between_coinstores_entry](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_swap_between_coinstores_entry)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `integrator`: Move argument of type `address`.
+    /// * `direction`: Move argument of type `bool`.
+    /// * `min_base`: Move argument of type `u64`.
+    /// * `max_base`: Move argument of type `u64`.
+    /// * `min_quote`: Move argument of type `u64`.
+    /// * `max_quote`: Move argument of type `u64`.
+    /// * `limit_price`: Move argument of type `u64`.
+    pub fn swap_between_coinstores_entry(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        market_id: u64,
+        integrator: AccountAddress,
+        direction: bool,
+        min_base: u64,
+        max_base: u64,
+        min_quote: u64,
+        max_quote: u64,
+        limit_price: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "market")?,
+            ident_str!("swap_between_coinstores_entry").to_owned(),
+            vec![base_type.clone(), quote_type.clone()],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&integrator)?,
+                bcs::to_bytes(&direction)?,
+                bcs::to_bytes(&min_base)?,
+                bcs::to_bytes(&max_base)?,
+                bcs::to_bytes(&min_quote)?,
+                bcs::to_bytes(&max_quote)?,
+                bcs::to_bytes(&limit_price)?,
+            ],
+        ))
+    }
+}
+
+pub mod registry {
+    use super::*;
+
+    /// Create the `EntryFunction` for [register_integrator_fee_store_base_tier](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_register_integrator_fee_store_base_tier)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    pub fn register_integrator_fee_store_base_tier(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        utility_coin_type: &TypeTag,
+        market_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("register_integrator_fee_store_base_tier").to_owned(),
+            vec![quote_coin_type.clone(), utility_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [register_integrator_fee_store_from_coinstore](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_register_integrator_fee_store_from_coinstore)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `tier`: Move argument of type `u8`.
+    pub fn register_integrator_fee_store_from_coinstore(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        utility_coin_type: &TypeTag,
+        market_id: u64,
+        tier: u8,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("register_integrator_fee_store_from_coinstore").to_owned(),
+            vec![quote_coin_type.clone(), utility_coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&tier)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [remove_recognized_market](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_remove_recognized_market)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    pub fn remove_recognized_market(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("remove_recognized_market").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [remove_recognized_markets](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_remove_recognized_markets)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_ids`: Move argument of type `vector<u64>`.
+    pub fn remove_recognized_markets(
+        econia_address: AccountAddress,
+        market_ids: Vec<u64>,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("remove_recognized_markets").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_ids)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [set_recognized_market](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_set_recognized_market)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    pub fn set_recognized_market(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("set_recognized_market").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [set_recognized_markets](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_set_recognized_markets)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_ids`: Move argument of type `vector<u64>`.
+    pub fn set_recognized_markets(
+        econia_address: AccountAddress,
+        market_ids: Vec<u64>,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "registry")?,
+            ident_str!("set_recognized_markets").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_ids)?],
+        ))
+    }
+}
+
+pub mod user {
+    use super::*;
+
+    /// Create the `EntryFunction` for [deposit_from_coinstore](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_deposit_from_coinstore)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `coin_type`: Aptos `TypeTag` for the `CoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    /// * `amount`: Move argument of type `u64`.
+    pub fn deposit_from_coinstore(
+        econia_address: AccountAddress,
+        coin_type: &TypeTag,
+        market_id: u64,
+        custodian_id: u64,
+        amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "user")?,
+            ident_str!("deposit_from_coinstore").to_owned(),
+            vec![coin_type.clone()],
+            vec![
+                bcs::to_bytes(&market_id)?,
+                bcs::to_bytes(&custodian_id)?,
+                bcs::to_bytes(&amount)?,
+            ],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [init_market_event_handles_if_missing](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_init_market_event_handles_if_missing)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    pub fn init_market_event_handles_if_missing(
+        econia_address: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "user")?,
+            ident_str!("init_market_event_handles_if_missing").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&custodian_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [register_market_account](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_register_market_account)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    pub fn register_market_account(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "user")?,
+            ident_str!("register_market_account").to_owned(),
+            vec![base_type.clone(), quote_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&custodian_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [register_market_account_generic_base](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_register_market_account_generic_base)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    pub fn register_market_account_generic_base(
+        econia_address: AccountAddress,
+        quote_type: &TypeTag,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "user")?,
+            ident_str!("register_market_account_generic_base").to_owned(),
+            vec![quote_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&custodian_id)?],
+        ))
+    }
+
+    /// Create the `EntryFunction` for [withdraw_to_coinstore](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_withdraw_to_coinstore)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `coin_type`: Aptos `TypeTag` for the `CoinType` type parameter.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `amount`: Move argument of type `u64`.
+    pub fn withdraw_to_coinstore(
+        econia_address: AccountAddress,
+        coin_type: &TypeTag,
+        market_id: u64,
+        amount: u64,
+    ) -> EconiaResult<EntryFunction> {
+        Ok(EntryFunction::new(
+            module_id(econia_address, "user")?,
+            ident_str!("withdraw_to_coinstore").to_owned(),
+            vec![coin_type.clone()],
+            vec![bcs::to_bytes(&market_id)?, bcs::to_bytes(&amount)?],
+        ))
+    }
+}
+
+/// Signature of every entry function, to decode the arguments of a payload.
+pub const ENTRY_SIGNATURES: &[EntrySignature] = &[
+    EntrySignature {
+        module: "incentives",
+        name: "update_incentives",
+        params: &[
+            ("market_registration_fee", MoveArgType::U64),
+            ("underwriter_registration_fee", MoveArgType::U64),
+            ("custodian_registration_fee", MoveArgType::U64),
+            ("taker_fee_divisor", MoveArgType::U64),
+            (
+                "integrator_fee_store_tiers",
+                MoveArgType::Vector(&MoveArgType::Vector(&MoveArgType::U64)),
+            ),
+        ],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "upgrade_integrator_fee_store_via_coinstore",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("new_tier", MoveArgType::U8),
+        ],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "withdraw_econia_fees_all_to_coin_store",
+        params: &[("market_id", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "withdraw_econia_fees_to_coin_store",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("amount", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "withdraw_integrator_fees_via_coinstores",
+        params: &[("market_id", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "withdraw_utility_coins_all_to_coin_store",
+        params: &[],
+    },
+    EntrySignature {
+        module: "incentives",
+        name: "withdraw_utility_coins_to_coin_store",
+        params: &[("amount", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "market",
+        name: "cancel_all_orders_user",
+        params: &[("market_id", MoveArgType::U64), ("side", MoveArgType::Bool)],
+    },
+    EntrySignature {
+        module: "market",
+        name: "cancel_order_user",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("side", MoveArgType::Bool),
+            ("market_order_id", MoveArgType::U128),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "change_order_size_user",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("side", MoveArgType::Bool),
+            ("market_order_id", MoveArgType::U128),
+            ("new_size", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "index_orders_sdk",
+        params: &[("_1", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "market",
+        name: "place_limit_order_passive_advance_user_entry",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("integrator", MoveArgType::Address),
+            ("side", MoveArgType::Bool),
+            ("size", MoveArgType::U64),
+            ("advance_style", MoveArgType::Bool),
+            ("target_advance_amount", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "place_limit_order_user_entry",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("integrator", MoveArgType::Address),
+            ("side", MoveArgType::Bool),
+            ("size", MoveArgType::U64),
+            ("price", MoveArgType::U64),
+            ("restriction", MoveArgType::U8),
+            ("self_match_behavior", MoveArgType::U8),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "place_market_order_user_entry",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("integrator", MoveArgType::Address),
+            ("direction", MoveArgType::Bool),
+            ("size", MoveArgType::U64),
+            ("self_match_behavior", MoveArgType::U8),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "register_market_base_coin_from_coinstore",
+        params: &[
+            ("lot_size", MoveArgType::U64),
+            ("tick_size", MoveArgType::U64),
+            ("min_size", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "market",
+        name: "swap_between_coinstores_entry",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("integrator", MoveArgType::Address),
+            ("direction", MoveArgType::Bool),
+            ("min_base", MoveArgType::U64),
+            ("max_base", MoveArgType::U64),
+            ("min_quote", MoveArgType::U64),
+            ("max_quote", MoveArgType::U64),
+            ("limit_price", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "register_integrator_fee_store_base_tier",
+        params: &[("market_id", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "register_integrator_fee_store_from_coinstore",
+        params: &[("market_id", MoveArgType::U64), ("tier", MoveArgType::U8)],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "remove_recognized_market",
+        params: &[("market_id", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "remove_recognized_markets",
+        params: &[("market_ids", MoveArgType::Vector(&MoveArgType::U64))],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "set_recognized_market",
+        params: &[("market_id", MoveArgType::U64)],
+    },
+    EntrySignature {
+        module: "registry",
+        name: "set_recognized_markets",
+        params: &[("market_ids", MoveArgType::Vector(&MoveArgType::U64))],
+    },
+    EntrySignature {
+        module: "user",
+        name: "deposit_from_coinstore",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("custodian_id", MoveArgType::U64),
+            ("amount", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "user",
+        name: "init_market_event_handles_if_missing",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("custodian_id", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "user",
+        name: "register_market_account",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("custodian_id", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "user",
+        name: "register_market_account_generic_base",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("custodian_id", MoveArgType::U64),
+        ],
+    },
+    EntrySignature {
+        module: "user",
+        name: "withdraw_to_coinstore",
+        params: &[
+            ("market_id", MoveArgType::U64),
+            ("amount", MoveArgType::U64),
+        ],
+    },
+];
@@ -0,0 +1,78 @@
+//! The [`generated`](crate::generated) module exposes a builder for every entry and view function
+//! of the Econia package, generated from its compiled ABIs and Move sources by
+//! `econia-sdk-codegen` so that the SDK cannot drift from the package.
+//!
+//! Builders are grouped by Move module, e.g. [`entry::market::place_limit_order_user_entry`] or
+//! [`view::registry::get_market_info`]. Entry builders return an `EntryFunction` to submit with
+//! [`EconiaClient::submit_tx`](crate::EconiaClient::submit_tx), and view builders return a
+//! `ViewRequest` to call with [`EconiaViewClient::call`](crate::view::EconiaViewClient::call).
+//!
+//! Unlike the hand-written [`entry`](crate::entry) and [`view`](crate::view) modules, arguments
+//! are passed as their raw Move types, e.g. a side is a `bool` and a restriction a `u8`.
+use std::str::FromStr;
+
+use aptos_api_types::{EntryFunctionId, IdentifierWrapper, MoveModuleId};
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::language_storage::ModuleId;
+
+use crate::errors::EconiaError;
+use crate::EconiaResult;
+
+pub mod entry;
+pub mod view;
+
+/// Move type of an entry function argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveArgType {
+    Bool,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Address,
+    /// `0x1::string::String`.
+    String,
+    Vector(&'static MoveArgType),
+}
+
+/// Name and argument types of an entry function, signers excluded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntrySignature {
+    pub module: &'static str,
+    pub name: &'static str,
+    pub params: &'static [(&'static str, MoveArgType)],
+}
+
+/// Signature of an entry function of the Econia package, `None` if there is no such function.
+///
+/// # Arguments:
+///
+/// * `module` - Name of the Move module.
+/// * `name` - Name of the entry function.
+pub fn entry_signature(module: &str, name: &str) -> Option<&'static EntrySignature> {
+    entry::ENTRY_SIGNATURES
+        .iter()
+        .find(|s| s.module == module && s.name == name)
+}
+
+fn module_id(econia_address: AccountAddress, module: &str) -> EconiaResult<ModuleId> {
+    Ok(ModuleId::from(move_module_id(econia_address, module)?))
+}
+
+fn function_id(
+    econia_address: AccountAddress,
+    module: &str,
+    name: &str,
+) -> EconiaResult<EntryFunctionId> {
+    Ok(EntryFunctionId {
+        module: move_module_id(econia_address, module)?,
+        name: IdentifierWrapper::from_str(name)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?,
+    })
+}
+
+fn move_module_id(econia_address: AccountAddress, module: &str) -> EconiaResult<MoveModuleId> {
+    MoveModuleId::from_str(&format!("{}::{}", econia_address, module))
+        .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))
+}
@@ -0,0 +1,1538 @@
+//! View function request builders of the Econia package, grouped by Move module.
+//!
+//! This file is generated by `econia-sdk-codegen` from the `src/move/econia` package, do not
+//! edit it by hand. To regenerate it, run `cargo run -p econia-sdk-codegen` from `src/rust`.
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use aptos_api_types::{MoveType, ViewRequest};
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::language_storage::TypeTag;
+use serde_json::json;
+
+use super::function_id;
+use crate::EconiaResult;
+
+pub mod incentives {
+    use super::*;
+
+    /// Create the `ViewRequest` for `get_cost_to_upgrade_integrator_fee_store_view`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `utility_coin_type`: Aptos `TypeTag` for the `UtilityCoinType` type parameter.
+    /// * `integrator_address`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `new_tier`: Move argument of type `u8`.
+    ///
+    /// Returns `u64`.
+    pub fn get_cost_to_upgrade_integrator_fee_store_view(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        utility_coin_type: &TypeTag,
+        integrator_address: AccountAddress,
+        market_id: u64,
+        new_tier: u8,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "incentives",
+                "get_cost_to_upgrade_integrator_fee_store_view",
+            )?,
+            type_arguments: vec![
+                MoveType::from(quote_coin_type),
+                MoveType::from(utility_coin_type),
+            ],
+            arguments: vec![
+                json!(integrator_address.to_string()),
+                json!(market_id.to_string()),
+                json!(new_tier),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_custodian_registration_fee](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_custodian_registration_fee)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_custodian_registration_fee(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "incentives",
+                "get_custodian_registration_fee",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_fee_share_divisor](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_fee_share_divisor)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `tier`: Move argument of type `u8`.
+    ///
+    /// Returns `u64`.
+    pub fn get_fee_share_divisor(
+        econia_address: AccountAddress,
+        tier: u8,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_fee_share_divisor")?,
+            type_arguments: vec![],
+            arguments: vec![json!(tier)],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_integrator_withdrawal_fee_view`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `integrator_address`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `u64`.
+    pub fn get_integrator_withdrawal_fee_view(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        integrator_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "incentives",
+                "get_integrator_withdrawal_fee_view",
+            )?,
+            type_arguments: vec![MoveType::from(quote_coin_type)],
+            arguments: vec![
+                json!(integrator_address.to_string()),
+                json!(market_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_market_registration_fee](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_market_registration_fee)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_market_registration_fee(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_market_registration_fee")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_n_fee_store_tiers](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_n_fee_store_tiers)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_n_fee_store_tiers(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_n_fee_store_tiers")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_taker_fee_divisor](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_taker_fee_divisor)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_taker_fee_divisor(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_taker_fee_divisor")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_tier_activation_fee](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_tier_activation_fee)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `tier`: Move argument of type `u8`.
+    ///
+    /// Returns `u64`.
+    pub fn get_tier_activation_fee(
+        econia_address: AccountAddress,
+        tier: u8,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_tier_activation_fee")?,
+            type_arguments: vec![],
+            arguments: vec![json!(tier)],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_tier_withdrawal_fee](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_tier_withdrawal_fee)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `tier`: Move argument of type `u8`.
+    ///
+    /// Returns `u64`.
+    pub fn get_tier_withdrawal_fee(
+        econia_address: AccountAddress,
+        tier: u8,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "get_tier_withdrawal_fee")?,
+            type_arguments: vec![],
+            arguments: vec![json!(tier)],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_underwriter_registration_fee](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_get_underwriter_registration_fee)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_underwriter_registration_fee(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "incentives",
+                "get_underwriter_registration_fee",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [is_utility_coin_type](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/incentives.md#0xc0deb00c_incentives_is_utility_coin_type)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `t`: Aptos `TypeTag` for the `T` type parameter.
+    ///
+    /// Returns `bool`.
+    pub fn is_utility_coin_type(
+        econia_address: AccountAddress,
+        t: &TypeTag,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "incentives", "is_utility_coin_type")?,
+            type_arguments: vec![MoveType::from(t)],
+            arguments: vec![],
+        })
+    }
+}
+
+pub mod market {
+    use super::*;
+
+    /// Create the `ViewRequest` for [did_order_post](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_did_order_post)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `bool`.
+    pub fn did_order_post(
+        econia_address: AccountAddress,
+        order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "did_order_post")?,
+            type_arguments: vec![],
+            arguments: vec![json!(order_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_ABORT](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_ABORT)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_ABORT(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_ABORT")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_ASK](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_ASK)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_ASK(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_ASK")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_BID](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_BID)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_BID(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_BID")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_BUY](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_BUY)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_BUY(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_BUY")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_BOTH](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_CANCEL_BOTH)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_BOTH(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_CANCEL_BOTH")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_MAKER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_CANCEL_MAKER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_MAKER(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_CANCEL_MAKER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_TAKER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_CANCEL_TAKER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_TAKER(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_CANCEL_TAKER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_FILL_OR_ABORT](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_FILL_OR_ABORT)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_FILL_OR_ABORT(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_FILL_OR_ABORT")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_HI_PRICE](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_HI_PRICE)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_HI_PRICE(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_HI_PRICE")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_IMMEDIATE_OR_CANCEL](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_IMMEDIATE_OR_CANCEL)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_IMMEDIATE_OR_CANCEL(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_IMMEDIATE_OR_CANCEL")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_MAX_POSSIBLE](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_MAX_POSSIBLE)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_MAX_POSSIBLE(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_MAX_POSSIBLE")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_CUSTODIAN](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_NO_CUSTODIAN)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_NO_CUSTODIAN(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_NO_CUSTODIAN")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_RESTRICTION](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_NO_RESTRICTION)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_NO_RESTRICTION(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_NO_RESTRICTION")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_UNDERWRITER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_NO_UNDERWRITER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_NO_UNDERWRITER(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_NO_UNDERWRITER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_PERCENT](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_PERCENT)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_PERCENT(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_PERCENT")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_POST_OR_ABORT](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_POST_OR_ABORT)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_POST_OR_ABORT(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_POST_OR_ABORT")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_SELL](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_SELL)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_SELL(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_SELL")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_TICKS](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_TICKS)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_TICKS(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_TICKS")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_event_handle_creation_info`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `Option<MarketEventHandleCreationInfo>`.
+    pub fn get_market_event_handle_creation_info(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "market",
+                "get_market_event_handle_creation_info",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_market_order_id_counter](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_market_order_id_counter)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `u64`.
+    pub fn get_market_order_id_counter(
+        econia_address: AccountAddress,
+        market_order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_market_order_id_counter")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_order_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_market_order_id_price](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_market_order_id_price)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `u64`.
+    pub fn get_market_order_id_price(
+        econia_address: AccountAddress,
+        market_order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_market_order_id_price")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_order_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_open_order](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_open_order)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `Option<OrderView>`.
+    pub fn get_open_order(
+        econia_address: AccountAddress,
+        market_id: u64,
+        order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_open_order")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string()), json!(order_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_open_orders`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `n_asks_max`: Move argument of type `u64`.
+    /// * `n_bids_max`: Move argument of type `u64`.
+    ///
+    /// Returns `OrdersView`.
+    pub fn get_open_orders(
+        econia_address: AccountAddress,
+        market_id: u64,
+        n_asks_max: u64,
+        n_bids_max: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_open_orders")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(market_id.to_string()),
+                json!(n_asks_max.to_string()),
+                json!(n_bids_max.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_open_orders_all`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `OrdersView`.
+    pub fn get_open_orders_all(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_open_orders_all")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_open_orders_paginated](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_open_orders_paginated)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `n_asks_to_index_max`: Move argument of type `u64`.
+    /// * `n_bids_to_index_max`: Move argument of type `u64`.
+    /// * `starting_ask_order_id`: Move argument of type `u128`.
+    /// * `starting_bid_order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `(OrdersView, u128, u128)`.
+    pub fn get_open_orders_paginated(
+        econia_address: AccountAddress,
+        market_id: u64,
+        n_asks_to_index_max: u64,
+        n_bids_to_index_max: u64,
+        starting_ask_order_id: u128,
+        starting_bid_order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_open_orders_paginated")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(market_id.to_string()),
+                json!(n_asks_to_index_max.to_string()),
+                json!(n_bids_to_index_max.to_string()),
+                json!(starting_ask_order_id.to_string()),
+                json!(starting_bid_order_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_posted_order_id_side](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_posted_order_id_side)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `bool`.
+    pub fn get_posted_order_id_side(
+        econia_address: AccountAddress,
+        order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_posted_order_id_side")?,
+            type_arguments: vec![],
+            arguments: vec![json!(order_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_price_levels`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `n_ask_levels_max`: Move argument of type `u64`.
+    /// * `n_bid_levels_max`: Move argument of type `u64`.
+    ///
+    /// Returns `PriceLevels`.
+    pub fn get_price_levels(
+        econia_address: AccountAddress,
+        market_id: u64,
+        n_ask_levels_max: u64,
+        n_bid_levels_max: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_price_levels")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(market_id.to_string()),
+                json!(n_ask_levels_max.to_string()),
+                json!(n_bid_levels_max.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_price_levels_all`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `PriceLevels`.
+    pub fn get_price_levels_all(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_price_levels_all")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_price_levels_paginated](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_get_price_levels_paginated)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `n_asks_to_index_max`: Move argument of type `u64`.
+    /// * `n_bids_to_index_max`: Move argument of type `u64`.
+    /// * `starting_ask_order_id`: Move argument of type `u128`.
+    /// * `starting_bid_order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `(PriceLevels, u128, u128)`.
+    pub fn get_price_levels_paginated(
+        econia_address: AccountAddress,
+        market_id: u64,
+        n_asks_to_index_max: u64,
+        n_bids_to_index_max: u64,
+        starting_ask_order_id: u128,
+        starting_bid_order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "get_price_levels_paginated")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(market_id.to_string()),
+                json!(n_asks_to_index_max.to_string()),
+                json!(n_bids_to_index_max.to_string()),
+                json!(starting_ask_order_id.to_string()),
+                json!(starting_bid_order_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_swapper_event_handle_creation_numbers`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `swapper`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `Option<SwapperEventHandleCreationNumbers>`.
+    pub fn get_swapper_event_handle_creation_numbers(
+        econia_address: AccountAddress,
+        swapper: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "market",
+                "get_swapper_event_handle_creation_numbers",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![json!(swapper.to_string()), json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_open_order](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/market.md#0xc0deb00c_market_has_open_order)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `order_id`: Move argument of type `u128`.
+    ///
+    /// Returns `bool`.
+    pub fn has_open_order(
+        econia_address: AccountAddress,
+        market_id: u64,
+        order_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "market", "has_open_order")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string()), json!(order_id.to_string())],
+        })
+    }
+}
+
+pub mod registry {
+    use super::*;
+
+    /// Create the `ViewRequest` for [get_MAX_CHARACTERS_GENERIC](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_MAX_CHARACTERS_GENERIC)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_MAX_CHARACTERS_GENERIC(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_MAX_CHARACTERS_GENERIC")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_MIN_CHARACTERS_GENERIC](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_MIN_CHARACTERS_GENERIC)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_MIN_CHARACTERS_GENERIC(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_MIN_CHARACTERS_GENERIC")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_CUSTODIAN](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_NO_CUSTODIAN)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_NO_CUSTODIAN(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_NO_CUSTODIAN")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_UNDERWRITER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_NO_UNDERWRITER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_NO_UNDERWRITER(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_NO_UNDERWRITER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_counts`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `MarketCounts`.
+    pub fn get_market_counts(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_market_counts")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_id_base_coin`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_type`: Aptos `TypeTag` for the `BaseType` type parameter.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `lot_size`: Move argument of type `u64`.
+    /// * `tick_size`: Move argument of type `u64`.
+    /// * `min_size`: Move argument of type `u64`.
+    ///
+    /// Returns `Option<u64>`.
+    pub fn get_market_id_base_coin(
+        econia_address: AccountAddress,
+        base_type: &TypeTag,
+        quote_type: &TypeTag,
+        lot_size: u64,
+        tick_size: u64,
+        min_size: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_market_id_base_coin")?,
+            type_arguments: vec![MoveType::from(base_type), MoveType::from(quote_type)],
+            arguments: vec![
+                json!(lot_size.to_string()),
+                json!(tick_size.to_string()),
+                json!(min_size.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_id_base_generic`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_type`: Aptos `TypeTag` for the `QuoteType` type parameter.
+    /// * `base_name_generic`: Move argument of type `String`.
+    /// * `lot_size`: Move argument of type `u64`.
+    /// * `tick_size`: Move argument of type `u64`.
+    /// * `min_size`: Move argument of type `u64`.
+    /// * `underwriter_id`: Move argument of type `u64`.
+    ///
+    /// Returns `Option<u64>`.
+    pub fn get_market_id_base_generic(
+        econia_address: AccountAddress,
+        quote_type: &TypeTag,
+        base_name_generic: String,
+        lot_size: u64,
+        tick_size: u64,
+        min_size: u64,
+        underwriter_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_market_id_base_generic")?,
+            type_arguments: vec![MoveType::from(quote_type)],
+            arguments: vec![
+                json!(base_name_generic),
+                json!(lot_size.to_string()),
+                json!(tick_size.to_string()),
+                json!(min_size.to_string()),
+                json!(underwriter_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_info`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `MarketInfoView`.
+    pub fn get_market_info(
+        econia_address: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "registry", "get_market_info")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_recognized_market_id_base_coin](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_recognized_market_id_base_coin)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_coin_type`: Aptos `TypeTag` for the `BaseCoinType` type parameter.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    ///
+    /// Returns `u64`.
+    pub fn get_recognized_market_id_base_coin(
+        econia_address: AccountAddress,
+        base_coin_type: &TypeTag,
+        quote_coin_type: &TypeTag,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "registry",
+                "get_recognized_market_id_base_coin",
+            )?,
+            type_arguments: vec![
+                MoveType::from(base_coin_type),
+                MoveType::from(quote_coin_type),
+            ],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_recognized_market_id_base_generic](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_get_recognized_market_id_base_generic)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `base_name_generic`: Move argument of type `String`.
+    ///
+    /// Returns `u64`.
+    pub fn get_recognized_market_id_base_generic(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        base_name_generic: String,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "registry",
+                "get_recognized_market_id_base_generic",
+            )?,
+            type_arguments: vec![MoveType::from(quote_coin_type)],
+            arguments: vec![json!(base_name_generic)],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_recognized_market_base_coin_by_type](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_has_recognized_market_base_coin_by_type)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `base_coin_type`: Aptos `TypeTag` for the `BaseCoinType` type parameter.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    ///
+    /// Returns `bool`.
+    pub fn has_recognized_market_base_coin_by_type(
+        econia_address: AccountAddress,
+        base_coin_type: &TypeTag,
+        quote_coin_type: &TypeTag,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "registry",
+                "has_recognized_market_base_coin_by_type",
+            )?,
+            type_arguments: vec![
+                MoveType::from(base_coin_type),
+                MoveType::from(quote_coin_type),
+            ],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_recognized_market_base_generic_by_type](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/registry.md#0xc0deb00c_registry_has_recognized_market_base_generic_by_type)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `quote_coin_type`: Aptos `TypeTag` for the `QuoteCoinType` type parameter.
+    /// * `base_name_generic`: Move argument of type `String`.
+    ///
+    /// Returns `bool`.
+    pub fn has_recognized_market_base_generic_by_type(
+        econia_address: AccountAddress,
+        quote_coin_type: &TypeTag,
+        base_name_generic: String,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "registry",
+                "has_recognized_market_base_generic_by_type",
+            )?,
+            type_arguments: vec![MoveType::from(quote_coin_type)],
+            arguments: vec![json!(base_name_generic)],
+        })
+    }
+}
+
+pub mod resource_account {
+    use super::*;
+
+    /// Create the `ViewRequest` for [get_address](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/resource_account.md#0xc0deb00c_resource_account_get_address)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `address`.
+    pub fn get_address(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "resource_account", "get_address")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+}
+
+pub mod user {
+    use super::*;
+
+    /// Create the `ViewRequest` for [get_ASK](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_ASK)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_ASK(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_ASK")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_BID](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_BID)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `bool`.
+    pub fn get_BID(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_BID")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_EVICTION](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_EVICTION)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_EVICTION(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_CANCEL_REASON_EVICTION")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_IMMEDIATE_OR_CANCEL](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_IMMEDIATE_OR_CANCEL)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_IMMEDIATE_OR_CANCEL(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_CANCEL_REASON_IMMEDIATE_OR_CANCEL",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_MANUAL_CANCEL](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_MANUAL_CANCEL)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_MANUAL_CANCEL(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_CANCEL_REASON_MANUAL_CANCEL")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_MAX_QUOTE_TRADED](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_MAX_QUOTE_TRADED)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_MAX_QUOTE_TRADED(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_CANCEL_REASON_MAX_QUOTE_TRADED")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_NOT_ENOUGH_LIQUIDITY](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_NOT_ENOUGH_LIQUIDITY)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_NOT_ENOUGH_LIQUIDITY(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_CANCEL_REASON_NOT_ENOUGH_LIQUIDITY",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_SELF_MATCH_MAKER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_SELF_MATCH_MAKER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_SELF_MATCH_MAKER(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_CANCEL_REASON_SELF_MATCH_MAKER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_SELF_MATCH_TAKER](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_SELF_MATCH_TAKER)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_SELF_MATCH_TAKER(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_CANCEL_REASON_SELF_MATCH_TAKER")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_TOO_SMALL_TO_FILL_LOT](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_TOO_SMALL_TO_FILL_LOT)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_TOO_SMALL_TO_FILL_LOT(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_CANCEL_REASON_TOO_SMALL_TO_FILL_LOT",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_CANCEL_REASON_VIOLATED_LIMIT_PRICE](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_CANCEL_REASON_VIOLATED_LIMIT_PRICE)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u8`.
+    pub fn get_CANCEL_REASON_VIOLATED_LIMIT_PRICE(
+        econia_address: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_CANCEL_REASON_VIOLATED_LIMIT_PRICE",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_NO_CUSTODIAN](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_NO_CUSTODIAN)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    ///
+    /// Returns `u64`.
+    pub fn get_NO_CUSTODIAN(econia_address: AccountAddress) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_NO_CUSTODIAN")?,
+            type_arguments: vec![],
+            arguments: vec![],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_all_market_account_ids_for_market_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_all_market_account_ids_for_market_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `vector<u128>`.
+    pub fn get_all_market_account_ids_for_market_id(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_all_market_account_ids_for_market_id",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![json!(user.to_string()), json!(market_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_all_market_account_ids_for_user](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_all_market_account_ids_for_user)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    ///
+    /// Returns `vector<u128>`.
+    pub fn get_all_market_account_ids_for_user(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_all_market_account_ids_for_user",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![json!(user.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_custodian_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_custodian_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_account_id`: Move argument of type `u128`.
+    ///
+    /// Returns `u64`.
+    pub fn get_custodian_id(
+        econia_address: AccountAddress,
+        market_account_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_custodian_id")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_account_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_account`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    ///
+    /// Returns `MarketAccountView`.
+    pub fn get_market_account(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_market_account")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(user.to_string()),
+                json!(market_id.to_string()),
+                json!(custodian_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_market_account_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_market_account_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    ///
+    /// Returns `u128`.
+    pub fn get_market_account_id(
+        econia_address: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_market_account_id")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(market_id.to_string()),
+                json!(custodian_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_accounts`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    ///
+    /// Returns `vector<MarketAccountView>`.
+    pub fn get_market_accounts(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_market_accounts")?,
+            type_arguments: vec![],
+            arguments: vec![json!(user.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for `get_market_event_handle_creation_numbers`
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    ///
+    /// Returns `Option<MarketEventHandleCreationNumbers>`.
+    pub fn get_market_event_handle_creation_numbers(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "get_market_event_handle_creation_numbers",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(user.to_string()),
+                json!(market_id.to_string()),
+                json!(custodian_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [get_market_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_get_market_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `market_account_id`: Move argument of type `u128`.
+    ///
+    /// Returns `u64`.
+    pub fn get_market_id(
+        econia_address: AccountAddress,
+        market_account_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "get_market_id")?,
+            type_arguments: vec![],
+            arguments: vec![json!(market_account_id.to_string())],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_market_account](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_has_market_account)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    /// * `custodian_id`: Move argument of type `u64`.
+    ///
+    /// Returns `bool`.
+    pub fn has_market_account(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "has_market_account")?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(user.to_string()),
+                json!(market_id.to_string()),
+                json!(custodian_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_market_account_by_market_account_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_has_market_account_by_market_account_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_account_id`: Move argument of type `u128`.
+    ///
+    /// Returns `bool`.
+    pub fn has_market_account_by_market_account_id(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_account_id: u128,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(
+                econia_address,
+                "user",
+                "has_market_account_by_market_account_id",
+            )?,
+            type_arguments: vec![],
+            arguments: vec![
+                json!(user.to_string()),
+                json!(market_account_id.to_string()),
+            ],
+        })
+    }
+
+    /// Create the `ViewRequest` for [has_market_account_by_market_id](https://github.com/econia-labs/econia/blob/main/src/move/econia/doc/user.md#0xc0deb00c_user_has_market_account_by_market_id)
+    ///
+    /// Arguments:
+    /// * `econia_address`: Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `user`: Move argument of type `address`.
+    /// * `market_id`: Move argument of type `u64`.
+    ///
+    /// Returns `bool`.
+    pub fn has_market_account_by_market_id(
+        econia_address: AccountAddress,
+        user: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<ViewRequest> {
+        Ok(ViewRequest {
+            function: function_id(econia_address, "user", "has_market_account_by_market_id")?,
+            type_arguments: vec![],
+            arguments: vec![json!(user.to_string()), json!(market_id.to_string())],
+        })
+    }
+}
@@ -5,37 +5,102 @@
 //!
 //! To use the SDK create an [EconiaClient], then either use it to get an [EconiaViewClient], or
 //! create a payload using helper functions from the [entry] module and submit it using
-//! [`EconiaClient::submit_tx`].
+//! [`EconiaClient::submit_tx`]. Transactions are signed by a [`Signer`](signer::Signer), which
+//! can hold the private key in memory, decrypt it from a keystore file or delegate to an external
+//! process. Failed submissions are retried according to the [`RetryPolicy`](retry::RetryPolicy)
+//! of the client. To sign transactions on an air-gapped machine instead, prepare, sign and submit
+//! them separately with the [offline] module. To have a sponsor pay the gas of the transactions of
+//! a user, see the [sponsor] module.
+//!
+//! To keep several transactions in flight at once, submit them through a
+//! [`TransactionPipeline`](pipeline::TransactionPipeline) instead. To call several entry functions
+//! in a single transaction, batch them with a [`BatchBuilder`](batch::BatchBuilder).
+//!
+//! To follow the events of a market or a user, use an
+//! [`EventSubscription`](subscription::EventSubscription), and to replicate the order book of a
+//! market locally, an [`OrderBook`](book::OrderBook). The orders placed by a transaction are
+//! summarized in its [`OrderOutcome`](outcome::OrderOutcome)s and
+//! [`SwapOutcome`](outcome::SwapOutcome)s.
+//!
+//! To manage several user accounts at once, or act on market accounts managed by a custodian, see
+//! the [accounts] module, and to get a user account ready to trade on a market in a single call,
+//! the [onboarding] module. To place orders with decimal sizes and prices rather than lots and
+//! ticks, see [`MarketUnits`](units::MarketUnits). To catch orders that would abort before
+//! spending gas on them, check them with an [`OrderValidator`](validate::OrderValidator), and to
+//! estimate their fill, quote them with a [`QuoteEngine`](quote::QuoteEngine). Balances of
+//! fungible assets, and of coins paired with them, are read as an
+//! [`AssetBalance`](asset::AssetBalance), see the [asset] module.
+//!
+//! Every entry and view function of the Econia package also has a builder in the [generated]
+//! module, generated from the Move sources, for functions the hand-written modules do not cover.
+//!
+//! To query the indexed history of the exchange, such as fills, order history or candlesticks,
+//! from the data service stack, use a [`DssClient`](dss::DssClient). To receive its real-time
+//! notifications over MQTT, use an [`MqttSubscriber`](mqtt::MqttSubscriber).
+//!
+//! To register a market if it does not exist yet, recognize it and get it ready for trading, see
+//! the [registration] module.
+//!
+//! Every request to the Aptos node goes through an [`AptosTransport`](transport::AptosTransport),
+//! which can be replaced, e.g. by a [`MockTransport`](transport::MockTransport) to unit test code
+//! using the SDK without a node. To spread requests over several nodes, failing over between
+//! them, connect with [`EconiaClient::connect_with_nodes`].
 
 use anyhow::anyhow;
 use aptos_api_types::{
-    AptosErrorCode, MoveType, Transaction, TransactionInfo, UserTransactionRequest, VersionedEvent,
-    U64,
+    AptosErrorCode, Event, MoveType, PendingTransaction, Transaction, TransactionInfo,
+    UserTransactionRequest, VersionedEvent, U64,
 };
-use aptos_sdk::crypto::ed25519::Ed25519PrivateKey;
+use aptos_sdk::crypto::ed25519::{Ed25519PrivateKey, Ed25519Signature, ED25519_SIGNATURE_LENGTH};
 use aptos_sdk::crypto::ValidCryptoMaterialStringExt;
 use aptos_sdk::move_types::language_storage::TypeTag;
-use aptos_sdk::rest_client::aptos::Balance;
 use aptos_sdk::rest_client::error::RestError;
 use aptos_sdk::rest_client::{Client, Resource, AptosBaseUrl};
 use aptos_sdk::transaction_builder::TransactionFactory;
 use aptos_sdk::types::account_address::AccountAddress;
 use aptos_sdk::types::chain_id::ChainId;
-use aptos_sdk::types::transaction::EntryFunction;
+use aptos_sdk::types::transaction::{EntryFunction, SignedTransaction, TransactionPayload};
 use aptos_sdk::types::{AccountKey, LocalAccount};
+use batch::{BatchBuilder, BatchTransaction, ScriptCompiler};
 use econia_types::events::EconiaEvent;
 use errors::EconiaError;
+use failover::{FailoverConfig, FailoverTransport, NodeEndpoint};
 use reqwest::Url;
+use retry::{RetryClass, RetryPolicy};
 use serde::Deserialize;
-use std::cmp::max;
+use signer::Signer;
 use std::collections::HashMap;
 use std::default;
 use std::fmt::Debug;
 use std::fs::File;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use transport::AptosTransport;
 use view::EconiaViewClient;
 
+pub mod accounts;
+pub mod asset;
+pub mod batch;
+pub mod book;
+pub mod dss;
 pub mod entry;
 pub mod errors;
+pub mod failover;
+pub mod generated;
+pub mod mqtt;
+pub mod offline;
+pub mod onboarding;
+pub mod outcome;
+pub mod pipeline;
+pub mod quote;
+pub mod registration;
+pub mod retry;
+pub mod signer;
+pub mod sponsor;
+pub mod subscription;
+pub mod transport;
+pub mod units;
+pub mod validate;
 pub mod view;
 
 pub use econia_types as types;
@@ -52,20 +117,28 @@ struct AptosConfig {
 type AptosConfigYaml = HashMap<String, HashMap<String, AptosConfig>>;
 
 impl AptosConfig {
-    pub fn from_config(path: &str, profile_name: &str) -> Self {
-        let file = File::open(path).expect("invalid config path provided");
-        let config =
-            serde_yaml::from_reader::<File, AptosConfigYaml>(file).expect("config file is invalid");
-        let profiles = config
-            .get("profiles")
-            .expect("profiles section missing in config file");
-        profiles
-            .get(profile_name)
-            .expect("given profile name is missing in config file")
-            .clone()
+    pub fn from_config(path: &str, profile_name: &str) -> EconiaResult<Self> {
+        let file = File::open(path)
+            .map_err(|e| EconiaError::InvalidAptosConfig(format!("{}: {}", path, e)))?;
+        let config = serde_yaml::from_reader::<File, AptosConfigYaml>(file)
+            .map_err(|e| EconiaError::InvalidAptosConfig(e.to_string()))?;
+        let profiles = config.get("profiles").ok_or_else(|| {
+            EconiaError::InvalidAptosConfig("profiles section missing in config file".to_string())
+        })?;
+        profiles.get(profile_name).cloned().ok_or_else(|| {
+            EconiaError::InvalidAptosConfig(format!(
+                "profile `{}` is missing in config file",
+                profile_name
+            ))
+        })
     }
 }
 
+#[derive(Deserialize, Debug, Clone)]
+struct CoinInfo {
+    decimals: u8,
+}
+
 pub type EconiaResult<T> = std::result::Result<T, EconiaError>;
 
 #[derive(Debug, Clone)]
@@ -78,21 +151,102 @@ pub struct EconiaTransaction {
     pub events: Vec<EconiaEvent>,
     /// The time the transaction occurred.
     pub timestamp: U64,
+    /// [`OrderOutcome`](outcome::OrderOutcome) of every limit and market order placed by the
+    /// transaction.
+    pub orders: Vec<outcome::OrderOutcome>,
+    /// [`SwapOutcome`](outcome::SwapOutcome) of every swap placed by the transaction.
+    pub swaps: Vec<outcome::SwapOutcome>,
+}
+
+impl EconiaTransaction {
+    /// Turn a committed transaction into an [`EconiaTransaction`].
+    pub(crate) fn from_transaction(
+        econia_address: &AccountAddress,
+        tx: Transaction,
+    ) -> EconiaResult<Self> {
+        let Transaction::UserTransaction(ut) = tx else {
+            return Err(EconiaError::InvalidTransaction);
+        };
+
+        let events = econia_events(econia_address, &ut.events)?;
+
+        Ok(Self {
+            info: ut.info.clone(),
+            request: ut.request.clone(),
+            orders: outcome::order_outcomes(&events),
+            swaps: outcome::swap_outcomes(&events),
+            events,
+            timestamp: ut.timestamp,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EconiaSimulation {
+    /// Aptos VM status of the simulated transaction.
+    pub vm_status: String,
+    /// List of [`EconiaEvent`] the transaction would generate.
+    pub events: Vec<EconiaEvent>,
+    /// Estimated gas units used by the transaction.
+    pub gas_used: u64,
+    /// Estimated gas unit price, in octas.
+    pub gas_unit_price: u64,
+}
+
+impl EconiaSimulation {
+    /// Estimated fee of the transaction, in octas.
+    pub fn estimated_fee(&self) -> u64 {
+        self.gas_used.saturating_mul(self.gas_unit_price)
+    }
 }
 
 #[derive(Debug)]
 pub struct EconiaClientConfig {
-    /// Amount of times to attempt submitting a transaction.
-    pub retry_count: u8,
+    /// How failed transactions are resubmitted, see the [`retry`] module.
+    pub retry_policy: RetryPolicy,
     /// Max gas to use in an Aptos transaction.
     pub max_gas_amount: u64,
+    /// If set, every transaction is simulated before being submitted, and its max gas amount is
+    /// the simulated gas usage scaled by this factor, capped at `max_gas_amount`.
+    ///
+    /// Transactions that would abort are then rejected without being submitted.
+    pub gas_estimation_multiplier: Option<f64>,
+}
+
+impl EconiaClientConfig {
+    /// Simulate every transaction before submitting it, see
+    /// [`EconiaClientConfig::gas_estimation_multiplier`].
+    ///
+    /// # Arguments:
+    ///
+    /// * `multiplier` - Factor the simulated gas usage is scaled by, must be finite and positive.
+    pub fn with_gas_estimation_multiplier(mut self, multiplier: f64) -> EconiaResult<Self> {
+        self.gas_estimation_multiplier = Some(multiplier);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Check the config can be used by a client, including its [`RetryPolicy`].
+    pub fn validate(&self) -> EconiaResult<()> {
+        self.retry_policy.validate()?;
+        if let Some(multiplier) = self.gas_estimation_multiplier {
+            if !multiplier.is_finite() || multiplier <= 0.0 {
+                return Err(EconiaError::InvalidClientConfig(format!(
+                    "gas estimation multiplier must be finite and positive, got: {}",
+                    multiplier
+                )));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl default::Default for EconiaClientConfig {
     fn default() -> Self {
         Self {
-            retry_count: SUBMIT_ATTEMPTS,
+            retry_policy: RetryPolicy::default(),
             max_gas_amount: MAX_GAS_AMOUNT,
+            gas_estimation_multiplier: None,
         }
     }
 }
@@ -101,14 +255,16 @@ impl default::Default for EconiaClientConfig {
 pub struct EconiaClient {
     /// Aptos `AccountAddress` of the account that holds the econia modules.
     pub econia_address: AccountAddress,
-    /// Aptos `Client` used to communicate with the Aptos node.
-    pub aptos_client: Client,
+    /// [`AptosTransport`] used to communicate with the Aptos node.
+    pub transport: Arc<dyn AptosTransport>,
     /// Aptos `ChainId` of the Aptos node.
     pub chain_id: ChainId,
-    /// Aptos `LocalAccount` representing the user account of this client.
-    pub user_account: LocalAccount,
+    /// [`Signer`] of the user account of this client.
+    pub signer: Box<dyn Signer>,
     /// `EconiaClientConfig`
     pub config: EconiaClientConfig,
+    /// Sequence number of the next transaction of the user account.
+    sequence_number: AtomicU64,
 }
 
 impl EconiaClient {
@@ -119,13 +275,13 @@ impl EconiaClient {
     /// * `node_url` - Url of aptos node.
     /// * `node_api_key` - API key to use when connecting to the node.
     /// * `econia_address` - Aptos `AccountAddress`.
-    /// * `account` - `LocalAccount` representing Aptos user account.
+    /// * `signer` - [`Signer`] of the Aptos user account, e.g. a `LocalAccount`.
     /// * `config` - `EconiaClientConfig` to configure the Econia client, if `None` default values will be used.
     pub async fn connect(
         node_url: Url,
         node_api_key: Option<String>,
         econia: AccountAddress,
-        account: LocalAccount,
+        signer: impl Signer + 'static,
         config: Option<EconiaClientConfig>,
     ) -> EconiaResult<Self> {
         let builder = Client::builder(AptosBaseUrl::Custom(node_url));
@@ -134,19 +290,60 @@ impl EconiaClient {
         } else {
             builder
         };
-        let aptos = builder.build();
-        let index = aptos.get_index().await?.into_inner();
+        Self::connect_with_transport(Arc::new(builder.build()), econia, signer, config).await
+    }
+
+    /// Connect to several Aptos nodes and initialize the Econia client, sending each request to
+    /// the healthiest node, see the [`failover`] module.
+    ///
+    /// # Arguments:
+    ///
+    /// * `nodes` - [`NodeEndpoint`]s of the Aptos nodes.
+    /// * `failover_config` - `FailoverConfig` to configure the health checks of the nodes, if
+    ///   `None` default values will be used.
+    /// * `econia_address` - Aptos `AccountAddress`.
+    /// * `signer` - [`Signer`] of the Aptos user account, e.g. a `LocalAccount`.
+    /// * `config` - `EconiaClientConfig` to configure the Econia client, if `None` default values will be used.
+    pub async fn connect_with_nodes(
+        nodes: Vec<NodeEndpoint>,
+        failover_config: Option<FailoverConfig>,
+        econia: AccountAddress,
+        signer: impl Signer + 'static,
+        config: Option<EconiaClientConfig>,
+    ) -> EconiaResult<Self> {
+        let transport = FailoverTransport::connect(nodes, failover_config).await?;
+        Self::connect_with_transport(Arc::new(transport), econia, signer, config).await
+    }
+
+    /// Initialize the Econia client on top of an [`AptosTransport`], e.g. a
+    /// [`MockTransport`](transport::MockTransport) in unit tests.
+    ///
+    /// # Arguments:
+    ///
+    /// * `transport` - [`AptosTransport`] used to communicate with the Aptos node.
+    /// * `econia_address` - Aptos `AccountAddress`.
+    /// * `signer` - [`Signer`] of the Aptos user account, e.g. a `LocalAccount`.
+    /// * `config` - `EconiaClientConfig` to configure the Econia client, if `None` default values will be used.
+    pub async fn connect_with_transport(
+        transport: Arc<dyn AptosTransport>,
+        econia: AccountAddress,
+        signer: impl Signer + 'static,
+        config: Option<EconiaClientConfig>,
+    ) -> EconiaResult<Self> {
+        let config = config.unwrap_or_default();
+        config.validate()?;
+        let index = transport.get_index().await?;
         let chain_id = ChainId::new(index.chain_id);
-        let account_info = aptos.get_account(account.address()).await?.into_inner();
+        let account_info = transport.get_account(signer.address()).await?;
         let seq_num = account_info.sequence_number;
-        account.set_sequence_number(seq_num);
 
         Ok(Self {
             econia_address: econia,
-            aptos_client: aptos,
+            transport,
             chain_id,
-            user_account: account,
-            config: config.unwrap_or_default(),
+            signer: Box::new(signer),
+            config,
+            sequence_number: AtomicU64::new(seq_num),
         })
     }
 
@@ -169,7 +366,7 @@ impl EconiaClient {
         account_private_key: &str,
         config: Option<EconiaClientConfig>,
     ) -> EconiaResult<Self> {
-        let node_url = Url::parse(node_url).expect("node url is not valid");
+        let node_url = Url::parse(node_url).map_err(|e| EconiaError::Custom(anyhow!(e)))?;
         let econia = AccountAddress::from_hex_literal(econia_address)?;
         let account_address = AccountAddress::from_hex_literal(account_address)?;
         let private_key = Ed25519PrivateKey::from_encoded_string(account_private_key)?;
@@ -197,7 +394,7 @@ impl EconiaClient {
         aptos_config_profile_name: &str,
         config: Option<EconiaClientConfig>,
     ) -> EconiaResult<Self> {
-        let aptos_config = AptosConfig::from_config(aptos_config_path, aptos_config_profile_name);
+        let aptos_config = AptosConfig::from_config(aptos_config_path, aptos_config_profile_name)?;
         Self::connect_with_strings(
             node_url,
             node_api_key,
@@ -209,11 +406,115 @@ impl EconiaClient {
         .await
     }
 
+    /// Connect to an Aptos node and initialize the econia client using an encrypted
+    /// [`Keystore`](signer::Keystore) file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `node_url` - Url string of aptos node to connect to.
+    /// * `node_api_key` - API key to use when connecting to the node.
+    /// * `econia_address` - Hex encoded address string of account that holds the econia modules.
+    /// * `keystore_path` - Path to the keystore file.
+    /// * `password` - Password the keystore is encrypted with.
+    /// * `config` - `EconiaClientConfig` to configure the Econia client, if `None` default values will be used.
+    pub async fn connect_with_keystore(
+        node_url: &str,
+        node_api_key: Option<String>,
+        econia_address: &str,
+        keystore_path: &str,
+        password: &str,
+        config: Option<EconiaClientConfig>,
+    ) -> EconiaResult<Self> {
+        let node_url = Url::parse(node_url).map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        let econia = AccountAddress::from_hex_literal(econia_address)?;
+        let account = signer::Keystore::open(keystore_path, password)?;
+        Self::connect(node_url, node_api_key, econia, account, config).await
+    }
+
+    /// Create a client for another user account, sharing the connection of this client.
+    ///
+    /// # Arguments:
+    ///
+    /// * `signer` - [`Signer`] of the Aptos user account.
+    /// * `config` - `EconiaClientConfig` to configure the Econia client, if `None` default values will be used.
+    pub async fn with_signer(
+        &self,
+        signer: impl Signer + 'static,
+        config: Option<EconiaClientConfig>,
+    ) -> EconiaResult<Self> {
+        let config = config.unwrap_or_default();
+        config.validate()?;
+        let account_info = self.transport.get_account(signer.address()).await?;
+
+        Ok(Self {
+            econia_address: self.econia_address,
+            transport: self.transport.clone(),
+            chain_id: self.chain_id,
+            signer: Box::new(signer),
+            config,
+            sequence_number: AtomicU64::new(account_info.sequence_number),
+        })
+    }
+
+    /// Address of the user account of this client.
+    pub fn user_address(&self) -> AccountAddress {
+        self.signer.address()
+    }
+
+    /// `LocalAccount` of the user account of this client, `None` unless its [`Signer`] signs with
+    /// an in memory key, e.g. a `LocalAccount` or a decrypted [`Keystore`](signer::Keystore).
+    ///
+    /// The sequence number of the returned account is not kept up to date, the client tracks it
+    /// instead, see [`EconiaClient::sequence_number`].
+    #[deprecated(note = "use `EconiaClient::user_address` or the `signer` field instead")]
+    pub fn user_account(&self) -> Option<&LocalAccount> {
+        self.signer.local_account()
+    }
+
+    /// Sequence number the next transaction of the user account will be submitted with.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number.load(Ordering::SeqCst)
+    }
+
+    /// Set the sequence number the next transaction of the user account will be submitted with.
+    pub fn set_sequence_number(&self, sequence_number: u64) {
+        self.sequence_number
+            .store(sequence_number, Ordering::SeqCst);
+    }
+
+    /// Hand out the next sequence number, returning it.
+    pub(crate) fn increment_sequence_number(&self) -> u64 {
+        self.sequence_number.fetch_add(1, Ordering::SeqCst)
+    }
+
+    /// Give back a sequence number handed out by `increment_sequence_number` whose transaction
+    /// was not submitted.
+    ///
+    /// It is only rolled back if no sequence number was handed out since. Otherwise concurrent
+    /// submissions already hold the following ones, and the sequence number is resynced from the
+    /// chain instead.
+    pub(crate) async fn release_sequence_number(&self, sequence_number: u64) {
+        if self
+            .sequence_number
+            .compare_exchange(
+                sequence_number + 1,
+                sequence_number,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .is_err()
+        {
+            if let Ok(seq_num) = self.get_sequence_number().await {
+                self.set_sequence_number(seq_num);
+            }
+        }
+    }
+
     /// Update the econia client's aptos chain id.
     /// If the aptos team pushes out a new node deployment, the chain id may change.
     /// In case of a change the internal chain id needs to be updated.
     pub async fn update_chain_id(&mut self) -> EconiaResult<()> {
-        let index = self.aptos_client.get_index().await?.into_inner();
+        let index = self.transport.get_index().await?;
         let chain_id = ChainId::new(index.chain_id);
         self.chain_id = chain_id;
         Ok(())
@@ -222,10 +523,10 @@ impl EconiaClient {
     /// Return the current aptos sequence number for the user account
     /// in this econia client.
     pub async fn get_sequence_number(&self) -> EconiaResult<u64> {
-        self.aptos_client
-            .get_account(self.user_account.address())
+        self.transport
+            .get_account(self.user_address())
             .await
-            .map(|a| a.inner().sequence_number)
+            .map(|a| a.sequence_number)
             .map_err(EconiaError::AptosError)
     }
 
@@ -234,10 +535,9 @@ impl EconiaClient {
         address: AccountAddress,
         resource: &str,
     ) -> EconiaResult<Option<Resource>> {
-        self.aptos_client
+        self.transport
             .get_account_resource(address, resource)
             .await
-            .map(|a| a.into_inner())
             .map_err(EconiaError::AptosError)
     }
 
@@ -248,37 +548,14 @@ impl EconiaClient {
         start: Option<u64>,
         limit: Option<u16>,
     ) -> EconiaResult<Vec<VersionedEvent>> {
-        let url = self
-            .aptos_client
-            .build_path(&format!(
-                "accounts/{}/events/{}",
-                address.to_hex_literal(),
-                creation_number,
-            ))
-            .map_err(EconiaError::AptosError)?;
-
-        let client = reqwest::Client::new();
-        let mut request = client.get(url);
-
-        if let Some(start) = start {
-            request = request.query(&[("start", start)])
-        }
-
-        if let Some(limit) = limit {
-            request = request.query(&[("limit", limit)])
-        }
-
-        let response = request
-            .send()
+        self.transport
+            .get_events_by_creation_number(address, creation_number, start, limit)
             .await
-            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
-        Ok(response
-            .json()
-            .await
-            .map_err(|e| EconiaError::Custom(anyhow!(e)))?)
+            .map_err(EconiaError::AptosError)
     }
 
-    /// Checks if a coin exists on the aptos chain.
+    /// Checks if a coin exists on the aptos chain. See [`EconiaClient::does_asset_exist`] for
+    /// fungible assets.
     ///
     /// # Arguments:
     ///
@@ -294,87 +571,203 @@ impl EconiaClient {
             .map(|r| r.is_some())
     }
 
-    /// Checks if the user is registered for a coin's CoinStore.
+    /// Returns the decimals of a coin, or [`EconiaError::CoinNotFound`] if it is not initialized.
+    ///
+    /// # Arguments:
+    ///
+    /// * `coin` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for the given coin.
+    pub async fn get_coin_decimals(&self, coin: &TypeTag) -> EconiaResult<u8> {
+        let coin_info = format!("0x1::coin::CoinInfo<{}>", coin);
+        let TypeTag::Struct(tag) = coin else {
+            return Err(EconiaError::InvalidTypeTag(coin.clone()));
+        };
+
+        self.fetch_resource(tag.address, &coin_info)
+            .await?
+            .ok_or_else(|| EconiaError::CoinNotFound(coin.clone()))
+            .and_then(|r| {
+                serde_json::from_value::<CoinInfo>(r.data).map_err(EconiaError::JsonError)
+            })
+            .map(|info| info.decimals)
+    }
+
+    /// Checks if the user can hold a coin, in its CoinStore or in the primary fungible store of
+    /// the fungible asset paired with the coin.
+    ///
+    /// An account holding the coin only in the primary store of the paired fungible asset, e.g.
+    /// after receiving it as a fungible asset, is registered even without a CoinStore. Check for
+    /// the `0x1::coin::CoinStore` resource itself where a CoinStore is required.
     ///
     /// # Arguments:
     ///
     /// * `coin` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for the given coin.
     pub async fn is_registered_for_coin(&self, coin: &TypeTag) -> EconiaResult<bool> {
         let coin_store = format!("0x1::coin::CoinStore<{}>", coin);
-        self.fetch_resource(self.user_account.address(), &coin_store)
-            .await
-            .map(|r| r.is_some())
+        if self
+            .fetch_resource(self.user_address(), &coin_store)
+            .await?
+            .is_some()
+        {
+            return Ok(true);
+        }
+        match self.get_paired_metadata(coin).await? {
+            Some(metadata) => Ok(self
+                .get_primary_store_balance(self.user_address(), metadata)
+                .await?
+                .is_some()),
+            None => Ok(false),
+        }
     }
 
-    /// Returns the user's coin balance in their CoinStore.
+    /// Returns the user's coin balance, in their CoinStore and in the primary fungible store of
+    /// the fungible asset paired with the coin. See [`EconiaClient::get_asset_balance`] for both
+    /// parts.
     ///
     /// # Arguments:
     ///
     /// * `coin` - Aptos [`TypeTag`](https://docs.rs/move-core-types/0.0.3/move_core_types/language_storage/enum.TypeTag.html) for the given coin.
     pub async fn get_coin_balance(&self, coin: &TypeTag) -> EconiaResult<U64> {
-        let coin_store = format!("0x1::coin::CoinStore<{}>", coin);
-        self.fetch_resource(self.user_account.address(), &coin_store)
+        let owner = self.user_address();
+        let coin_store = self.coin_store_balance(owner, coin).await?;
+        let primary_store = match self.get_paired_metadata(coin).await? {
+            // A missing primary store has a zero balance, its existence only matters for accounts
+            // without a CoinStore.
+            Some(metadata) if coin_store.is_some() => {
+                Some(self.primary_store_balance(owner, metadata).await?)
+            }
+            Some(metadata) => self.get_primary_store_balance(owner, metadata).await?,
+            None => None,
+        };
+        if coin_store.is_none() && primary_store.is_none() {
+            return Err(EconiaError::AccountNotRegisteredForCoin(coin.clone()));
+        }
+        coin_store
+            .unwrap_or_default()
+            .checked_add(primary_store.unwrap_or_default())
+            .map(U64)
+            .ok_or_else(|| {
+                EconiaError::FungibleAssetError(format!(
+                    "the balance of `{}` does not fit in a u64, see `get_asset_balance`",
+                    coin
+                ))
+            })
+    }
+
+    async fn simulate_tx_internal(
+        &self,
+        payload: &TransactionPayload,
+    ) -> EconiaResult<EconiaSimulation> {
+        let tx = TransactionFactory::new(self.chain_id)
+            .payload(payload.clone())
+            .sender(self.user_address())
+            .sequence_number(self.sequence_number())
+            .max_gas_amount(self.config.max_gas_amount)
+            .build();
+
+        // The simulate endpoint rejects transactions carrying a valid signature.
+        let signature = Ed25519Signature::try_from(&[0u8; ED25519_SIGNATURE_LENGTH][..])?;
+        let signed_tx = SignedTransaction::new(tx, self.signer.public_key(), signature);
+        let ut = self
+            .transport
+            .simulate(&signed_tx)
             .await?
-            .ok_or_else(|| EconiaError::AccountNotRegisteredForCoin(coin.clone()))
-            .and_then(|r| serde_json::from_value::<Balance>(r.data).map_err(EconiaError::JsonError))
-            .map(|b| b.coin.value)
+            .into_iter()
+            .next()
+            .ok_or(EconiaError::InvalidResponse)?;
+
+        if !ut.info.success {
+            return Err(EconiaError::from_vm_status(
+                &self.econia_address,
+                &ut.info.vm_status,
+            ));
+        }
+
+        Ok(EconiaSimulation {
+            events: econia_events(&self.econia_address, &ut.events)?,
+            vm_status: ut.info.vm_status,
+            gas_used: ut.info.gas_used.0,
+            gas_unit_price: ut.request.gas_unit_price.0,
+        })
+    }
+
+    /// Simulate a transaction without submitting it, returning the [`EconiaEvent`]s it would
+    /// generate and its estimated gas usage.
+    ///
+    /// If the transaction would fail, the Move abort is decoded into an [`EconiaError`], e.g. an
+    /// [`EconiaError::MarketError`] for aborts of the Econia `market` module. See
+    /// [`EconiaError::from_vm_status`].
+    ///
+    /// # Arguments:
+    ///
+    /// * `entry` - `EntryFunction` to simulate.
+    pub async fn simulate_tx(&self, entry: EntryFunction) -> EconiaResult<EconiaSimulation> {
+        self.simulate_tx_internal(&TransactionPayload::EntryFunction(entry))
+            .await
     }
 
     async fn submit_tx_internal(
         &self,
-        payload: &EntryFunction,
+        payload: &TransactionPayload,
     ) -> EconiaResult<EconiaTransaction> {
-        let addr = self.user_account.address();
+        let addr = self.user_address();
         let tx = TransactionFactory::new(self.chain_id)
-            .entry_function(payload.clone())
-            .sender(addr)
-            .sequence_number(self.user_account.sequence_number())
-            .max_gas_amount(self.config.max_gas_amount)
-            .build();
+            .payload(payload.clone())
+            .sender(addr);
+        let tx = match self.config.gas_estimation_multiplier {
+            Some(multiplier) => {
+                let simulation = self.simulate_tx_internal(payload).await?;
+                let max_gas_amount = (simulation.gas_used as f64 * multiplier).ceil() as u64;
+                tx.max_gas_amount(max_gas_amount.min(self.config.max_gas_amount))
+                    .gas_unit_price(simulation.gas_unit_price)
+            }
+            None => tx.max_gas_amount(self.config.max_gas_amount),
+        };
 
-        let signed_tx = self.user_account.sign_transaction(tx);
-        let pending = match self.aptos_client.submit(&signed_tx).await {
-            Ok(res) => res.into_inner(),
+        // Hand out the sequence number locally, it is only released if the submission fails.
+        let sequence_number = self.increment_sequence_number();
+        let signed_tx = match self
+            .signer
+            .sign_transaction(tx.sequence_number(sequence_number).build())
+            .await
+        {
+            Ok(signed_tx) => signed_tx,
+            Err(e) => {
+                self.release_sequence_number(sequence_number).await;
+                return Err(e);
+            }
+        };
+        let pending = match self.transport.submit(&signed_tx).await {
+            Ok(pending) => pending,
             Err(RestError::Api(a)) => {
                 return match a.error.error_code {
                     AptosErrorCode::InvalidTransactionUpdate
                     | AptosErrorCode::SequenceNumberTooOld
                     | AptosErrorCode::VmError => {
                         let seq_num = self.get_sequence_number().await?;
-                        let acc_seq_num = self.user_account.sequence_number();
-                        self.user_account
-                            .set_sequence_number(max(seq_num, acc_seq_num + 1));
+                        self.set_sequence_number(seq_num);
+                        Err(EconiaError::AptosError(RestError::Api(a)))
+                    }
+                    _ => {
+                        self.release_sequence_number(sequence_number).await;
                         Err(EconiaError::AptosError(RestError::Api(a)))
                     }
-                    _ => Err(EconiaError::AptosError(RestError::Api(a))),
                 }
             }
-            Err(e) => return Err(EconiaError::AptosError(e)),
+            Err(e) => {
+                self.release_sequence_number(sequence_number).await;
+                return Err(EconiaError::AptosError(e));
+            }
         };
 
-        let tx = self
-            .aptos_client
-            .wait_for_transaction(&pending)
-            .await?
-            .into_inner();
+        let tx =
+            wait_for_transaction(self.transport.as_ref(), &self.econia_address, &pending).await?;
 
-        let Transaction::UserTransaction(ut) = tx else {
-            return Err(EconiaError::InvalidTransaction);
-        };
-
-        let events = ut
-            .events
-            .iter()
-            .filter(|e| matches!(&e.typ, MoveType::Struct(s) if s.address.inner() == &self.econia_address))
-            .map(|e| serde_json::from_value(e.data.clone()))
-            .collect::<Result<Vec<EconiaEvent>, serde_json::Error>>()?;
+        self.econia_transaction(tx)
+    }
 
-        Ok(EconiaTransaction {
-            info: ut.info.clone(),
-            request: ut.request.clone(),
-            events,
-            timestamp: ut.timestamp,
-        })
+    /// Turn a committed transaction into an [`EconiaTransaction`].
+    pub(crate) fn econia_transaction(&self, tx: Transaction) -> EconiaResult<EconiaTransaction> {
+        EconiaTransaction::from_transaction(&self.econia_address, tx)
     }
 
     /// Create and submit a transaction to the blockchain returning the an [`EconiaResult<EconiaTransaction>`].
@@ -383,20 +776,251 @@ impl EconiaClient {
     ///
     /// * `entry` - `EntryFunction` to be submitted as part of the transaction to the blockchain.
     pub async fn submit_tx(&self, entry: EntryFunction) -> EconiaResult<EconiaTransaction> {
-        for i in 0..(self.config.retry_count) {
-            match self.submit_tx_internal(&entry).await {
-                Ok(lt) => return Ok(lt),
-                Err(e) if i == SUBMIT_ATTEMPTS - 1 => return Err(e),
-                _ => continue,
+        self.submit_payload(TransactionPayload::EntryFunction(entry))
+            .await
+    }
+
+    /// Compile a batch of entry functions into a single script and submit it, see the [`batch`]
+    /// module.
+    ///
+    /// Returns the committed transaction along with its [`EconiaEvent`]s split per entry function,
+    /// see [`BatchScript::split_events`](batch::BatchScript::split_events).
+    ///
+    /// # Arguments:
+    ///
+    /// * `batch` - `BatchBuilder` holding the entry functions to call, in order.
+    /// * `compiler` - `ScriptCompiler` to compile the batch script with.
+    pub async fn submit_batch(
+        &self,
+        batch: &BatchBuilder,
+        compiler: &ScriptCompiler,
+    ) -> EconiaResult<BatchTransaction> {
+        let script = batch
+            .build(self.transport.as_ref(), self.econia_address, compiler)
+            .await?;
+        let transaction = self
+            .submit_payload(TransactionPayload::Script(script.script.clone()))
+            .await?;
+        let operations = script.split_events(&transaction.events);
+        Ok(BatchTransaction {
+            transaction,
+            operations,
+        })
+    }
+
+    async fn submit_payload(&self, payload: TransactionPayload) -> EconiaResult<EconiaTransaction> {
+        let policy = &self.config.retry_policy;
+        for attempt in 1..=policy.max_attempts {
+            let error = match self.submit_tx_internal(&payload).await {
+                Ok(lt) => {
+                    policy.on_success(attempt);
+                    return Ok(lt);
+                }
+                Err(e) => e,
+            };
+            let (class, delay) = policy.on_failure(attempt, &error);
+            let Some(delay) = delay else {
+                return Err(error);
+            };
+            if class == RetryClass::Resign {
+                let seq_num = self.get_sequence_number().await?;
+                self.set_sequence_number(seq_num);
             }
+            tokio::time::sleep(delay).await;
         }
         Err(EconiaError::FailedSubmittingTransaction)
     }
 
     pub fn view_client(&self) -> EconiaViewClient {
-        EconiaViewClient::new(&self.aptos_client, self.econia_address.clone())
+        EconiaViewClient::new(self.transport.as_ref(), self.econia_address.clone())
     }
 }
 
+/// Keep the events emitted by the Econia modules and deserialize them.
+fn econia_events(
+    econia_address: &AccountAddress,
+    events: &[Event],
+) -> EconiaResult<Vec<EconiaEvent>> {
+    events
+        .iter()
+        .filter(|e| matches!(&e.typ, MoveType::Struct(s) if s.address.inner() == econia_address))
+        .map(|e| serde_json::from_value(e.data.clone()))
+        .collect::<Result<Vec<EconiaEvent>, serde_json::Error>>()
+        .map_err(EconiaError::JsonError)
+}
+
+/// Wait for a submitted transaction to be committed.
+///
+/// Expired transactions and failed executions are known not to be committed, any other failure
+/// leaves the transaction in flight and fails with an [`EconiaError::TransactionOutcomeUnknown`].
+pub(crate) async fn wait_for_transaction(
+    transport: &dyn AptosTransport,
+    econia_address: &AccountAddress,
+    pending: &PendingTransaction,
+) -> EconiaResult<Transaction> {
+    transport
+        .wait_for_transaction(pending)
+        .await
+        .map_err(|e| match e {
+            RestError::Unknown(ref u)
+                if u.to_string().contains("Transaction expired")
+                    || u.to_string().contains("failed execution") =>
+            {
+                EconiaError::from_rest_error(econia_address, e)
+            }
+            e => EconiaError::TransactionOutcomeUnknown(pending.hash.to_string(), e),
+        })
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use aptos_api_types::AptosError;
+    use aptos_sdk::rest_client::error::AptosErrorResponse;
+
+    pub(crate) const ECONIA: &str = "0xc0deb00c";
+
+    /// Client of a fresh user account on a [`MockTransport`] of chain 4.
+    pub(crate) async fn mock_client() -> (Arc<MockTransport>, EconiaClient) {
+        let transport = Arc::new(MockTransport::new(4));
+        let client = mock_client_with_config(transport.clone(), Default::default()).await;
+        (transport, client)
+    }
+
+    /// Client of the same user as [`mock_client`], with a custom configuration.
+    pub(crate) async fn mock_client_with_config(
+        transport: Arc<MockTransport>,
+        config: EconiaClientConfig,
+    ) -> EconiaClient {
+        let private_key = Ed25519PrivateKey::try_from(&[7u8; 32][..]).unwrap();
+        let account = LocalAccount::new(
+            AccountAddress::from_hex_literal("0xa11ce").unwrap(),
+            AccountKey::from(private_key),
+            0,
+        );
+        EconiaClient::connect_with_transport(
+            transport,
+            AccountAddress::from_hex_literal(ECONIA).unwrap(),
+            account,
+            Some(config),
+        )
+        .await
+        .unwrap()
+    }
+
+    /// Constructors of the events of market 1 between the orders of 0xa11ce and those of 0xb0b,
+    /// without custodians.
+    pub(crate) mod events {
+        use econia_types::events::{
+            CancelOrderEvent, ChangeOrderSizeEvent, EconiaEvent, FillEvent, PlaceLimitOrderEvent,
+        };
+        use econia_types::order::{CancelReason, Restriction, SelfMatchBehavior, Side};
+
+        pub(crate) fn place_limit(
+            order_id: u128,
+            side: Side,
+            size: u64,
+            price: u64,
+            remaining_size: u64,
+        ) -> EconiaEvent {
+            EconiaEvent::PlaceLimitOrder(Box::new(PlaceLimitOrderEvent {
+                market_id: 1,
+                user: "0xa11ce".to_string(),
+                custodian_id: None,
+                integrator: None,
+                side,
+                size,
+                price,
+                restriction: Restriction::NoRestriction,
+                self_match_behavior: SelfMatchBehavior::Abort,
+                remaining_size,
+                order_id,
+            }))
+        }
+
+        /// Fill of an ask of 0xb0b by an order of 0xa11ce, paying a fee of a quote subunit per lot.
+        /// The same event is emitted to the taker and to the maker.
+        pub(crate) fn fill(
+            maker_order_id: u128,
+            taker_order_id: u128,
+            size: u64,
+            price: u64,
+            sequence_number: u64,
+        ) -> EconiaEvent {
+            EconiaEvent::Fill(Box::new(FillEvent {
+                market_id: 1,
+                size,
+                price,
+                maker_side: Side::Ask,
+                maker: "0xb0b".to_string(),
+                maker_custodian_id: None,
+                maker_order_id,
+                taker: "0xa11ce".to_string(),
+                taker_custodian_id: None,
+                taker_order_id,
+                taker_quote_fees_paid: size,
+                sequence_number_for_trade: sequence_number,
+            }))
+        }
+
+        pub(crate) fn cancel(order_id: u128, reason: CancelReason) -> EconiaEvent {
+            EconiaEvent::CancelOrder(Box::new(CancelOrderEvent {
+                market_id: 1,
+                order_id,
+                user: "0xa11ce".to_string(),
+                custodian_id: None,
+                reason,
+            }))
+        }
+
+        pub(crate) fn change_size(order_id: u128, side: Side, new_size: u64) -> EconiaEvent {
+            EconiaEvent::ChangeOrderSize(Box::new(ChangeOrderSizeEvent {
+                market_id: 1,
+                order_id,
+                user: "0xa11ce".to_string(),
+                custodian_id: None,
+                side,
+                new_size,
+            }))
+        }
+    }
+
+    /// Error returned by a node rejecting a request with `status` and `error_code`.
+    pub(crate) fn api_error(status: u16, error_code: AptosErrorCode) -> RestError {
+        RestError::Api(AptosErrorResponse {
+            error: AptosError::new_with_error_code("rejected by the node", error_code),
+            state: None,
+            status_code: reqwest::StatusCode::from_u16(status).unwrap(),
+        })
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn local_user_account() {
+        let (_, client) = mock_client().await;
+        let account = client.user_account().unwrap();
+        assert_eq!(account.address(), client.user_address());
+    }
+
+    #[tokio::test]
+    async fn release_last_sequence_number() {
+        let (_, client) = mock_client().await;
+        assert_eq!(client.increment_sequence_number(), 0);
+        let sequence_number = client.increment_sequence_number();
+        client.release_sequence_number(sequence_number).await;
+        assert_eq!(client.sequence_number(), 1);
+        assert_eq!(client.increment_sequence_number(), 1);
+    }
+
+    #[tokio::test]
+    async fn resync_sequence_number_taken_since() {
+        let (transport, client) = mock_client().await;
+        let released = client.increment_sequence_number();
+        // Taken by a concurrent submission, which must not be handed out again.
+        client.increment_sequence_number();
+        transport.set_sequence_number(client.user_address(), 5);
+        client.release_sequence_number(released).await;
+        assert_eq!(client.sequence_number(), 5);
+    }
+}
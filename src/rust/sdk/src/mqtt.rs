@@ -0,0 +1,701 @@
+//! The [`mqtt`](crate::mqtt) module exposes an [`MqttSubscriber`] which streams the notifications
+//! published by the MQTT server of the Econia data service stack (DSS).
+//!
+//! Topics to subscribe to are built as [`Topic`]s, fields left to `None` matching any value. The
+//! [`Topic::market`], [`Topic::user`] and [`Topic::integrator`] helpers return every topic of a
+//! market, a user or an integrator:
+//!
+//! ```ignore
+//! let topics = Topic::user(user, Some(market_id), None);
+//! let mut notifications = MqttSubscriber::new(MQTT_URL, topics, None)?.into_stream();
+//! while let Some(notification) = notifications.next().await {
+//!     match notification? {
+//!         Notification::Fill(fill) => println!("filled {} lots", fill.size),
+//!         _ => {}
+//!     }
+//! }
+//! ```
+//!
+//! Payloads are decoded into the [`econia_types::notif`] types shared with the publisher. The
+//! connection is reestablished with an exponential backoff when lost, and the topics subscribed to
+//! again once it is, the error being yielded by the stream so that callers can log it or give up.
+//! Notifications published while disconnected are lost.
+
+use std::fmt;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::OsRng;
+use econia_types::notif::{
+    CancelOrderNotif, ChangeOrderSizeNotif, FillNotif, PlaceLimitOrderNotif, PlaceMarketOrderNotif,
+    PlaceSwapOrderNotif, PriceLevelNotif,
+};
+use econia_types::order::Side;
+use futures::Stream;
+use rumqttc::{AsyncClient, Event, EventLoop, MqttOptions, Packet, QoS, SubscribeFilter};
+
+use crate::errors::EconiaError;
+use crate::EconiaResult;
+
+/// Url of the MQTT server of a DSS running locally.
+pub const MQTT_URL: &str = "mqtt://localhost:21883";
+pub const KEEP_ALIVE: Duration = Duration::from_secs(5);
+pub const CHANNEL_CAPACITY: usize = 64;
+pub const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct MqttConfig {
+    /// MQTT client ID, a random one is used if `None`.
+    pub client_id: Option<String>,
+    /// Username and password, the server allows anonymous subscribers by default.
+    pub credentials: Option<(String, String)>,
+    /// Interval at which the connection is checked.
+    pub keep_alive: Duration,
+    /// Amount of requests and notifications buffered by the client.
+    pub channel_capacity: usize,
+    /// Time to wait before reconnecting after a first connection error.
+    pub initial_backoff: Duration,
+    /// Maximum time to wait before reconnecting, the backoff doubling after each error.
+    pub max_backoff: Duration,
+}
+
+impl Default for MqttConfig {
+    fn default() -> Self {
+        Self {
+            client_id: None,
+            credentials: None,
+            keep_alive: KEEP_ALIVE,
+            channel_capacity: CHANNEL_CAPACITY,
+            initial_backoff: INITIAL_BACKOFF,
+            max_backoff: MAX_BACKOFF,
+        }
+    }
+}
+
+/// A topic published to by the DSS, fields left to `None` matching any value.
+///
+/// Addresses are formatted as indexed by the DSS, without leading zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Topic {
+    PlaceLimitOrder {
+        market_id: Option<u64>,
+        user: Option<AccountAddress>,
+        custodian_id: Option<u64>,
+        integrator: Option<AccountAddress>,
+    },
+    PlaceMarketOrder {
+        market_id: Option<u64>,
+        user: Option<AccountAddress>,
+        custodian_id: Option<u64>,
+        integrator: Option<AccountAddress>,
+    },
+    PlaceSwapOrder {
+        market_id: Option<u64>,
+        integrator: Option<AccountAddress>,
+        signing_account: Option<AccountAddress>,
+    },
+    ChangeOrderSize {
+        market_id: Option<u64>,
+        user: Option<AccountAddress>,
+        custodian_id: Option<u64>,
+    },
+    CancelOrder {
+        market_id: Option<u64>,
+        user: Option<AccountAddress>,
+        custodian_id: Option<u64>,
+    },
+    /// Fills are published once for the maker and once for the taker, subscribing to both
+    /// yields them twice.
+    Fill {
+        market_id: Option<u64>,
+        user: Option<AccountAddress>,
+        custodian_id: Option<u64>,
+    },
+    /// Price levels, only published if the DSS is run with `MQTT_PRICE_LEVELS=yes`.
+    Levels {
+        market_id: Option<u64>,
+        side: Option<Side>,
+        /// Level of the price level, starting at 1 for the best price, up to 10.
+        level: Option<u8>,
+    },
+}
+
+impl Topic {
+    /// Every order, swap and fill topic of a market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market_id` - Market ID of the market.
+    pub fn market(market_id: u64) -> Vec<Topic> {
+        let market_id = Some(market_id);
+        vec![
+            Topic::PlaceLimitOrder {
+                market_id,
+                user: None,
+                custodian_id: None,
+                integrator: None,
+            },
+            Topic::PlaceMarketOrder {
+                market_id,
+                user: None,
+                custodian_id: None,
+                integrator: None,
+            },
+            Topic::PlaceSwapOrder {
+                market_id,
+                integrator: None,
+                signing_account: None,
+            },
+            Topic::ChangeOrderSize {
+                market_id,
+                user: None,
+                custodian_id: None,
+            },
+            Topic::CancelOrder {
+                market_id,
+                user: None,
+                custodian_id: None,
+            },
+            Topic::Fill {
+                market_id,
+                user: None,
+                custodian_id: None,
+            },
+        ]
+    }
+
+    /// Every price level of a market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market_id` - Market ID of the market.
+    pub fn levels(market_id: u64) -> Topic {
+        Topic::Levels {
+            market_id: Some(market_id),
+            side: None,
+            level: None,
+        }
+    }
+
+    /// Every order, swap and fill topic of a user, including the swaps it signed.
+    ///
+    /// # Arguments:
+    ///
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID of the market, `None` for every market.
+    /// * `custodian_id` - Custodian ID of the market accounts, `None` for every custodian.
+    pub fn user(
+        user: AccountAddress,
+        market_id: Option<u64>,
+        custodian_id: Option<u64>,
+    ) -> Vec<Topic> {
+        let user = Some(user);
+        vec![
+            Topic::PlaceLimitOrder {
+                market_id,
+                user,
+                custodian_id,
+                integrator: None,
+            },
+            Topic::PlaceMarketOrder {
+                market_id,
+                user,
+                custodian_id,
+                integrator: None,
+            },
+            Topic::PlaceSwapOrder {
+                market_id,
+                integrator: None,
+                signing_account: user,
+            },
+            Topic::ChangeOrderSize {
+                market_id,
+                user,
+                custodian_id,
+            },
+            Topic::CancelOrder {
+                market_id,
+                user,
+                custodian_id,
+            },
+            Topic::Fill {
+                market_id,
+                user,
+                custodian_id,
+            },
+        ]
+    }
+
+    /// Every order and swap topic of an integrator.
+    ///
+    /// # Arguments:
+    ///
+    /// * `integrator` - Aptos `AccountAddress` of the integrator.
+    /// * `market_id` - Market ID of the market, `None` for every market.
+    pub fn integrator(integrator: AccountAddress, market_id: Option<u64>) -> Vec<Topic> {
+        let integrator = Some(integrator);
+        vec![
+            Topic::PlaceLimitOrder {
+                market_id,
+                user: None,
+                custodian_id: None,
+                integrator,
+            },
+            Topic::PlaceMarketOrder {
+                market_id,
+                user: None,
+                custodian_id: None,
+                integrator,
+            },
+            Topic::PlaceSwapOrder {
+                market_id,
+                integrator,
+                signing_account: None,
+            },
+        ]
+    }
+}
+
+fn segment<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map_or("+".to_string(), |v| v.to_string())
+}
+
+fn address_segment(address: &Option<AccountAddress>) -> String {
+    address.map_or("+".to_string(), |a| a.to_hex_literal())
+}
+
+/// Renders the topic filter, e.g. `fill/3/0xc0deface/+`.
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Topic::PlaceLimitOrder {
+                market_id,
+                user,
+                custodian_id,
+                integrator,
+            } => write!(
+                f,
+                "place_limit_order/{}/{}/{}/{}",
+                segment(market_id),
+                address_segment(user),
+                segment(custodian_id),
+                address_segment(integrator)
+            ),
+            Topic::PlaceMarketOrder {
+                market_id,
+                user,
+                custodian_id,
+                integrator,
+            } => write!(
+                f,
+                "place_market_order/{}/{}/{}/{}",
+                segment(market_id),
+                address_segment(user),
+                segment(custodian_id),
+                address_segment(integrator)
+            ),
+            Topic::PlaceSwapOrder {
+                market_id,
+                integrator,
+                signing_account,
+            } => write!(
+                f,
+                "place_swap_order/{}/{}/{}",
+                segment(market_id),
+                address_segment(integrator),
+                address_segment(signing_account)
+            ),
+            Topic::ChangeOrderSize {
+                market_id,
+                user,
+                custodian_id,
+            } => write!(
+                f,
+                "change_order_size/{}/{}/{}",
+                segment(market_id),
+                address_segment(user),
+                segment(custodian_id)
+            ),
+            Topic::CancelOrder {
+                market_id,
+                user,
+                custodian_id,
+            } => write!(
+                f,
+                "cancel_order/{}/{}/{}",
+                segment(market_id),
+                address_segment(user),
+                segment(custodian_id)
+            ),
+            Topic::Fill {
+                market_id,
+                user,
+                custodian_id,
+            } => write!(
+                f,
+                "fill/{}/{}/{}",
+                segment(market_id),
+                address_segment(user),
+                segment(custodian_id)
+            ),
+            Topic::Levels {
+                market_id,
+                side,
+                level,
+            } => {
+                let side = side.map_or("+", |s| match s {
+                    Side::Bid => "bid",
+                    Side::Ask => "ask",
+                });
+                write!(
+                    f,
+                    "levels/{}/{}/{}",
+                    segment(market_id),
+                    side,
+                    segment(level)
+                )
+            }
+        }
+    }
+}
+
+/// A price level, as published on the `levels` topics.
+#[derive(Debug, Clone)]
+pub struct PriceLevel {
+    pub market_id: u64,
+    pub side: Side,
+    /// Level of the price level, starting at 1 for the best price.
+    pub level: u8,
+    /// Price and total size of the level.
+    pub notif: PriceLevelNotif,
+}
+
+/// A notification received from the DSS.
+#[derive(Debug, Clone)]
+pub enum Notification {
+    PlaceLimitOrder(Box<PlaceLimitOrderNotif>),
+    PlaceMarketOrder(Box<PlaceMarketOrderNotif>),
+    PlaceSwapOrder(Box<PlaceSwapOrderNotif>),
+    ChangeOrderSize(Box<ChangeOrderSizeNotif>),
+    CancelOrder(Box<CancelOrderNotif>),
+    Fill(Box<FillNotif>),
+    PriceLevel(Box<PriceLevel>),
+}
+
+impl Notification {
+    /// Decode a notification from the topic it was published on and its payload.
+    ///
+    /// # Arguments:
+    ///
+    /// * `topic` - Topic the notification was published on.
+    /// * `payload` - JSON payload of the notification.
+    pub fn decode(topic: &str, payload: &[u8]) -> EconiaResult<Self> {
+        let unknown_topic = || EconiaError::Custom(anyhow!("unknown mqtt topic: `{}`", topic));
+        let segments = topic.split('/').collect::<Vec<_>>();
+        Ok(match segments[0] {
+            "place_limit_order" => Self::PlaceLimitOrder(serde_json::from_slice(payload)?),
+            "place_market_order" => Self::PlaceMarketOrder(serde_json::from_slice(payload)?),
+            "place_swap_order" => Self::PlaceSwapOrder(serde_json::from_slice(payload)?),
+            "change_order_size" => Self::ChangeOrderSize(serde_json::from_slice(payload)?),
+            "cancel_order" => Self::CancelOrder(serde_json::from_slice(payload)?),
+            "fill" => Self::Fill(serde_json::from_slice(payload)?),
+            "levels" if segments.len() == 4 => Self::PriceLevel(Box::new(PriceLevel {
+                market_id: segments[1].parse().map_err(|_| unknown_topic())?,
+                side: match segments[2] {
+                    "bid" => Side::Bid,
+                    "ask" => Side::Ask,
+                    _ => return Err(unknown_topic()),
+                },
+                level: segments[3].parse().map_err(|_| unknown_topic())?,
+                notif: serde_json::from_slice(payload)?,
+            })),
+            _ => return Err(unknown_topic()),
+        })
+    }
+}
+
+/// Subscribes to topics of the DSS MQTT server, see the [module documentation](crate::mqtt).
+pub struct MqttSubscriber {
+    client: AsyncClient,
+    event_loop: EventLoop,
+    topics: Vec<Topic>,
+    config: MqttConfig,
+}
+
+impl MqttSubscriber {
+    /// Create a subscriber to the given topics. It connects once streamed.
+    ///
+    /// # Arguments:
+    ///
+    /// * `url` - Url of the MQTT server, e.g. [`MQTT_URL`].
+    /// * `topics` - [`Topic`]s to subscribe to.
+    /// * `config` - `MqttConfig` to configure the subscriber, if `None` default values will be used.
+    pub fn new(url: &str, topics: Vec<Topic>, config: Option<MqttConfig>) -> EconiaResult<Self> {
+        let config = config.unwrap_or_default();
+        let client_id = config
+            .client_id
+            .clone()
+            .unwrap_or_else(|| format!("econia-sdk-{:016x}", OsRng.next_u64()));
+        let mut options = MqttOptions::parse_url(format!("{}?client_id={}", url, client_id))
+            .map_err(|e| EconiaError::MqttError(e.to_string()))?;
+        options.set_keep_alive(config.keep_alive);
+        if let Some((username, password)) = &config.credentials {
+            options.set_credentials(username, password);
+        }
+        let (client, event_loop) = AsyncClient::new(options, config.channel_capacity);
+        Ok(Self {
+            client,
+            event_loop,
+            topics,
+            config,
+        })
+    }
+
+    /// Topics of the subscriber.
+    pub fn topics(&self) -> &[Topic] {
+        &self.topics
+    }
+
+    /// Wait for the next notification, connecting and subscribing first if needed.
+    ///
+    /// After an error, the next call reconnects.
+    pub async fn next(&mut self) -> EconiaResult<Notification> {
+        loop {
+            let event = self
+                .event_loop
+                .poll()
+                .await
+                .map_err(|e| EconiaError::MqttError(e.to_string()))?;
+            match event {
+                // Subscriptions do not survive reconnections with a clean session, subscribe on
+                // every connection.
+                Event::Incoming(Packet::ConnAck(_)) if !self.topics.is_empty() => {
+                    let filters = self
+                        .topics
+                        .iter()
+                        .map(|t| SubscribeFilter::new(t.to_string(), QoS::AtLeastOnce));
+                    // The request is sent by the event loop, waiting for it here would deadlock
+                    // if the channel was full.
+                    self.client
+                        .try_subscribe_many(filters)
+                        .map_err(|e| EconiaError::MqttError(e.to_string()))?;
+                }
+                Event::Incoming(Packet::Publish(publish)) => {
+                    return Notification::decode(&publish.topic, &publish.payload);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Turn the subscriber into a never ending `Stream` of notifications.
+    ///
+    /// Errors are yielded as they happen, reconnecting being delayed by the backoff. Notifications
+    /// which cannot be decoded are yielded as errors without delaying the stream.
+    ///
+    /// Must be polled from within a tokio runtime.
+    pub fn into_stream(self) -> impl Stream<Item = EconiaResult<Notification>> {
+        futures::stream::unfold((self, None), |(mut subscriber, backoff)| async move {
+            if let Some(delay) = backoff {
+                tokio::time::sleep(delay).await;
+            }
+            match subscriber.next().await {
+                Ok(notification) => Some((Ok(notification), (subscriber, None))),
+                Err(e @ EconiaError::MqttError(_)) => {
+                    let delay = backoff.map_or(subscriber.config.initial_backoff, |d| {
+                        (d * 2).min(subscriber.config.max_backoff)
+                    });
+                    Some((Err(e), (subscriber, Some(delay))))
+                }
+                Err(e) => Some((Err(e), (subscriber, backoff))),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: &str = "0xc0deface";
+    const INTEGRATOR: &str = "0xbeef";
+
+    fn address(address: &str) -> AccountAddress {
+        AccountAddress::from_hex_literal(address).unwrap()
+    }
+
+    const FILL: &str = r#"{"txn_version":12345,"event_idx":3,"emit_address":"0xc0deface","time":"2023-11-08T16:29:55.123456Z","maker_address":"0xc0deface","maker_custodian_id":1,"maker_order_id":340282366920938463444927863358058659840,"maker_side":false,"market_id":3,"price":1000,"sequence_number_for_trade":0,"size":50,"taker_address":"0xbeef","taker_custodian_id":0,"taker_order_id":18446744073709551617,"taker_quote_fees_paid":25}"#;
+
+    #[test]
+    fn render_publisher_topics() {
+        // Topics as formatted by the `mqtt-publisher` from the rows of the DSS.
+        let (market_id, custodian_id) = (3u128, 1u128);
+        let topics = [
+            (
+                Topic::PlaceLimitOrder {
+                    market_id: Some(3),
+                    user: Some(address(USER)),
+                    custodian_id: Some(1),
+                    integrator: Some(address(INTEGRATOR)),
+                },
+                format!(
+                    "place_limit_order/{}/{}/{}/{}",
+                    market_id, USER, custodian_id, INTEGRATOR
+                ),
+            ),
+            (
+                Topic::PlaceMarketOrder {
+                    market_id: Some(3),
+                    user: Some(address(USER)),
+                    custodian_id: Some(1),
+                    integrator: Some(address(INTEGRATOR)),
+                },
+                format!(
+                    "place_market_order/{}/{}/{}/{}",
+                    market_id, USER, custodian_id, INTEGRATOR
+                ),
+            ),
+            (
+                Topic::PlaceSwapOrder {
+                    market_id: Some(3),
+                    integrator: Some(address(INTEGRATOR)),
+                    signing_account: Some(address(USER)),
+                },
+                format!("place_swap_order/{}/{}/{}", market_id, INTEGRATOR, USER),
+            ),
+            (
+                Topic::ChangeOrderSize {
+                    market_id: Some(3),
+                    user: Some(address(USER)),
+                    custodian_id: Some(1),
+                },
+                format!("change_order_size/{}/{}/{}", market_id, USER, custodian_id),
+            ),
+            (
+                Topic::CancelOrder {
+                    market_id: Some(3),
+                    user: Some(address(USER)),
+                    custodian_id: Some(1),
+                },
+                format!("cancel_order/{}/{}/{}", market_id, USER, custodian_id),
+            ),
+            (
+                Topic::Fill {
+                    market_id: Some(3),
+                    user: Some(address(USER)),
+                    custodian_id: Some(1),
+                },
+                format!("fill/{}/{}/{}", market_id, USER, custodian_id),
+            ),
+            (
+                Topic::Levels {
+                    market_id: Some(3),
+                    side: Some(Side::Ask),
+                    level: Some(1),
+                },
+                format!("levels/{}/{}/{}", market_id, "ask", 1),
+            ),
+        ];
+        for (topic, published) in topics {
+            assert_eq!(topic.to_string(), published);
+        }
+    }
+
+    #[test]
+    fn render_wildcards() {
+        let topics = Topic::user(address(USER), None, Some(0))
+            .iter()
+            .map(|t| t.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            topics,
+            [
+                "place_limit_order/+/0xc0deface/0/+",
+                "place_market_order/+/0xc0deface/0/+",
+                "place_swap_order/+/+/0xc0deface",
+                "change_order_size/+/0xc0deface/0",
+                "cancel_order/+/0xc0deface/0",
+                "fill/+/0xc0deface/0",
+            ]
+        );
+        assert_eq!(Topic::levels(3).to_string(), "levels/3/+/+");
+        let topics = Topic::integrator(address(INTEGRATOR), Some(3));
+        assert_eq!(topics[2].to_string(), "place_swap_order/3/0xbeef/+");
+    }
+
+    #[test]
+    fn decode_fill() {
+        let notification = Notification::decode("fill/3/0xc0deface/1", FILL.as_bytes()).unwrap();
+        let Notification::Fill(fill) = notification else {
+            panic!("not a fill: {:?}", notification);
+        };
+        assert_eq!(fill.maker_side, Side::Bid);
+        assert_eq!(fill.maker_order_id, 340282366920938463444927863358058659840);
+        assert_eq!(fill.taker_order_id, 18446744073709551617);
+        assert_eq!(fill.time.timestamp_micros(), 1_699_460_995_123_456);
+        // Sides are published as booleans, an ask being `true`.
+        assert_eq!(serde_json::to_string(&fill).unwrap(), FILL);
+    }
+
+    #[test]
+    fn decode_orders() {
+        let payload = r#"{"txn_version":1,"event_idx":0,"time":"2023-11-08T16:29:55Z","market_id":3,"user":"0xc0deface","custodian_id":0,"order_id":42,"side":true,"integrator":"0xbeef","initial_size":10,"price":990,"restriction":0,"self_match_behavior":0,"size":10}"#;
+        let notification = Notification::decode(
+            "place_limit_order/3/0xc0deface/0/0xbeef",
+            payload.as_bytes(),
+        )
+        .unwrap();
+        let Notification::PlaceLimitOrder(order) = notification else {
+            panic!("not a limit order: {:?}", notification);
+        };
+        assert_eq!(order.side, Side::Ask);
+        assert_eq!(order.price, 990);
+        assert_eq!(serde_json::to_string(&order).unwrap(), payload);
+
+        let payload = r#"{"txn_version":2,"event_idx":1,"time":"2023-11-08T16:29:56Z","market_id":3,"order_id":43,"direction":false,"signing_account":"0xc0deface","integrator":"0xbeef","min_base":0,"max_base":300,"min_quote":0,"max_quote":18446744073709551615,"limit_price":1000}"#;
+        let notification =
+            Notification::decode("place_swap_order/3/0xbeef/0xc0deface", payload.as_bytes())
+                .unwrap();
+        let Notification::PlaceSwapOrder(swap) = notification else {
+            panic!("not a swap: {:?}", notification);
+        };
+        assert_eq!(swap.direction, Side::Bid);
+        assert_eq!(swap.max_quote, u64::MAX as u128);
+
+        let payload = r#"{"txn_version":3,"event_idx":0,"time":"2023-11-08T16:29:57Z","market_id":3,"user":"0xc0deface","custodian_id":0,"order_id":42,"reason":3}"#;
+        let notification =
+            Notification::decode("cancel_order/3/0xc0deface/0", payload.as_bytes()).unwrap();
+        let Notification::CancelOrder(cancel) = notification else {
+            panic!("not a cancel: {:?}", notification);
+        };
+        assert_eq!(cancel.reason, 3);
+    }
+
+    #[test]
+    fn decode_levels() {
+        let payload = r#"{"price":1000,"size":250,"txn_version":12345}"#;
+        let notification = Notification::decode("levels/3/bid/2", payload.as_bytes()).unwrap();
+        let Notification::PriceLevel(level) = notification else {
+            panic!("not a price level: {:?}", notification);
+        };
+        assert_eq!(
+            (level.market_id, level.side, level.level),
+            (3, Side::Bid, 2)
+        );
+        assert_eq!((level.notif.price, level.notif.size), (1000, 250));
+        assert_eq!(serde_json::to_string(&level.notif).unwrap(), payload);
+    }
+
+    #[test]
+    fn reject_invalid_notifications() {
+        let payload = r#"{"price":1000,"size":250,"txn_version":12345}"#.as_bytes();
+        assert!(Notification::decode("levels/3/buy/2", payload).is_err());
+        assert!(Notification::decode("levels/3/bid", payload).is_err());
+        assert!(Notification::decode("orders/3", payload).is_err());
+        assert!(Notification::decode("fill/3/0xc0deface/1", payload).is_err());
+        assert!(Notification::decode("fill/3/0xc0deface/1", b"not json").is_err());
+    }
+}
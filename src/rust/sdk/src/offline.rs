@@ -0,0 +1,630 @@
+//! The [`offline`](crate::offline) module splits the submission of a transaction into steps
+//! which can run on different machines, so that the private key of the account never has to
+//! leave an air-gapped machine:
+//!
+//! 1. An online machine, without the private key, prepares an [`OfflineTransaction`] with
+//!    [`prepare`], fetching the chain ID, sequence number and gas price from a node, and exports
+//!    it to a file with [`OfflineTransaction::save`].
+//! 2. The air-gapped machine loads the file, reviews it, and signs it with any
+//!    [`Signer`](crate::signer::Signer), e.g. a [`Keystore`](crate::signer::Keystore), into a
+//!    [`SignedOfflineTransaction`] saved to another file.
+//! 3. The online machine loads the signed transaction and submits it with [`submit`].
+//!
+//! Files are JSON, the transaction being BCS encoded and hex encoded. The fields displayed for
+//! review, including the arguments of the function called, are checked against the encoded
+//! transaction when it is decoded, so that they cannot differ from what is signed. Arguments of
+//! the Econia entry functions are decoded, others are displayed hex encoded, and transactions
+//! with a payload whose arguments cannot be displayed are refused.
+//!
+//! A transaction must be committed before it expires, [`DEFAULT_EXPIRATION`] after being
+//! prepared unless configured otherwise. Transactions which expire before being submitted can be
+//! renewed with [`OfflineTransaction::renew`], and must then be signed again. As the sequence
+//! number is fixed when preparing, transactions of a same account must be submitted in order,
+//! and a transaction is invalidated by any other transaction of the account committed first.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aptos_sdk::bcs;
+use aptos_sdk::transaction_builder::TransactionFactory;
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::chain_id::ChainId;
+use aptos_sdk::types::transaction::{RawTransaction, SignedTransaction, TransactionPayload};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::EconiaError;
+use crate::generated::{entry_signature, MoveArgType};
+use crate::signer::{decode_hex, Signer};
+use crate::transport::AptosTransport;
+use crate::{wait_for_transaction, EconiaResult, EconiaTransaction, MAX_GAS_AMOUNT};
+
+pub const OFFLINE_TRANSACTION_VERSION: u8 = 1;
+pub const DEFAULT_EXPIRATION: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone)]
+pub struct PrepareOptions {
+    /// Sequence number of the transaction, fetched from the chain if `None`. Set it to prepare
+    /// several transactions of a same account at once.
+    pub sequence_number: Option<u64>,
+    /// Max gas to use in the transaction.
+    pub max_gas_amount: u64,
+    /// Gas unit price of the transaction, in octas, estimated by the node if `None`.
+    pub gas_unit_price: Option<u64>,
+    /// Time from the preparation, as measured by the chain, after which the transaction expires.
+    pub expiration: Duration,
+}
+
+impl Default for PrepareOptions {
+    fn default() -> Self {
+        Self {
+            sequence_number: None,
+            max_gas_amount: MAX_GAS_AMOUNT,
+            gas_unit_price: None,
+            expiration: DEFAULT_EXPIRATION,
+        }
+    }
+}
+
+/// An unsigned transaction, as exported for signing.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OfflineTransaction {
+    pub version: u8,
+    /// Free form description of the transaction, for review.
+    pub description: String,
+    /// Function called by the transaction, with its type arguments.
+    pub function: String,
+    /// Arguments of the function, as `name: value` for the Econia entry functions and hex
+    /// encoded BCS otherwise.
+    pub arguments: Vec<String>,
+    pub chain_id: u8,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    pub max_gas_amount: u64,
+    pub gas_unit_price: u64,
+    /// Unix timestamp in seconds after which the transaction expires.
+    pub expiration_timestamp_secs: u64,
+    /// Hex encoded BCS serialized `RawTransaction`.
+    pub raw_transaction: String,
+}
+
+impl OfflineTransaction {
+    /// Wrap a raw transaction for export.
+    ///
+    /// # Arguments:
+    ///
+    /// * `raw_transaction` - `RawTransaction` to sign offline.
+    /// * `description` - Free form description of the transaction, for review.
+    pub fn new(raw_transaction: &RawTransaction, description: &str) -> EconiaResult<Self> {
+        Ok(Self {
+            version: OFFLINE_TRANSACTION_VERSION,
+            description: description.to_string(),
+            function: describe_payload(raw_transaction.payload()),
+            arguments: describe_arguments(raw_transaction.payload())?,
+            chain_id: raw_transaction.chain_id().id(),
+            sender: raw_transaction.sender(),
+            sequence_number: raw_transaction.sequence_number(),
+            max_gas_amount: raw_transaction.max_gas_amount(),
+            gas_unit_price: raw_transaction.gas_unit_price(),
+            expiration_timestamp_secs: raw_transaction.expiration_timestamp_secs(),
+            raw_transaction: hex::encode(bcs::to_bytes(raw_transaction)?),
+        })
+    }
+
+    /// Decode the raw transaction, checking that it matches the fields displayed for review.
+    pub fn raw_transaction(&self) -> EconiaResult<RawTransaction> {
+        if self.version != OFFLINE_TRANSACTION_VERSION {
+            return Err(EconiaError::OfflineTransactionError(format!(
+                "unsupported version: {}",
+                self.version
+            )));
+        }
+        let bytes =
+            decode_hex(&self.raw_transaction).map_err(EconiaError::OfflineTransactionError)?;
+        let raw_transaction: RawTransaction = bcs::from_bytes(&bytes)?;
+        if Self::new(&raw_transaction, &self.description)?.fields() != self.fields() {
+            return Err(EconiaError::OfflineTransactionError(
+                "the transaction does not match its displayed fields".to_string(),
+            ));
+        }
+        Ok(raw_transaction)
+    }
+
+    /// Time left before the transaction expires according to the local clock, `None` if it has
+    /// expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        expires_in(self.expiration_timestamp_secs)
+    }
+
+    /// Sign the transaction.
+    ///
+    /// # Arguments:
+    ///
+    /// * `signer` - [`Signer`] of the sender of the transaction.
+    pub async fn sign(&self, signer: &dyn Signer) -> EconiaResult<SignedOfflineTransaction> {
+        let raw_transaction = self.raw_transaction()?;
+        if signer.address() != self.sender {
+            return Err(EconiaError::OfflineTransactionError(format!(
+                "the transaction is sent by: `{}`, not by the signer: `{}`",
+                self.sender,
+                signer.address()
+            )));
+        }
+        if self.expires_in().is_none() {
+            return Err(EconiaError::OfflineTransactionError(
+                "the transaction has expired".to_string(),
+            ));
+        }
+        let signed_transaction = signer.sign_transaction(raw_transaction).await?;
+        SignedOfflineTransaction::new(&signed_transaction, &self.description)
+    }
+
+    /// Prepare the same transaction again with a new expiration, e.g. once it has expired. It
+    /// keeps its sequence number, payload and gas parameters, and must be signed again.
+    ///
+    /// # Arguments:
+    ///
+    /// * `transport` - [`AptosTransport`] to fetch the time of the chain with.
+    /// * `expiration` - Time from now, as measured by the chain, after which it expires.
+    pub async fn renew(
+        &self,
+        transport: &dyn AptosTransport,
+        expiration: Duration,
+    ) -> EconiaResult<Self> {
+        let raw_transaction = self.raw_transaction()?;
+        let index = transport.get_index().await?;
+        let raw_transaction = RawTransaction::new(
+            raw_transaction.sender(),
+            raw_transaction.sequence_number(),
+            raw_transaction.payload().clone(),
+            raw_transaction.max_gas_amount(),
+            raw_transaction.gas_unit_price(),
+            index.ledger_timestamp.0 / 1_000_000 + expiration.as_secs(),
+            raw_transaction.chain_id(),
+        );
+        Self::new(&raw_transaction, &self.description)
+    }
+
+    /// Read a transaction from a file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the transaction file.
+    pub fn load(path: impl AsRef<Path>) -> EconiaResult<Self> {
+        load(path)
+    }
+
+    /// Write the transaction to a file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the transaction file.
+    pub fn save(&self, path: impl AsRef<Path>) -> EconiaResult<()> {
+        save(self, path)
+    }
+
+    fn fields(&self) -> (&str, &[String], u8, AccountAddress, u64, u64, u64, u64) {
+        (
+            &self.function,
+            &self.arguments,
+            self.chain_id,
+            self.sender,
+            self.sequence_number,
+            self.max_gas_amount,
+            self.gas_unit_price,
+            self.expiration_timestamp_secs,
+        )
+    }
+}
+
+/// A signed transaction, as exported for submission.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SignedOfflineTransaction {
+    pub version: u8,
+    /// Free form description of the transaction, for review.
+    pub description: String,
+    /// Hash the transaction will be committed with.
+    pub hash: String,
+    pub sender: AccountAddress,
+    pub sequence_number: u64,
+    /// Unix timestamp in seconds after which the transaction expires.
+    pub expiration_timestamp_secs: u64,
+    /// Hex encoded BCS serialized `SignedTransaction`.
+    pub signed_transaction: String,
+}
+
+impl SignedOfflineTransaction {
+    /// Wrap a signed transaction for export.
+    ///
+    /// # Arguments:
+    ///
+    /// * `signed_transaction` - `SignedTransaction` to submit.
+    /// * `description` - Free form description of the transaction, for review.
+    pub fn new(signed_transaction: &SignedTransaction, description: &str) -> EconiaResult<Self> {
+        Ok(Self {
+            version: OFFLINE_TRANSACTION_VERSION,
+            description: description.to_string(),
+            hash: signed_transaction.clone().committed_hash().to_hex_literal(),
+            sender: signed_transaction.sender(),
+            sequence_number: signed_transaction.sequence_number(),
+            expiration_timestamp_secs: signed_transaction.expiration_timestamp_secs(),
+            signed_transaction: hex::encode(bcs::to_bytes(signed_transaction)?),
+        })
+    }
+
+    /// Decode the signed transaction, checking its signature and that it matches the fields
+    /// displayed for review.
+    pub fn signed_transaction(&self) -> EconiaResult<SignedTransaction> {
+        if self.version != OFFLINE_TRANSACTION_VERSION {
+            return Err(EconiaError::OfflineTransactionError(format!(
+                "unsupported version: {}",
+                self.version
+            )));
+        }
+        let bytes =
+            decode_hex(&self.signed_transaction).map_err(EconiaError::OfflineTransactionError)?;
+        let signed_transaction: SignedTransaction = bcs::from_bytes(&bytes)?;
+        signed_transaction
+            .verify_signature()
+            .map_err(|e| EconiaError::OfflineTransactionError(e.to_string()))?;
+        let decoded = Self::new(&signed_transaction, &self.description)?;
+        if (
+            &decoded.hash,
+            decoded.sender,
+            decoded.sequence_number,
+            decoded.expiration_timestamp_secs,
+        ) != (
+            &self.hash,
+            self.sender,
+            self.sequence_number,
+            self.expiration_timestamp_secs,
+        ) {
+            return Err(EconiaError::OfflineTransactionError(
+                "the transaction does not match its displayed fields".to_string(),
+            ));
+        }
+        Ok(signed_transaction)
+    }
+
+    /// Time left before the transaction expires according to the local clock, `None` if it has
+    /// expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        expires_in(self.expiration_timestamp_secs)
+    }
+
+    /// Read a signed transaction from a file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the signed transaction file.
+    pub fn load(path: impl AsRef<Path>) -> EconiaResult<Self> {
+        load(path)
+    }
+
+    /// Write the signed transaction to a file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the signed transaction file.
+    pub fn save(&self, path: impl AsRef<Path>) -> EconiaResult<()> {
+        save(self, path)
+    }
+}
+
+/// Prepare a transaction to sign offline.
+///
+/// # Arguments:
+///
+/// * `transport` - [`AptosTransport`] to fetch the chain ID, sequence number and gas price with.
+/// * `sender` - Aptos `AccountAddress` of the account sending the transaction.
+/// * `payload` - `TransactionPayload` of the transaction, e.g. an entry function from the
+///   [`entry`](crate::entry) module.
+/// * `description` - Free form description of the transaction, for review.
+/// * `options` - `PrepareOptions` to configure the transaction, if `None` default values will be used.
+pub async fn prepare(
+    transport: &dyn AptosTransport,
+    sender: AccountAddress,
+    payload: TransactionPayload,
+    description: &str,
+    options: Option<PrepareOptions>,
+) -> EconiaResult<OfflineTransaction> {
+    let options = options.unwrap_or_default();
+    let index = transport.get_index().await?;
+    let sequence_number = match options.sequence_number {
+        Some(sequence_number) => sequence_number,
+        None => transport.get_account(sender).await?.sequence_number,
+    };
+    let gas_unit_price = match options.gas_unit_price {
+        Some(gas_unit_price) => gas_unit_price,
+        None => transport.estimate_gas_price().await?,
+    };
+    // Expire relative to the time of the chain rather than to the local clock.
+    let expiration_timestamp_secs =
+        index.ledger_timestamp.0 / 1_000_000 + options.expiration.as_secs();
+    let raw_transaction = TransactionFactory::new(ChainId::new(index.chain_id))
+        .payload(payload)
+        .sender(sender)
+        .sequence_number(sequence_number)
+        .max_gas_amount(options.max_gas_amount)
+        .gas_unit_price(gas_unit_price)
+        .expiration_timestamp_secs(expiration_timestamp_secs)
+        .build();
+    OfflineTransaction::new(&raw_transaction, description)
+}
+
+/// Submit a transaction signed offline and wait for it to be committed.
+///
+/// Transactions are not retried, as their sequence number and expiration cannot change without
+/// signing them again.
+///
+/// # Arguments:
+///
+/// * `transport` - [`AptosTransport`] to submit the transaction with.
+/// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules, to
+///   decode the events of the transaction.
+/// * `transaction` - [`SignedOfflineTransaction`] to submit.
+pub async fn submit(
+    transport: &dyn AptosTransport,
+    econia_address: AccountAddress,
+    transaction: &SignedOfflineTransaction,
+) -> EconiaResult<EconiaTransaction> {
+    let signed_transaction = transaction.signed_transaction()?;
+    let pending = transport
+        .submit(&signed_transaction)
+        .await
+        .map_err(|e| EconiaError::from_rest_error(&econia_address, e))?;
+    let tx = wait_for_transaction(transport, &econia_address, &pending).await?;
+    EconiaTransaction::from_transaction(&econia_address, tx)
+}
+
+fn describe_payload(payload: &TransactionPayload) -> String {
+    match payload {
+        TransactionPayload::EntryFunction(entry) => {
+            let type_args = entry
+                .ty_args()
+                .iter()
+                .map(|t| t.to_string())
+                .collect::<Vec<_>>();
+            let type_args = match type_args.is_empty() {
+                true => String::new(),
+                false => format!("<{}>", type_args.join(", ")),
+            };
+            format!(
+                "{}::{}::{}{}",
+                entry.module().address().to_hex_literal(),
+                entry.module().name(),
+                entry.function(),
+                type_args
+            )
+        }
+        TransactionPayload::Script(_) => "script".to_string(),
+        _ => "unsupported payload".to_string(),
+    }
+}
+
+/// Arguments of a payload, decoded for the Econia entry functions, see
+/// [`OfflineTransaction::arguments`].
+fn describe_arguments(payload: &TransactionPayload) -> EconiaResult<Vec<String>> {
+    match payload {
+        TransactionPayload::EntryFunction(entry) => {
+            let signature =
+                entry_signature(entry.module().name().as_str(), entry.function().as_str())
+                    .filter(|s| s.params.len() == entry.args().len());
+            Ok(entry
+                .args()
+                .iter()
+                .enumerate()
+                .map(|(i, arg)| {
+                    signature
+                        .and_then(|s| {
+                            let (name, ty) = s.params[i];
+                            decode_argument(ty, arg).map(|value| format!("{}: {}", name, value))
+                        })
+                        .unwrap_or_else(|| format!("{}: 0x{}", i, hex::encode(arg)))
+                })
+                .collect())
+        }
+        TransactionPayload::Script(script) => {
+            Ok(script.args().iter().map(|a| format!("{:?}", a)).collect())
+        }
+        _ => Err(EconiaError::OfflineTransactionError(
+            "the arguments of the payload cannot be displayed".to_string(),
+        )),
+    }
+}
+
+/// Decode a BCS encoded argument, `None` if it is not a value of the type.
+fn decode_argument(ty: MoveArgType, mut bytes: &[u8]) -> Option<String> {
+    let value = decode_value(ty, &mut bytes)?;
+    bytes.is_empty().then_some(value)
+}
+
+fn decode_value(ty: MoveArgType, bytes: &mut &[u8]) -> Option<String> {
+    Some(match ty {
+        MoveArgType::Bool => match take(bytes, 1)? {
+            [0] => "false".to_string(),
+            [1] => "true".to_string(),
+            _ => return None,
+        },
+        MoveArgType::U8 => take(bytes, 1)?[0].to_string(),
+        MoveArgType::U16 => u16::from_le_bytes(take(bytes, 2)?.try_into().ok()?).to_string(),
+        MoveArgType::U32 => u32::from_le_bytes(take(bytes, 4)?.try_into().ok()?).to_string(),
+        MoveArgType::U64 => u64::from_le_bytes(take(bytes, 8)?.try_into().ok()?).to_string(),
+        MoveArgType::U128 => u128::from_le_bytes(take(bytes, 16)?.try_into().ok()?).to_string(),
+        MoveArgType::Address => AccountAddress::from_bytes(take(bytes, AccountAddress::LENGTH)?)
+            .ok()?
+            .to_hex_literal(),
+        MoveArgType::String => {
+            let len = take_len(bytes)?;
+            format!("{:?}", std::str::from_utf8(take(bytes, len)?).ok()?)
+        }
+        MoveArgType::Vector(items) => {
+            let len = take_len(bytes)?;
+            let items = (0..len)
+                .map(|_| decode_value(*items, bytes))
+                .collect::<Option<Vec<_>>>()?;
+            format!("[{}]", items.join(", "))
+        }
+    })
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if bytes.len() < len {
+        return None;
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Some(head)
+}
+
+/// Length of a vector, ULEB128 encoded on at most 5 bytes.
+fn take_len(bytes: &mut &[u8]) -> Option<usize> {
+    let mut len = 0u64;
+    for shift in (0..35).step_by(7) {
+        let byte = take(bytes, 1)?[0];
+        len |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return usize::try_from(len).ok();
+        }
+    }
+    None
+}
+
+fn expires_in(expiration_timestamp_secs: u64) -> Option<Duration> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    Duration::from_secs(expiration_timestamp_secs).checked_sub(now)
+}
+
+fn load<T: serde::de::DeserializeOwned>(path: impl AsRef<Path>) -> EconiaResult<T> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| EconiaError::OfflineTransactionError(e.to_string()))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+fn save<T: Serialize>(value: &T, path: impl AsRef<Path>) -> EconiaResult<()> {
+    let content = serde_json::to_string_pretty(value)?;
+    fs::write(path, content).map_err(|e| EconiaError::OfflineTransactionError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use aptos_sdk::move_types::ident_str;
+    use aptos_sdk::move_types::language_storage::{ModuleId, TypeTag};
+    use aptos_sdk::types::transaction::{EntryFunction, Script, TransactionArgument};
+
+    use super::*;
+    use crate::generated::entry;
+
+    #[test]
+    fn decode_arguments() {
+        // Vectors of more than 127 items have a length encoded on several bytes.
+        let sevens = format!("[{}]", vec!["7"; 200].join(", "));
+        let cases = [
+            (MoveArgType::Bool, bcs::to_bytes(&true).unwrap(), "true"),
+            (MoveArgType::U8, bcs::to_bytes(&3u8).unwrap(), "3"),
+            (
+                MoveArgType::U64,
+                bcs::to_bytes(&u64::MAX).unwrap(),
+                "18446744073709551615",
+            ),
+            (
+                MoveArgType::U128,
+                bcs::to_bytes(&(1u128 << 64)).unwrap(),
+                "18446744073709551616",
+            ),
+            (
+                MoveArgType::Address,
+                bcs::to_bytes(&AccountAddress::from_hex_literal("0xa11ce").unwrap()).unwrap(),
+                "0xa11ce",
+            ),
+            (
+                MoveArgType::String,
+                bcs::to_bytes("Econia").unwrap(),
+                "\"Econia\"",
+            ),
+            (
+                MoveArgType::Vector(&MoveArgType::Vector(&MoveArgType::U64)),
+                bcs::to_bytes(&vec![vec![1u64, 2], vec![], vec![3]]).unwrap(),
+                "[[1, 2], [], [3]]",
+            ),
+            (
+                MoveArgType::Vector(&MoveArgType::U8),
+                bcs::to_bytes(&vec![7u8; 200]).unwrap(),
+                sevens.as_str(),
+            ),
+        ];
+        for (ty, bytes, value) in cases {
+            assert_eq!(decode_argument(ty, &bytes).as_deref(), Some(value));
+        }
+    }
+
+    #[test]
+    fn reject_invalid_arguments() {
+        assert_eq!(decode_argument(MoveArgType::Bool, &[2]), None);
+        assert_eq!(decode_argument(MoveArgType::U64, &[0; 7]), None);
+        assert_eq!(decode_argument(MoveArgType::U64, &[0; 9]), None);
+        assert_eq!(decode_argument(MoveArgType::String, &[1, 0xff]), None);
+        assert_eq!(
+            decode_argument(
+                MoveArgType::Vector(&MoveArgType::U64),
+                &[2, 0, 0, 0, 0, 0, 0, 0, 0]
+            ),
+            None
+        );
+        assert_eq!(
+            decode_argument(MoveArgType::Vector(&MoveArgType::U8), &[0xff; 6]),
+            None
+        );
+    }
+
+    #[test]
+    fn describe_entry_arguments() {
+        let econia = AccountAddress::from_hex_literal("0xc0deb00c").unwrap();
+        let integrator = AccountAddress::from_hex_literal("0xbeef").unwrap();
+        let entry = entry::market::place_limit_order_user_entry(
+            econia,
+            &TypeTag::U8,
+            &TypeTag::U64,
+            1,
+            integrator,
+            true,
+            100,
+            990,
+            0,
+            2,
+        )
+        .unwrap();
+        let payload = TransactionPayload::EntryFunction(entry);
+        assert_eq!(
+            describe_arguments(&payload).unwrap(),
+            [
+                "market_id: 1",
+                "integrator: 0xbeef",
+                "side: true",
+                "size: 100",
+                "price: 990",
+                "restriction: 0",
+                "self_match_behavior: 2",
+            ]
+        );
+
+        // Functions of other packages are displayed hex encoded.
+        let entry = EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, ident_str!("coin").to_owned()),
+            ident_str!("transfer").to_owned(),
+            vec![TypeTag::U8],
+            vec![
+                bcs::to_bytes(&integrator).unwrap(),
+                bcs::to_bytes(&5u64).unwrap(),
+            ],
+        );
+        let arguments = describe_arguments(&TransactionPayload::EntryFunction(entry)).unwrap();
+        assert_eq!(arguments[1], "1: 0x0500000000000000");
+
+        let script = Script::new(vec![], vec![], vec![TransactionArgument::U64(5)]);
+        let arguments = describe_arguments(&TransactionPayload::Script(script)).unwrap();
+        assert_eq!(arguments.len(), 1);
+    }
+}
@@ -0,0 +1,449 @@
+//! The [`onboarding`](crate::onboarding) module gets the user account of an
+//! [`EconiaClient`] ready to trade on a market with
+//! [`EconiaClient::prepare_market_account`].
+//!
+//! The workflow inspects the state of the account and only submits the steps that are missing,
+//! in order:
+//!
+//! 1. Register the user for the base and quote coins, see
+//!    [`EconiaClient::is_registered_for_coin`]. Generic base assets need no registration.
+//! 2. Register the market account, for the [`NO_CUSTODIAN`] custodian ID.
+//! 3. Initialize the event handles of the market account, if missing.
+//! 4. Deposit coins from the coin store, up to the [`MarketDeposits`] targets.
+//!
+//! Deposits are targets for the total balance of the market account rather than amounts to add,
+//! so that a workflow interrupted by a failure can be run again without depositing twice.
+
+use aptos_sdk::move_types::language_storage::TypeTag;
+
+use crate::accounts::NO_CUSTODIAN;
+use crate::errors::EconiaError;
+use crate::units::asset_type_tag;
+use crate::{entry, EconiaClient, EconiaResult, EconiaTransaction};
+
+/// Balances the market account should hold once prepared, in subunits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MarketDeposits {
+    /// Minimum total base balance, must be 0 for markets with a generic base asset.
+    pub base: u64,
+    /// Minimum total quote balance.
+    pub quote: u64,
+}
+
+/// A step of the [`onboarding`](crate::onboarding) workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// Register the user for a coin.
+    RegisterCoin(TypeTag),
+    RegisterMarketAccount,
+    InitEventHandles,
+    /// Deposit an amount of a coin from the coin store to the market account.
+    Deposit {
+        coin: TypeTag,
+        amount: u64,
+    },
+}
+
+/// Report of [`EconiaClient::prepare_market_account`].
+#[derive(Debug, Clone)]
+pub struct MarketAccountReport {
+    pub market_id: u64,
+    pub custodian_id: u64,
+    /// Steps which were already done before the workflow ran.
+    pub skipped: Vec<OnboardingStep>,
+    /// Steps submitted by the workflow, in order, along with their transaction.
+    pub submitted: Vec<(OnboardingStep, EconiaTransaction)>,
+    /// Total base balance of the market account once prepared, as read from the chain.
+    pub base_total: u64,
+    /// Total quote balance of the market account once prepared, as read from the chain.
+    pub quote_total: u64,
+}
+
+impl MarketAccountReport {
+    /// Whether the market account was already prepared, no step being submitted.
+    pub fn was_ready(&self) -> bool {
+        self.submitted.is_empty()
+    }
+
+    fn record(&mut self, step: OnboardingStep, transaction: Option<EconiaTransaction>) {
+        match transaction {
+            Some(transaction) => self.submitted.push((step, transaction)),
+            None => self.skipped.push(step),
+        }
+    }
+}
+
+impl EconiaClient {
+    /// Get the user account ready to trade on a market, see the
+    /// [`onboarding`](crate::onboarding) module.
+    ///
+    /// Steps are submitted one after the other and the first failure is returned. The steps
+    /// submitted before it are committed, run the workflow again to complete the remaining ones.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market_id` - Market ID of the market.
+    /// * `deposits` - [`MarketDeposits`] the market account should hold.
+    pub async fn prepare_market_account(
+        &self,
+        market_id: u64,
+        deposits: MarketDeposits,
+    ) -> EconiaResult<MarketAccountReport> {
+        let view_client = self.view_client();
+        let user = self.user_address();
+        let market = view_client.get_market_info(market_id).await?;
+        let generic_base = !market.base_name_generic.is_empty();
+        if generic_base && deposits.base > 0 {
+            return Err(EconiaError::OnboardingError(format!(
+                "market {} has a generic base asset, which cannot be deposited from a coin store",
+                market_id
+            )));
+        }
+        let base = asset_type_tag(&market.base_type)?;
+        let quote = asset_type_tag(&market.quote_type)?;
+        let mut report = MarketAccountReport {
+            market_id,
+            custodian_id: NO_CUSTODIAN,
+            skipped: vec![],
+            submitted: vec![],
+            base_total: 0,
+            quote_total: 0,
+        };
+
+        let coins = if generic_base {
+            vec![&quote]
+        } else {
+            vec![&base, &quote]
+        };
+        for coin in coins {
+            let transaction = if self.is_registered_for_coin(coin).await? {
+                None
+            } else {
+                Some(self.submit_tx(entry::register_for_coin(coin)?).await?)
+            };
+            report.record(OnboardingStep::RegisterCoin(coin.clone()), transaction);
+        }
+
+        let transaction = if view_client
+            .has_market_account(user, market_id, NO_CUSTODIAN)
+            .await?
+        {
+            None
+        } else {
+            let entry = if generic_base {
+                entry::register_market_account_generic_base(
+                    self.econia_address,
+                    &quote,
+                    market_id,
+                    NO_CUSTODIAN,
+                )?
+            } else {
+                entry::register_market_account(
+                    self.econia_address,
+                    &base,
+                    &quote,
+                    market_id,
+                    NO_CUSTODIAN,
+                )?
+            };
+            Some(self.submit_tx(entry).await?)
+        };
+        report.record(OnboardingStep::RegisterMarketAccount, transaction);
+
+        // Checked after registering the market account, which may initialize them.
+        let transaction = if view_client
+            .get_market_event_handle_creation_numbers(user, market_id, NO_CUSTODIAN)
+            .await?
+            .is_some()
+        {
+            None
+        } else {
+            let entry = entry::init_market_event_handles_if_missing(
+                self.econia_address,
+                market_id,
+                NO_CUSTODIAN,
+            )?;
+            Some(self.submit_tx(entry).await?)
+        };
+        report.record(OnboardingStep::InitEventHandles, transaction);
+
+        let account = view_client
+            .get_market_account(user, market_id, NO_CUSTODIAN)
+            .await?;
+        let mut targets = vec![];
+        if !generic_base {
+            targets.push((&base, deposits.base, account.base_total));
+        }
+        targets.push((&quote, deposits.quote, account.quote_total));
+        // Check every balance before depositing, so that a missing one deposits nothing.
+        for (coin, target, total) in &targets {
+            let missing = target.saturating_sub(*total);
+            if missing > 0 && self.get_coin_balance(coin).await?.0 < missing {
+                return Err(EconiaError::OnboardingError(format!(
+                    "depositing {} of `{}` exceeds the balance of the user",
+                    missing, coin
+                )));
+            }
+        }
+        for (coin, target, total) in targets {
+            let missing = target.saturating_sub(total);
+            let transaction = if missing == 0 {
+                None
+            } else {
+                let entry = entry::deposit_from_coinstore(
+                    self.econia_address,
+                    coin,
+                    market_id,
+                    NO_CUSTODIAN,
+                    missing,
+                )?;
+                Some(self.submit_tx(entry).await?)
+            };
+            report.record(
+                OnboardingStep::Deposit {
+                    coin: coin.clone(),
+                    amount: missing,
+                },
+                transaction,
+            );
+        }
+        let deposited = report
+            .submitted
+            .iter()
+            .any(|(step, _)| matches!(step, OnboardingStep::Deposit { .. }));
+        let account = if deposited {
+            view_client
+                .get_market_account(user, market_id, NO_CUSTODIAN)
+                .await?
+        } else {
+            account
+        };
+        report.base_total = account.base_total;
+        report.quote_total = account.quote_total;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use aptos_api_types::{AptosErrorCode, EntryFunctionId, ViewRequest};
+    use aptos_sdk::bcs;
+    use aptos_sdk::types::account_address::AccountAddress;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::generated::view;
+    use crate::tests::{api_error, mock_client, ECONIA};
+    use crate::transport::{MockOutcome, MockTransport};
+
+    const BASE: &str = "0x1::assets::BC";
+    const QUOTE: &str = "0x1::assets::QC";
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn coin(type_tag: &str) -> TypeTag {
+        TypeTag::from_str(type_tag).unwrap()
+    }
+
+    fn market_account(base_total: u64, quote_total: u64) -> Value {
+        json!({
+            "market_id": "1",
+            "custodian_id": "0",
+            "asks": [],
+            "bids": [],
+            "base_total": base_total.to_string(),
+            "base_available": base_total.to_string(),
+            "base_ceiling": base_total.to_string(),
+            "quote_total": quote_total.to_string(),
+            "quote_available": quote_total.to_string(),
+            "quote_ceiling": quote_total.to_string(),
+        })
+    }
+
+    /// Serve market 1 of coins, for which the user holds 1000 subunits of each coin, and queue
+    /// the responses of the market account views.
+    ///
+    /// # Arguments:
+    ///
+    /// * `registered` - Successive answers of `has_market_account`.
+    /// * `accounts` - Successive answers of `get_market_account`.
+    fn serve_market(
+        transport: &MockTransport,
+        user: AccountAddress,
+        registered: &[bool],
+        accounts: &[Value],
+    ) {
+        let asset = |type_name: &str| {
+            json!({
+                "package_address": "0x1",
+                "module_name": "assets",
+                "type_name": type_name,
+            })
+        };
+        transport
+            .push_view(
+                &view::registry::get_market_info(econia(), 1).unwrap(),
+                vec![json!({
+                    "market_id": "1",
+                    "is_recognized": true,
+                    "base_type": asset("BC"),
+                    "base_name_generic": "",
+                    "quote_type": asset("QC"),
+                    "lot_size": "1",
+                    "tick_size": "1",
+                    "min_size": "1",
+                    "underwriter_id": "0",
+                })],
+            )
+            .unwrap();
+        for type_tag in [BASE, QUOTE] {
+            transport
+                .set_resource(
+                    user,
+                    &format!("0x1::coin::CoinStore<{}>", type_tag),
+                    json!({ "coin": { "value": "1000" } }),
+                )
+                .unwrap();
+            let paired_metadata = ViewRequest {
+                function: EntryFunctionId::from_str("0x1::coin::paired_metadata").unwrap(),
+                type_arguments: vec![(&coin(type_tag)).into()],
+                arguments: vec![],
+            };
+            transport
+                .push_view(&paired_metadata, vec![json!({ "vec": [] })])
+                .unwrap();
+        }
+        let has_market_account = view::user::has_market_account(econia(), user, 1, 0).unwrap();
+        for registered in registered {
+            transport
+                .push_view(&has_market_account, vec![json!(registered)])
+                .unwrap();
+        }
+        let handles = json!({
+            "cancel_order_events_handle_creation_num": "10",
+            "change_order_size_events_handle_creation_num": "11",
+            "fill_events_handle_creation_num": "12",
+            "place_limit_order_events_handle_creation_num": "13",
+            "place_market_order_events_handle_creation_num": "14",
+        });
+        transport
+            .push_view(
+                &view::user::get_market_event_handle_creation_numbers(econia(), user, 1, 0)
+                    .unwrap(),
+                vec![json!({ "vec": [handles] })],
+            )
+            .unwrap();
+        let get_market_account = view::user::get_market_account(econia(), user, 1, 0).unwrap();
+        for account in accounts {
+            transport
+                .push_view(&get_market_account, vec![account.clone()])
+                .unwrap();
+        }
+    }
+
+    /// Submitted deposits, as their coin and amount.
+    fn deposits(transport: &MockTransport) -> Vec<(TypeTag, u64)> {
+        transport
+            .submitted_entry_functions()
+            .into_iter()
+            .filter(|entry| entry.function().as_str() == "deposit_from_coinstore")
+            .map(|entry| {
+                let amount = bcs::from_bytes(&entry.args()[2]).unwrap();
+                (entry.ty_args()[0].clone(), amount)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn resume_after_a_partial_failure() {
+        let (transport, client) = mock_client().await;
+        let targets = MarketDeposits {
+            base: 100,
+            quote: 500,
+        };
+        serve_market(
+            &transport,
+            client.user_address(),
+            &[false, true],
+            &[
+                market_account(0, 0),
+                market_account(100, 0),
+                // Totals are read back once deposited, including anything credited meanwhile.
+                market_account(100, 520),
+            ],
+        );
+        // The market account and the base deposit are committed, the quote deposit fails.
+        transport.push_outcome(MockOutcome::Committed(vec![]));
+        transport.push_outcome(MockOutcome::Committed(vec![]));
+        transport.push_outcome(MockOutcome::Rejected(api_error(
+            400,
+            AptosErrorCode::VmError,
+        )));
+        assert!(client.prepare_market_account(1, targets).await.is_err());
+        assert_eq!(deposits(&transport), [(coin(BASE), 100)]);
+
+        // Only the missing quote is deposited when running again.
+        let report = client.prepare_market_account(1, targets).await.unwrap();
+        let submitted = report
+            .submitted
+            .iter()
+            .map(|(step, _)| step.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            submitted,
+            [OnboardingStep::Deposit {
+                coin: coin(QUOTE),
+                amount: 500
+            }]
+        );
+        assert_eq!(report.skipped.len(), 5);
+        assert_eq!((report.base_total, report.quote_total), (100, 520));
+
+        // Then there is nothing left to do.
+        let report = client.prepare_market_account(1, targets).await.unwrap();
+        assert!(report.was_ready());
+        assert_eq!((report.base_total, report.quote_total), (100, 520));
+        let functions = transport
+            .submitted_entry_functions()
+            .iter()
+            .map(|entry| entry.function().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            functions,
+            [
+                "register_market_account",
+                "deposit_from_coinstore",
+                "deposit_from_coinstore"
+            ]
+        );
+        assert_eq!(
+            deposits(&transport),
+            [(coin(BASE), 100), (coin(QUOTE), 500)]
+        );
+    }
+
+    #[tokio::test]
+    async fn reject_deposits_exceeding_the_balance() {
+        let (transport, client) = mock_client().await;
+        serve_market(
+            &transport,
+            client.user_address(),
+            &[true],
+            &[market_account(0, 600)],
+        );
+        let targets = MarketDeposits {
+            base: 1_001,
+            quote: 1_500,
+        };
+        assert!(matches!(
+            client.prepare_market_account(1, targets).await,
+            Err(EconiaError::OnboardingError(_))
+        ));
+        // Neither coin is deposited, although the quote balance suffices.
+        assert!(transport.submitted().is_empty());
+    }
+}
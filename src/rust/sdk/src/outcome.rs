@@ -0,0 +1,310 @@
+//! The [`outcome`](crate::outcome) module summarizes the orders placed by a transaction from the
+//! events it emitted.
+//!
+//! Every order placed emits a place event carrying its order ID, followed by the
+//! [`FillEvent`]s it matched against, with the order as taker, and a [`CancelOrderEvent`] if the
+//! part of it which was not filled did not post. Those events are correlated by market ID and
+//! order ID into an [`OrderOutcome`] for limit and market orders, and a [`SwapOutcome`] for swaps.
+//! Fill events are emitted once for the taker and once for the maker, and are only counted once.
+//!
+//! Outcomes are returned alongside every [`EconiaTransaction`](crate::EconiaTransaction).
+//! Amounts are in lots and ticks, convert them with [`MarketUnits`](crate::units::MarketUnits).
+
+use econia_types::events::{
+    EconiaEvent, FillEvent, PlaceLimitOrderEvent, PlaceMarketOrderEvent, PlaceSwapOrderEvent,
+};
+use econia_types::order::{CancelReason, Side};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKind {
+    Limit,
+    Market,
+}
+
+/// Summary of a limit or market order placed by a transaction.
+#[derive(Debug, Clone)]
+pub struct OrderOutcome {
+    pub market_id: u64,
+    pub order_id: u128,
+    /// Address of the user of the market account the order was placed for.
+    pub user: String,
+    pub custodian_id: Option<u64>,
+    pub kind: OrderKind,
+    /// Side of a limit order, direction of a market order.
+    pub side: Side,
+    /// Size of the order in lots.
+    pub size: u64,
+    /// Price of a limit order, in ticks per lot.
+    pub price: Option<u64>,
+    /// Fills of the order as taker, in order.
+    pub fills: Vec<FillEvent>,
+    /// Size posted to the order book in lots, always 0 for market orders.
+    pub posted_size: u64,
+    /// Why the part of the order which was not filled did not post, if it did not.
+    pub cancel_reason: Option<CancelReason>,
+}
+
+impl OrderOutcome {
+    fn limit(event: &PlaceLimitOrderEvent, events: &[EconiaEvent]) -> Self {
+        let (fills, cancel_reason) = correlate(events, event.market_id, event.order_id);
+        Self {
+            market_id: event.market_id,
+            order_id: event.order_id,
+            user: event.user.clone(),
+            custodian_id: event.custodian_id,
+            kind: OrderKind::Limit,
+            side: event.side,
+            size: event.size,
+            price: Some(event.price),
+            fills,
+            posted_size: match cancel_reason {
+                Some(_) => 0,
+                None => event.remaining_size,
+            },
+            cancel_reason,
+        }
+    }
+
+    fn market(event: &PlaceMarketOrderEvent, events: &[EconiaEvent]) -> Self {
+        let (fills, cancel_reason) = correlate(events, event.market_id, event.order_id);
+        Self {
+            market_id: event.market_id,
+            order_id: event.order_id,
+            user: event.user.clone(),
+            custodian_id: event.custodian_id,
+            kind: OrderKind::Market,
+            side: event.direction,
+            size: event.size,
+            price: None,
+            fills,
+            posted_size: 0,
+            cancel_reason,
+        }
+    }
+
+    /// Size filled in lots.
+    pub fn filled_size(&self) -> u64 {
+        filled_size(&self.fills)
+    }
+
+    /// Ticks filled.
+    pub fn filled_ticks(&self) -> u64 {
+        filled_ticks(&self.fills)
+    }
+
+    /// Average price filled at in ticks per lot, `None` if the order did not fill.
+    pub fn average_price(&self) -> Option<f64> {
+        average_price(&self.fills)
+    }
+
+    /// Taker fees paid, in quote subunits.
+    pub fn fees_paid(&self) -> u64 {
+        fees_paid(&self.fills)
+    }
+
+    /// Whether part of the order posted to the order book.
+    pub fn posted(&self) -> bool {
+        self.posted_size > 0
+    }
+
+    /// Whether the order filled completely.
+    pub fn is_filled(&self) -> bool {
+        self.filled_size() == self.size
+    }
+}
+
+/// Summary of a swap placed by a transaction.
+#[derive(Debug, Clone)]
+pub struct SwapOutcome {
+    pub market_id: u64,
+    pub order_id: u128,
+    /// Address of the account which signed the swap.
+    pub signing_account: String,
+    pub direction: Side,
+    /// Limit price of the swap, in ticks per lot.
+    pub limit_price: u64,
+    /// Fills of the swap, in order.
+    pub fills: Vec<FillEvent>,
+    /// Why the swap stopped matching before its maximum amounts were traded, if it did.
+    pub cancel_reason: Option<CancelReason>,
+}
+
+impl SwapOutcome {
+    fn new(event: &PlaceSwapOrderEvent, events: &[EconiaEvent]) -> Self {
+        let (fills, cancel_reason) = correlate(events, event.market_id, event.order_id);
+        Self {
+            market_id: event.market_id,
+            order_id: event.order_id,
+            signing_account: event.signing_account.clone(),
+            direction: event.direction,
+            limit_price: event.limit_price,
+            fills,
+            cancel_reason,
+        }
+    }
+
+    /// Size filled in lots.
+    pub fn filled_size(&self) -> u64 {
+        filled_size(&self.fills)
+    }
+
+    /// Ticks filled.
+    pub fn filled_ticks(&self) -> u64 {
+        filled_ticks(&self.fills)
+    }
+
+    /// Average price filled at in ticks per lot, `None` if the swap did not fill.
+    pub fn average_price(&self) -> Option<f64> {
+        average_price(&self.fills)
+    }
+
+    /// Taker fees paid, in quote subunits.
+    pub fn fees_paid(&self) -> u64 {
+        fees_paid(&self.fills)
+    }
+}
+
+/// Outcomes of the limit and market orders placed in `events`, in order.
+///
+/// # Arguments:
+///
+/// * `events` - [`EconiaEvent`]s emitted by a transaction.
+pub fn order_outcomes(events: &[EconiaEvent]) -> Vec<OrderOutcome> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            EconiaEvent::PlaceLimitOrder(e) => Some(OrderOutcome::limit(e, events)),
+            EconiaEvent::PlaceMarketOrder(e) => Some(OrderOutcome::market(e, events)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Outcomes of the swaps placed in `events`, in order.
+///
+/// # Arguments:
+///
+/// * `events` - [`EconiaEvent`]s emitted by a transaction.
+pub fn swap_outcomes(events: &[EconiaEvent]) -> Vec<SwapOutcome> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            EconiaEvent::PlaceSwapOrder(e) => Some(SwapOutcome::new(e, events)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Fills as taker and cancel reason of an order.
+fn correlate(
+    events: &[EconiaEvent],
+    market_id: u64,
+    order_id: u128,
+) -> (Vec<FillEvent>, Option<CancelReason>) {
+    let mut fills = vec![];
+    let mut cancel_reason = None;
+    for event in events
+        .iter()
+        .filter(|e| concerns_order(e, market_id, order_id))
+    {
+        match event {
+            // Fills are emitted to the taker and again to the maker.
+            EconiaEvent::Fill(e)
+                if fills.iter().all(|f: &FillEvent| {
+                    f.sequence_number_for_trade != e.sequence_number_for_trade
+                }) =>
+            {
+                fills.push(e.as_ref().clone())
+            }
+            EconiaEvent::CancelOrder(e) => cancel_reason = Some(e.reason.clone()),
+            _ => {}
+        }
+    }
+    (fills, cancel_reason)
+}
+
+/// Whether `event` is a fill of an order as taker, maker and taker copies alike, or a cancel of
+/// the order.
+pub(crate) fn concerns_order(event: &EconiaEvent, market_id: u64, order_id: u128) -> bool {
+    match event {
+        EconiaEvent::Fill(e) => e.market_id == market_id && e.taker_order_id == order_id,
+        EconiaEvent::CancelOrder(e) => e.market_id == market_id && e.order_id == order_id,
+        _ => false,
+    }
+}
+
+fn filled_size(fills: &[FillEvent]) -> u64 {
+    fills.iter().map(|f| f.size).sum()
+}
+
+fn filled_ticks(fills: &[FillEvent]) -> u64 {
+    fills.iter().map(|f| f.size * f.price).sum()
+}
+
+fn average_price(fills: &[FillEvent]) -> Option<f64> {
+    match filled_size(fills) {
+        0 => None,
+        size => Some(filled_ticks(fills) as f64 / size as f64),
+    }
+}
+
+fn fees_paid(fills: &[FillEvent]) -> u64 {
+    fills.iter().map(|f| f.taker_quote_fees_paid).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::events;
+
+    const TAKER_ORDER_ID: u128 = 7;
+
+    fn fill(maker_order_id: u128, size: u64, price: u64, sequence_number: u64) -> EconiaEvent {
+        events::fill(maker_order_id, TAKER_ORDER_ID, size, price, sequence_number)
+    }
+
+    #[test]
+    fn count_duplicate_fills_once() {
+        let place = events::place_limit(TAKER_ORDER_ID, Side::Bid, 10, 105, 0);
+        // Every fill is emitted to the taker, then to the maker.
+        let events = [
+            place,
+            fill(1, 4, 100, 0),
+            fill(1, 4, 100, 0),
+            fill(2, 2, 105, 1),
+            fill(2, 2, 105, 1),
+            events::cancel(TAKER_ORDER_ID, CancelReason::ImmediateOrCancel),
+        ];
+        let outcomes = order_outcomes(&events);
+        assert_eq!(outcomes.len(), 1);
+        let outcome = &outcomes[0];
+        assert_eq!(outcome.fills.len(), 2);
+        assert_eq!(outcome.filled_size(), 6);
+        assert_eq!(outcome.filled_ticks(), 610);
+        assert_eq!(outcome.fees_paid(), 6);
+        assert!(!outcome.posted());
+        assert_eq!(outcome.cancel_reason, Some(CancelReason::ImmediateOrCancel));
+    }
+
+    #[test]
+    fn count_duplicate_swap_fill_once() {
+        let place = EconiaEvent::PlaceSwapOrder(Box::new(PlaceSwapOrderEvent {
+            market_id: 1,
+            signing_account: "0xa11ce".to_string(),
+            integrator: None,
+            direction: Side::Bid,
+            min_base: 0,
+            max_base: 100,
+            min_quote: 0,
+            max_quote: 1000,
+            limit_price: 100,
+            order_id: TAKER_ORDER_ID,
+        }));
+        let events = [place, fill(1, 3, 100, 0), fill(1, 3, 100, 0)];
+        let outcomes = swap_outcomes(&events);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].fills.len(), 1);
+        assert_eq!(outcomes[0].filled_size(), 3);
+        assert_eq!(outcomes[0].cancel_reason, None);
+    }
+}
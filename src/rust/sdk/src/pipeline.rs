@@ -0,0 +1,610 @@
+//! The [`pipeline`](crate::pipeline) module exposes a [`TransactionPipeline`] which keeps several
+//! transactions in flight at once.
+//!
+//! [`EconiaClient::submit_tx`](crate::EconiaClient::submit_tx) waits for a transaction to be
+//! committed before the next one can be sent. The pipeline instead hands out sequence numbers
+//! locally, submits transactions as soon as they are queued and reports the result of each of
+//! them through a channel.
+//!
+//! Transient submission failures are retried with the same signed transaction, following the
+//! [`RetryPolicy`](crate::retry::RetryPolicy) of the client. A transaction whose outcome is not
+//! known, e.g. as waiting for it timed out, is left alone until the chain clock passes its
+//! expiration. Then, if its sequence number is still unused, it is resubmitted with the same
+//! sequence number. If it still cannot be committed, its sequence number is filled with a no-op
+//! transaction so that the transactions queued after it are not stuck.
+//!
+//! Sequence numbers are shared with [`EconiaClient::submit_tx`](crate::EconiaClient::submit_tx),
+//! which resynchronizes them with the chain when a submission fails. Avoid calling it while the
+//! pipeline has transactions in flight.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use aptos_api_types::AptosErrorCode;
+use aptos_sdk::bcs;
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::ident_str;
+use aptos_sdk::move_types::language_storage::ModuleId;
+use aptos_sdk::rest_client::error::RestError;
+use aptos_sdk::transaction_builder::TransactionFactory;
+use aptos_sdk::types::transaction::{EntryFunction, SignedTransaction};
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
+
+use crate::errors::EconiaError;
+use crate::retry::RetryClass;
+use crate::{wait_for_transaction, EconiaClient, EconiaResult, EconiaTransaction};
+
+pub const MAX_IN_FLIGHT: usize = 16;
+pub const EXPIRATION_SECS: u64 = 30;
+pub const RESUBMIT_ATTEMPTS: u8 = 3;
+
+#[derive(Debug, Clone)]
+pub struct PipelineConfig {
+    /// Maximum amount of transactions in flight at once.
+    pub max_in_flight: usize,
+    /// Seconds after which a submitted transaction expires.
+    pub expiration_secs: u64,
+    /// Amount of times to resubmit an expired transaction before filling its sequence number.
+    pub resubmit_count: u8,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            max_in_flight: MAX_IN_FLIGHT,
+            expiration_secs: EXPIRATION_SECS,
+            resubmit_count: RESUBMIT_ATTEMPTS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineStatus {
+    /// Submitted, waiting to be committed.
+    Pending { sequence_number: u64, attempts: u8 },
+    /// Committed on chain, successfully or not.
+    Committed { sequence_number: u64 },
+    /// Not committed, its sequence number was filled with a no-op transaction.
+    GapFilled { sequence_number: u64 },
+    /// Not committed, and its sequence number could not be filled.
+    Failed { sequence_number: u64 },
+    /// Neither known to be committed nor known not to be, e.g. as the node could not be reached
+    /// while waiting for it to expire.
+    Unknown { sequence_number: u64 },
+}
+
+impl PipelineStatus {
+    /// Whether the transaction is still in flight.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending { .. })
+    }
+}
+
+#[derive(Debug)]
+pub struct PipelineResult {
+    /// ID returned by [`TransactionPipeline::submit`].
+    pub id: u64,
+    /// Sequence number the transaction was submitted with.
+    pub sequence_number: u64,
+    /// Outcome of the transaction.
+    pub result: EconiaResult<EconiaTransaction>,
+}
+
+/// Submits transactions concurrently, see the [module documentation](crate::pipeline).
+#[derive(Clone)]
+pub struct TransactionPipeline {
+    inner: Arc<PipelineInner>,
+}
+
+struct PipelineInner {
+    client: Arc<EconiaClient>,
+    config: PipelineConfig,
+    permits: Arc<Semaphore>,
+    next_id: AtomicU64,
+    statuses: Mutex<HashMap<u64, PipelineStatus>>,
+    results: mpsc::UnboundedSender<PipelineResult>,
+}
+
+impl TransactionPipeline {
+    /// Create a pipeline submitting transactions signed by the user account of `client`.
+    ///
+    /// Returns the pipeline along with the receiving end of the channel the result of every
+    /// transaction is sent to.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to submit the transactions with.
+    /// * `config` - `PipelineConfig` to configure the pipeline, if `None` default values will be used.
+    pub fn new(
+        client: Arc<EconiaClient>,
+        config: Option<PipelineConfig>,
+    ) -> (Self, mpsc::UnboundedReceiver<PipelineResult>) {
+        let config = config.unwrap_or_default();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let pipeline = Self {
+            inner: Arc::new(PipelineInner {
+                client,
+                permits: Arc::new(Semaphore::new(config.max_in_flight)),
+                config,
+                next_id: AtomicU64::new(0),
+                statuses: Mutex::new(HashMap::new()),
+                results: sender,
+            }),
+        };
+        (pipeline, receiver)
+    }
+
+    /// Queue a transaction, returning the ID its result will be reported with.
+    ///
+    /// Waits for a slot if `max_in_flight` transactions are already in flight. Sequence numbers
+    /// are handed out in the order transactions are queued.
+    ///
+    /// Must be called from within a tokio runtime.
+    ///
+    /// # Arguments:
+    ///
+    /// * `entry` - `EntryFunction` to be submitted as part of the transaction to the blockchain.
+    pub async fn submit(&self, entry: EntryFunction) -> EconiaResult<u64> {
+        let permit = self
+            .inner
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let sequence_number = self.inner.client.increment_sequence_number();
+        self.inner.set_status(
+            id,
+            PipelineStatus::Pending {
+                sequence_number,
+                attempts: 0,
+            },
+        );
+        tokio::spawn(self.inner.clone().run(id, entry, sequence_number, permit));
+        Ok(id)
+    }
+
+    /// Get the status of a queued transaction.
+    pub fn status(&self, id: u64) -> Option<PipelineStatus> {
+        self.inner.statuses.lock().unwrap().get(&id).copied()
+    }
+
+    /// Forget the status of every transaction which is no longer in flight.
+    pub fn prune(&self) {
+        self.inner
+            .statuses
+            .lock()
+            .unwrap()
+            .retain(|_, status| status.is_pending());
+    }
+
+    /// Amount of transactions currently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.inner.config.max_in_flight - self.inner.permits.available_permits()
+    }
+
+    /// Wait until every queued transaction has been processed.
+    pub async fn flush(&self) -> EconiaResult<()> {
+        let n = u32::try_from(self.inner.config.max_in_flight)
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        let _permits = self
+            .inner
+            .permits
+            .acquire_many(n)
+            .await
+            .map_err(|e| EconiaError::Custom(anyhow!(e)))?;
+        Ok(())
+    }
+}
+
+impl PipelineInner {
+    fn set_status(&self, id: u64, status: PipelineStatus) {
+        self.statuses.lock().unwrap().insert(id, status);
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        id: u64,
+        entry: EntryFunction,
+        sequence_number: u64,
+        _permit: OwnedSemaphorePermit,
+    ) {
+        let (status, result) = self.process(id, &entry, sequence_number).await;
+        self.set_status(id, status);
+        // The receiver may have been dropped if the caller is not interested in results.
+        let _ = self.results.send(PipelineResult {
+            id,
+            sequence_number,
+            result,
+        });
+    }
+
+    async fn process(
+        &self,
+        id: u64,
+        entry: &EntryFunction,
+        sequence_number: u64,
+    ) -> (PipelineStatus, EconiaResult<EconiaTransaction>) {
+        let client = &self.client;
+        let policy = &client.config.retry_policy;
+        let mut attempts = 0;
+        let mut submissions = 0;
+        let error = loop {
+            attempts += 1;
+            self.set_status(
+                id,
+                PipelineStatus::Pending {
+                    sequence_number,
+                    attempts,
+                },
+            );
+            let signed_tx = match self.sign(entry, sequence_number).await {
+                Ok(signed_tx) => signed_tx,
+                Err(e) => break e,
+            };
+            // Expiration of the transaction if it may have reached the mempool, in which case it
+            // can be committed until the chain clock passes it.
+            let mut expiration = None;
+            let mut tries = 0;
+            let error = loop {
+                tries += 1;
+                submissions += 1;
+                let error = match client.transport.submit(&signed_tx).await {
+                    Ok(pending) => {
+                        expiration = Some(signed_tx.expiration_timestamp_secs());
+                        match wait_for_transaction(
+                            client.transport.as_ref(),
+                            &client.econia_address,
+                            &pending,
+                        )
+                        .await
+                        {
+                            Ok(tx) => {
+                                policy.on_success(tries);
+                                return (
+                                    PipelineStatus::Committed { sequence_number },
+                                    client.econia_transaction(tx),
+                                );
+                            }
+                            Err(e) => break e,
+                        }
+                    }
+                    Err(RestError::Api(a))
+                        if matches!(a.error.error_code, AptosErrorCode::SequenceNumberTooOld)
+                            && submissions == 1 =>
+                    {
+                        // The sequence number was used outside of the pipeline, which cannot
+                        // recover it without reordering transactions.
+                        if let Ok(seq_num) = client.get_sequence_number().await {
+                            let local = client.sequence_number();
+                            client.set_sequence_number(local.max(seq_num));
+                        }
+                        return (
+                            PipelineStatus::Failed { sequence_number },
+                            Err(EconiaError::AptosError(RestError::Api(a))),
+                        );
+                    }
+                    Err(e) => {
+                        // The node may have accepted the transaction before the request failed.
+                        if !matches!(e, RestError::Api(_)) {
+                            expiration = Some(signed_tx.expiration_timestamp_secs());
+                        }
+                        EconiaError::AptosError(e)
+                    }
+                };
+                // Transient failures are retried with the same transaction, which cannot be
+                // committed twice.
+                match policy.on_failure(tries, &error) {
+                    (RetryClass::Transient, Some(delay)) => tokio::time::sleep(delay).await,
+                    _ => break error,
+                }
+            };
+            if is_committed_failure(&error) {
+                return (PipelineStatus::Committed { sequence_number }, Err(error));
+            }
+            // Otherwise the transaction is only known not to be committed once it expired.
+            match self.settle(sequence_number, expiration).await {
+                Ok(true) => return (PipelineStatus::Committed { sequence_number }, Err(error)),
+                Ok(false) => {}
+                Err(_) => return (PipelineStatus::Unknown { sequence_number }, Err(error)),
+            }
+            if attempts > self.config.resubmit_count {
+                break error;
+            }
+        };
+        match self.fill_gap(sequence_number).await {
+            Ok(true) => (PipelineStatus::GapFilled { sequence_number }, Err(error)),
+            Ok(false) => (PipelineStatus::Failed { sequence_number }, Err(error)),
+            Err(_) => (PipelineStatus::Unknown { sequence_number }, Err(error)),
+        }
+    }
+
+    /// Wait until a transaction submitted with `sequence_number` can no longer be committed,
+    /// returning whether the sequence number was used.
+    ///
+    /// # Arguments:
+    ///
+    /// * `sequence_number` - Sequence number the transaction was submitted with.
+    /// * `expiration` - Expiration timestamp of the transaction, in seconds, `None` if it was
+    ///   rejected by the node.
+    async fn settle(&self, sequence_number: u64, expiration: Option<u64>) -> EconiaResult<bool> {
+        let policy = &self.client.config.retry_policy;
+        let mut failures = 0;
+        loop {
+            let error = match self.chain_secs().await {
+                // Once the chain clock passed the expiration, the transaction can no longer be
+                // committed and the sequence number read after it is final.
+                Ok(now) if expiration.map_or(true, |expiration| now >= expiration) => {
+                    match self.client.get_sequence_number().await {
+                        Ok(seq_num) => return Ok(seq_num > sequence_number),
+                        Err(e) => e,
+                    }
+                }
+                Ok(now) => {
+                    let expiration = expiration.unwrap_or_default();
+                    tokio::time::sleep(Duration::from_secs(expiration - now)).await;
+                    continue;
+                }
+                Err(e) => e,
+            };
+            failures += 1;
+            match policy.on_failure(failures, &error) {
+                (RetryClass::Transient, Some(delay)) => tokio::time::sleep(delay).await,
+                _ => return Err(error),
+            }
+        }
+    }
+
+    /// Timestamp of the latest ledger, in seconds.
+    async fn chain_secs(&self) -> EconiaResult<u64> {
+        let index = self.client.transport.get_index().await?;
+        Ok(index.ledger_timestamp.0 / 1_000_000)
+    }
+
+    /// Sign a transaction expiring `expiration_secs` from now.
+    async fn sign(
+        &self,
+        entry: &EntryFunction,
+        sequence_number: u64,
+    ) -> EconiaResult<SignedTransaction> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let tx = TransactionFactory::new(self.client.chain_id)
+            .entry_function(entry.clone())
+            .sender(self.client.user_address())
+            .sequence_number(sequence_number)
+            .max_gas_amount(self.client.config.max_gas_amount)
+            .expiration_timestamp_secs(now + self.config.expiration_secs)
+            .build();
+        self.client.signer.sign_transaction(tx).await
+    }
+
+    /// Use a sequence number with a transfer of 0 APT to the user account itself, returning
+    /// whether the sequence number is used once the transfer can no longer be committed.
+    async fn fill_gap(&self, sequence_number: u64) -> EconiaResult<bool> {
+        let address = self.client.user_address();
+        let entry = EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, ident_str!("aptos_account").to_owned()),
+            ident_str!("transfer").to_owned(),
+            vec![],
+            vec![bcs::to_bytes(&address)?, bcs::to_bytes(&0u64)?],
+        );
+        let signed_tx = self.sign(&entry, sequence_number).await?;
+        let expiration = match self.client.transport.submit(&signed_tx).await {
+            Ok(pending) => match self.client.transport.wait_for_transaction(&pending).await {
+                Ok(_) => return Ok(true),
+                Err(_) => Some(signed_tx.expiration_timestamp_secs()),
+            },
+            Err(RestError::Api(_)) => None,
+            Err(_) => Some(signed_tx.expiration_timestamp_secs()),
+        };
+        self.settle(sequence_number, expiration).await
+    }
+}
+
+/// Whether a transaction failed after being committed, i.e. it aborted or failed execution.
+fn is_committed_failure(error: &EconiaError) -> bool {
+    match error {
+        EconiaError::AptosError(e) => e.to_string().contains("failed execution"),
+        EconiaError::TransactionOutcomeUnknown(..) => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::entry;
+    use crate::retry::RetryPolicy;
+    use crate::tests::{api_error, mock_client_with_config, ECONIA};
+    use crate::transport::{MockOutcome, MockTransport};
+    use crate::EconiaClientConfig;
+
+    fn cancel(order_id: u128) -> EntryFunction {
+        let econia = AccountAddress::from_hex_literal(ECONIA).unwrap();
+        entry::market::cancel_order_user(econia, 1, false, order_id).unwrap()
+    }
+
+    /// Pipeline of a fresh user account on a mock, whose transactions expire `expiration_secs`
+    /// after being signed.
+    async fn mock_pipeline(
+        expiration_secs: u64,
+        resubmit_count: u8,
+    ) -> (
+        Arc<MockTransport>,
+        TransactionPipeline,
+        mpsc::UnboundedReceiver<PipelineResult>,
+    ) {
+        let transport = Arc::new(MockTransport::new(4));
+        let config = EconiaClientConfig {
+            retry_policy: RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let client = mock_client_with_config(transport.clone(), config).await;
+        let (pipeline, results) = TransactionPipeline::new(
+            Arc::new(client),
+            Some(PipelineConfig {
+                max_in_flight: 4,
+                expiration_secs,
+                resubmit_count,
+            }),
+        );
+        (transport, pipeline, results)
+    }
+
+    fn now_secs() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    #[tokio::test]
+    async fn report_every_transaction_in_order() {
+        let (transport, pipeline, mut results) = mock_pipeline(30, 1).await;
+        transport.push_outcome(MockOutcome::Committed(vec![]));
+        transport.push_outcome(MockOutcome::Failed(
+            "Move abort in 0xc0deb00c::market: 0x1c".to_string(),
+        ));
+        transport.push_outcome(MockOutcome::Committed(vec![]));
+
+        let mut ids = vec![];
+        for order_id in 0..3 {
+            ids.push(pipeline.submit(cancel(order_id)).await.unwrap());
+        }
+        assert_eq!(ids, vec![0, 1, 2]);
+        pipeline.flush().await.unwrap();
+        assert_eq!(pipeline.in_flight(), 0);
+
+        let mut reported = vec![];
+        for _ in 0..3 {
+            reported.push(results.recv().await.unwrap());
+        }
+        reported.sort_by_key(|r| r.id);
+        // Sequence numbers are handed out in the order transactions are queued.
+        for (id, result) in reported.iter().enumerate() {
+            assert_eq!((result.id, result.sequence_number), (id as u64, id as u64));
+            assert_eq!(
+                pipeline.status(result.id),
+                Some(PipelineStatus::Committed {
+                    sequence_number: id as u64
+                })
+            );
+        }
+        // The aborted transaction is committed, and not resubmitted.
+        assert_eq!(reported.iter().filter(|r| r.result.is_err()).count(), 1);
+        let mut sequence_numbers: Vec<_> = transport
+            .submitted()
+            .iter()
+            .map(|tx| tx.sequence_number())
+            .collect();
+        sequence_numbers.sort();
+        assert_eq!(sequence_numbers, vec![0, 1, 2]);
+
+        pipeline.prune();
+        assert_eq!(pipeline.status(0), None);
+    }
+
+    #[tokio::test]
+    async fn retry_transient_failures_with_the_same_transaction() {
+        let (transport, pipeline, mut results) = mock_pipeline(30, 1).await;
+        transport.push_outcome(MockOutcome::Rejected(api_error(
+            503,
+            AptosErrorCode::MempoolIsFull,
+        )));
+        let id = pipeline.submit(cancel(1)).await.unwrap();
+        let result = results.recv().await.unwrap();
+        assert!(result.result.is_ok());
+        assert_eq!(
+            pipeline.status(id),
+            Some(PipelineStatus::Committed { sequence_number: 0 })
+        );
+        assert_eq!(transport.submitted().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn resubmit_once_expired_on_chain() {
+        let (transport, pipeline, mut results) = mock_pipeline(1, 1).await;
+        transport.push_outcome(MockOutcome::Unconfirmed { committed: false });
+        let id = pipeline.submit(cancel(1)).await.unwrap();
+        let result = results.recv().await.unwrap();
+        assert!(result.result.is_ok());
+        assert_eq!(
+            pipeline.status(id),
+            Some(PipelineStatus::Committed { sequence_number: 0 })
+        );
+
+        let submitted = transport.submitted();
+        assert_eq!(submitted.len(), 2);
+        assert!(submitted.iter().all(|tx| tx.sequence_number() == 0));
+        // The transaction was only resubmitted once the chain clock passed its expiration.
+        let expiration = submitted[0].expiration_timestamp_secs();
+        assert!(submitted[1].expiration_timestamp_secs() > expiration);
+        assert!(now_secs() >= expiration);
+    }
+
+    #[tokio::test]
+    async fn do_not_resubmit_a_committed_transaction() {
+        let (transport, pipeline, mut results) = mock_pipeline(0, 3).await;
+        transport.push_outcome(MockOutcome::Unconfirmed { committed: true });
+        let id = pipeline.submit(cancel(1)).await.unwrap();
+        let result = results.recv().await.unwrap();
+        assert!(matches!(
+            result.result,
+            Err(EconiaError::TransactionOutcomeUnknown(..))
+        ));
+        assert_eq!(
+            pipeline.status(id),
+            Some(PipelineStatus::Committed { sequence_number: 0 })
+        );
+        assert_eq!(transport.submitted().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fill_the_gap_of_a_transaction_never_committed() {
+        let (transport, pipeline, mut results) = mock_pipeline(0, 1).await;
+        transport.push_outcome(MockOutcome::Unconfirmed { committed: false });
+        transport.push_outcome(MockOutcome::Unconfirmed { committed: false });
+        let id = pipeline.submit(cancel(1)).await.unwrap();
+        let result = results.recv().await.unwrap();
+        assert!(result.result.is_err());
+        assert_eq!(
+            pipeline.status(id),
+            Some(PipelineStatus::GapFilled { sequence_number: 0 })
+        );
+
+        let submitted = transport.submitted();
+        assert_eq!(submitted.len(), 3);
+        assert!(submitted.iter().all(|tx| tx.sequence_number() == 0));
+        let entries = transport.submitted_entry_functions();
+        assert_eq!(entries[1].function().as_str(), "cancel_order_user");
+        assert_eq!(entries[2].module().name().as_str(), "aptos_account");
+        assert_eq!(entries[2].function().as_str(), "transfer");
+    }
+
+    #[tokio::test]
+    async fn fail_on_a_sequence_number_used_outside() {
+        let (transport, pipeline, mut results) = mock_pipeline(30, 1).await;
+        transport.push_outcome(MockOutcome::Rejected(api_error(
+            400,
+            AptosErrorCode::SequenceNumberTooOld,
+        )));
+        let id = pipeline.submit(cancel(1)).await.unwrap();
+        let result = results.recv().await.unwrap();
+        assert!(result.result.is_err());
+        assert_eq!(
+            pipeline.status(id),
+            Some(PipelineStatus::Failed { sequence_number: 0 })
+        );
+        assert!(transport.submitted().is_empty());
+    }
+}
@@ -0,0 +1,434 @@
+//! The [`quote`](crate::quote) module exposes a [`QuoteEngine`] which estimates the fill of a
+//! taker order from a snapshot of the price levels of a market.
+//!
+//! The engine mirrors the matching loop of the Econia `market` module: the maximum amounts to
+//! trade are converted to lots and ticks, the taker fee is reserved out of the maximum quote, and
+//! levels are consumed from the best price until the order is filled, the limit price is
+//! violated or the book runs out of liquidity.
+//!
+//! Quotes are estimates. The book may change before the order is executed, self matches are not
+//! simulated as price levels do not tell which users the orders belong to, and the taker fee is
+//! computed on the total fill while the chain computes it per maker order, so the actual fee may be
+//! lower by a few subunits.
+
+use econia_types::order::{Side, HI_64, HI_PRICE};
+
+use crate::errors::{IncentivesError, MarketError, RegistryError};
+use crate::view::{EconiaViewClient, MarketInfoView, PriceLevel, PriceLevels};
+use crate::EconiaResult;
+
+/// Minimum taker fee divisor, as enforced by the `incentives` module.
+const MIN_DIVISOR: u64 = 2;
+
+/// Estimated fill of a taker order, amounts are in subunits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quote {
+    /// Direction of the taker order, [`Side::Ask`] to sell and [`Side::Bid`] to buy.
+    pub direction: Side,
+    /// Base received by the taker.
+    pub base_in: u64,
+    /// Base paid by the taker.
+    pub base_out: u64,
+    /// Quote received by the taker, net of fees.
+    pub quote_in: u64,
+    /// Quote paid by the taker, fees included.
+    pub quote_out: u64,
+    /// Quote exchanged with the makers, before fees.
+    pub quote_filled: u64,
+    /// Taker fees paid.
+    pub fees: u64,
+    /// Price levels filled against, best first. Sizes are the lots filled at each level.
+    pub levels: Vec<PriceLevel>,
+    /// Whether matching stopped because the next level violated the limit price.
+    pub violated_limit_price: bool,
+    /// Whether every level of the opposite side was consumed.
+    pub liquidity_gone: bool,
+}
+
+impl Quote {
+    /// Amount of price levels filled against.
+    pub fn levels_consumed(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Lots filled.
+    pub fn lots_filled(&self) -> u64 {
+        self.levels.iter().map(|l| l.size as u64).sum()
+    }
+
+    /// Ticks filled.
+    pub fn ticks_filled(&self) -> u64 {
+        self.levels.iter().map(|l| l.size as u64 * l.price).sum()
+    }
+
+    /// Best price filled at, in ticks per lot.
+    pub fn best_price(&self) -> Option<u64> {
+        self.levels.first().map(|l| l.price)
+    }
+
+    /// Worst price filled at, in ticks per lot.
+    pub fn worst_price(&self) -> Option<u64> {
+        self.levels.last().map(|l| l.price)
+    }
+
+    /// Average price filled at, in ticks per lot, before fees.
+    pub fn average_price(&self) -> Option<f64> {
+        match self.lots_filled() {
+            0 => None,
+            lots => Some(self.ticks_filled() as f64 / lots as f64),
+        }
+    }
+
+    /// Relative difference between the average price and the best price, positive when the
+    /// average price is worse for the taker.
+    pub fn slippage(&self) -> Option<f64> {
+        let best = self.best_price()? as f64;
+        let average = self.average_price()?;
+        Some(match self.direction {
+            Side::Bid => (average - best) / best,
+            Side::Ask => (best - average) / best,
+        })
+    }
+}
+
+/// Quotes taker orders against a snapshot of price levels, see the
+/// [module documentation](crate::quote).
+#[derive(Debug, Clone)]
+pub struct QuoteEngine {
+    lot_size: u64,
+    tick_size: u64,
+    taker_fee_divisor: u64,
+    /// Asks, best (lowest) first.
+    asks: Vec<PriceLevel>,
+    /// Bids, best (highest) first.
+    bids: Vec<PriceLevel>,
+}
+
+impl QuoteEngine {
+    /// Returns the error the chain would abort with if the lot size, the tick size, the taker fee
+    /// divisor or the price of a level could not have been set on chain.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market` - [`MarketInfoView`] of the market, as returned by `get_market_info`.
+    /// * `levels` - Snapshot of the [`PriceLevels`] of the market.
+    /// * `taker_fee_divisor` - Taker fee divisor, as returned by `get_taker_fee_divisor`.
+    pub fn new(
+        market: &MarketInfoView,
+        levels: &PriceLevels,
+        taker_fee_divisor: u64,
+    ) -> EconiaResult<Self> {
+        if market.lot_size == 0 {
+            return Err(RegistryError::LotSizeZero.into());
+        }
+        if market.tick_size == 0 {
+            return Err(RegistryError::TickSizeZero.into());
+        }
+        if taker_fee_divisor < MIN_DIVISOR {
+            return Err(IncentivesError::TakerDivisorLessThanMin.into());
+        }
+        if levels.asks.iter().chain(&levels.bids).any(|l| l.price == 0) {
+            return Err(MarketError::PriceZero.into());
+        }
+        let mut asks = levels.asks.clone();
+        let mut bids = levels.bids.clone();
+        asks.sort_by_key(|l| l.price);
+        bids.sort_by_key(|l| std::cmp::Reverse(l.price));
+        Ok(Self {
+            lot_size: market.lot_size,
+            tick_size: market.tick_size,
+            taker_fee_divisor,
+            asks,
+            bids,
+        })
+    }
+
+    /// Fetch the market info, every price level and the taker fee divisor of a market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `view_client` - [`EconiaViewClient`] to fetch the market data with.
+    /// * `market_id` - Market ID of the market.
+    pub async fn load(view_client: &EconiaViewClient<'_>, market_id: u64) -> EconiaResult<Self> {
+        let (market, levels, taker_fee_divisor) = futures::try_join!(
+            view_client.get_market_info(market_id),
+            view_client.get_price_levels_all(market_id),
+            view_client.get_taker_fee_divisor(),
+        )?;
+        Self::new(&market, &levels, taker_fee_divisor)
+    }
+
+    /// Quote a taker order, as matched by `swap_between_coinstores_entry` once its maximum
+    /// amounts have been resolved.
+    ///
+    /// Returns the error the chain would abort with if the limit price is too high or if the
+    /// minimum amounts would not be traded.
+    ///
+    /// # Arguments:
+    ///
+    /// * `direction` - Order direction, [`Side::Ask`] to sell and [`Side::Bid`] to buy.
+    /// * `min_base` - Minimum base to trade, in subunits.
+    /// * `max_base` - Maximum base to trade, in subunits.
+    /// * `min_quote` - Minimum quote to trade, in subunits, fees included.
+    /// * `max_quote` - Maximum quote to trade, in subunits, fees included.
+    /// * `limit_price` - Worst price to fill at, in ticks per lot.
+    pub fn quote(
+        &self,
+        direction: Side,
+        min_base: u64,
+        max_base: u64,
+        min_quote: u64,
+        max_quote: u64,
+        limit_price: u64,
+    ) -> EconiaResult<Quote> {
+        if limit_price > HI_PRICE {
+            return Err(MarketError::PriceTooHigh.into());
+        }
+        let max_quote_match = self.max_quote_match(direction, max_quote);
+        let (max_lots, max_ticks) = (max_base / self.lot_size, max_quote_match / self.tick_size);
+        let (mut lots_until_max, mut ticks_until_max) = (max_lots, max_ticks);
+        let book = match direction {
+            Side::Bid => &self.asks,
+            Side::Ask => &self.bids,
+        };
+
+        let mut levels = vec![];
+        let mut complete_fills = 0;
+        let mut violated_limit_price = false;
+        for level in book {
+            let price = level.price;
+            if (direction == Side::Bid && price > limit_price)
+                || (direction == Side::Ask && price < limit_price)
+            {
+                violated_limit_price = true;
+                break;
+            }
+            let max_fill_size = (ticks_until_max / price).min(lots_until_max);
+            let complete_fill = max_fill_size as u128 >= level.size;
+            let fill_size = if complete_fill {
+                level.size as u64
+            } else {
+                max_fill_size
+            };
+            if fill_size == 0 {
+                break;
+            }
+            lots_until_max -= fill_size;
+            ticks_until_max -= fill_size * price;
+            levels.push(PriceLevel {
+                price,
+                size: fill_size as u128,
+            });
+            if !complete_fill {
+                break;
+            }
+            complete_fills += 1;
+            if lots_until_max == 0 || ticks_until_max == 0 {
+                break;
+            }
+        }
+
+        let base_fill = (max_lots - lots_until_max) * self.lot_size;
+        let quote_fill = (max_ticks - ticks_until_max) * self.tick_size;
+        let fees = quote_fill / self.taker_fee_divisor;
+        let quote_traded = match direction {
+            Side::Bid => quote_fill + fees,
+            Side::Ask => quote_fill - fees,
+        };
+        if base_fill < min_base {
+            return Err(MarketError::MinBaseNotTraded.into());
+        }
+        if quote_traded < min_quote {
+            return Err(MarketError::MinQuoteNotTraded.into());
+        }
+
+        let (base_in, base_out, quote_in, quote_out) = match direction {
+            Side::Bid => (base_fill, 0, 0, quote_traded),
+            Side::Ask => (0, base_fill, quote_traded, 0),
+        };
+        Ok(Quote {
+            direction,
+            base_in,
+            base_out,
+            quote_in,
+            quote_out,
+            quote_filled: quote_fill,
+            fees,
+            levels,
+            violated_limit_price,
+            liquidity_gone: complete_fills == book.len(),
+        })
+    }
+
+    /// Quote a market order, as placed by `place_market_order_user_entry`.
+    ///
+    /// The market account is assumed to have enough quote available for a buy, and enough room
+    /// under its quote ceiling for a sell.
+    ///
+    /// # Arguments:
+    ///
+    /// * `direction` - Order direction, [`Side::Ask`] to sell and [`Side::Bid`] to buy.
+    /// * `size` - Size of the order in lots.
+    pub fn quote_market_order(&self, direction: Side, size: u64) -> EconiaResult<Quote> {
+        let max_base = size.saturating_mul(self.lot_size);
+        let limit_price = match direction {
+            Side::Bid => HI_PRICE,
+            Side::Ask => 0,
+        };
+        self.quote(direction, 0, max_base, 0, HI_64, limit_price)
+    }
+
+    /// Mirror of `incentives::calculate_max_quote_match`, the maximum quote to match so that the
+    /// quote traded, fees included, does not exceed `max_quote`.
+    fn max_quote_match(&self, direction: Side, max_quote: u64) -> u64 {
+        let numerator = self.taker_fee_divisor as u128 * max_quote as u128;
+        let denominator = match direction {
+            Side::Bid => self.taker_fee_divisor as u128 + 1,
+            Side::Ask => self.taker_fee_divisor as u128 - 1,
+        };
+        (numerator / denominator).min(HI_64 as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aptos_sdk::move_types::account_address::AccountAddress;
+
+    use super::*;
+    use crate::errors::EconiaError;
+    use crate::view::AssetTypeView;
+
+    // Parameters of the `market` module tests.
+    const LOT_SIZE_COIN: u64 = 2;
+    const TICK_SIZE_COIN: u64 = 3;
+    const MIN_SIZE_COIN: u64 = 4;
+    const TAKER_FEE_DIVISOR: u64 = 2000;
+    const FEE_SHARE_DIVISOR_0: u64 = 10000;
+
+    fn market() -> MarketInfoView {
+        let asset = |type_name: &str| AssetTypeView {
+            package_address: AccountAddress::ONE,
+            module_name: "assets".to_string(),
+            type_name: type_name.to_string(),
+        };
+        MarketInfoView {
+            market_id: 1,
+            is_recognized: true,
+            base_type: asset("BC"),
+            base_name_generic: String::new(),
+            quote_type: asset("QC"),
+            lot_size: LOT_SIZE_COIN,
+            tick_size: TICK_SIZE_COIN,
+            min_size: MIN_SIZE_COIN,
+            underwriter_id: 0,
+        }
+    }
+
+    fn levels(asks: &[(u64, u128)], bids: &[(u64, u128)]) -> PriceLevels {
+        let levels = |levels: &[(u64, u128)]| {
+            levels
+                .iter()
+                .map(|(price, size)| PriceLevel {
+                    price: *price,
+                    size: *size,
+                })
+                .collect()
+        };
+        PriceLevels {
+            market_id: 1,
+            asks: levels(asks),
+            bids: levels(bids),
+        }
+    }
+
+    /// `market::test_match_loop_twice`.
+    #[test]
+    fn match_loop_twice() {
+        let price_lo = FEE_SHARE_DIVISOR_0 * TAKER_FEE_DIVISOR;
+        let price_hi = price_lo * 2;
+        let (size_maker_hi, size_maker_lo) = (MIN_SIZE_COIN + 123, MIN_SIZE_COIN + 321);
+        let levels = levels(
+            &[],
+            &[
+                (price_lo, size_maker_lo as u128),
+                (price_hi, size_maker_hi as u128),
+            ],
+        );
+        let engine = QuoteEngine::new(&market(), &levels, TAKER_FEE_DIVISOR).unwrap();
+
+        let size_taker_lo = size_maker_lo - MIN_SIZE_COIN;
+        let base_trade = (size_maker_hi + size_taker_lo) * LOT_SIZE_COIN;
+        let quote_taker = (size_maker_hi * price_hi + size_taker_lo * price_lo) * TICK_SIZE_COIN;
+        let fee = quote_taker / TAKER_FEE_DIVISOR;
+        let quote_trade = quote_taker - fee;
+        let quote = engine
+            .quote(Side::Ask, 0, base_trade, 0, quote_trade * 2, price_lo)
+            .unwrap();
+        assert_eq!(quote.base_out, base_trade);
+        assert_eq!(quote.quote_in, quote_trade);
+        assert_eq!(quote.quote_filled, quote_taker);
+        assert_eq!(quote.fees, fee);
+        assert_eq!(quote.levels.len(), 2);
+        assert_eq!(quote.levels[1].size, size_taker_lo as u128);
+        assert!(!quote.liquidity_gone);
+    }
+
+    /// `market::test_match_partial_fill_tick_limited_buy`.
+    #[test]
+    fn match_partial_fill_tick_limited_buy() {
+        let price = FEE_SHARE_DIVISOR_0 * TAKER_FEE_DIVISOR;
+        let (size_maker, size_taker) = (MIN_SIZE_COIN + 10, 5);
+        let levels = levels(&[(price, size_maker as u128)], &[]);
+        let engine = QuoteEngine::new(&market(), &levels, TAKER_FEE_DIVISOR).unwrap();
+
+        let quote_taker = size_taker * price * TICK_SIZE_COIN;
+        let fee = quote_taker / TAKER_FEE_DIVISOR;
+        let quote_trade = quote_taker + fee;
+        let max_base = (size_taker + 2) * LOT_SIZE_COIN;
+        let quote = engine
+            .quote(Side::Bid, 0, max_base, 0, quote_trade, price)
+            .unwrap();
+        assert_eq!(quote.base_in, size_taker * LOT_SIZE_COIN);
+        assert_eq!(quote.quote_out, quote_trade);
+        assert_eq!(quote.fees, fee);
+        assert_eq!(quote.lots_filled(), size_taker);
+    }
+
+    /// `market::test_match_min_base_not_traded` and `market::test_match_min_quote_not_traded`.
+    #[test]
+    fn match_min_not_traded() {
+        let engine = QuoteEngine::new(&market(), &levels(&[], &[]), TAKER_FEE_DIVISOR).unwrap();
+        assert!(matches!(
+            engine.quote(Side::Bid, 1, HI_64, 0, HI_64, 1),
+            Err(EconiaError::MarketError(MarketError::MinBaseNotTraded))
+        ));
+        assert!(matches!(
+            engine.quote(Side::Ask, 0, HI_64, 1, HI_64, 1),
+            Err(EconiaError::MarketError(MarketError::MinQuoteNotTraded))
+        ));
+    }
+
+    #[test]
+    fn reject_invalid_inputs() {
+        let book = levels(&[(1, 1)], &[]);
+        for divisor in [0, 1] {
+            assert!(matches!(
+                QuoteEngine::new(&market(), &book, divisor),
+                Err(EconiaError::IncentivesError(
+                    IncentivesError::TakerDivisorLessThanMin
+                ))
+            ));
+        }
+        let zero_price = levels(&[], &[(0, 1)]);
+        assert!(matches!(
+            QuoteEngine::new(&market(), &zero_price, TAKER_FEE_DIVISOR),
+            Err(EconiaError::MarketError(MarketError::PriceZero))
+        ));
+        let mut market = market();
+        market.tick_size = 0;
+        assert!(matches!(
+            QuoteEngine::new(&market, &book, TAKER_FEE_DIVISOR),
+            Err(EconiaError::RegistryError(RegistryError::TickSizeZero))
+        ));
+    }
+}
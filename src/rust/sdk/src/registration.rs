@@ -0,0 +1,493 @@
+//! The [`registration`](crate::registration) module registers a market and gets it ready for
+//! trading in a single call, with [`EconiaClient::register_market`].
+//!
+//! The workflow looks the market up by its [`MarketRegistration`] parameters and only submits the
+//! steps that are missing, in order:
+//!
+//! 1. Register the market, paying the registration fee in utility coins, see
+//!    [`EconiaViewClient::get_market_registration_fee`](crate::view::EconiaViewClient::get_market_registration_fee).
+//! 2. If [`RegistrationOptions::recognize`] is set, recognize the market. Only the Econia account
+//!    can do so, and recognizing a market replaces the one previously recognized for the same
+//!    trading pair.
+//! 3. If [`RegistrationOptions::init_event_handles`] is set, initialize the market account event
+//!    handles of the user on the market, registering the market account first if needed, see the
+//!    [`onboarding`](crate::onboarding) module. The event handles of the market itself are created
+//!    on chain by its first swap, there is no entry function to create them ahead of time.
+//!
+//! With [`RegistrationOptions::dry_run`] set, nothing is submitted: the steps which would be are
+//! listed in the report, and simulated when they do not depend on an earlier one.
+
+use aptos_sdk::move_types::language_storage::TypeTag;
+
+use crate::accounts::NO_CUSTODIAN;
+use crate::errors::EconiaError;
+use crate::onboarding::{MarketAccountReport, MarketDeposits};
+use crate::types::events::EconiaEvent;
+use crate::view::MarketInfoView;
+use crate::{entry, EconiaClient, EconiaResult, EconiaSimulation, EconiaTransaction};
+
+/// Parameters of a market with a coin base asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketRegistration {
+    pub base: TypeTag,
+    pub quote: TypeTag,
+    /// Utility coin the registration fee is paid in.
+    pub utility_coin: TypeTag,
+    pub lot_size: u64,
+    pub tick_size: u64,
+    pub min_size: u64,
+}
+
+/// Options of [`EconiaClient::register_market`], nothing but the registration by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegistrationOptions {
+    /// Recognize the market, the client must be the one of the Econia account.
+    pub recognize: bool,
+    /// Initialize the market account event handles of the user on the market.
+    pub init_event_handles: bool,
+    /// Only simulate the steps instead of submitting them.
+    pub dry_run: bool,
+}
+
+/// A step of the [`registration`](crate::registration) workflow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrationStep {
+    RegisterMarket,
+    RecognizeMarket,
+    InitEventHandles,
+}
+
+/// Report of [`EconiaClient::register_market`].
+#[derive(Debug, Clone)]
+pub struct MarketRegistrationReport {
+    /// Market ID of the market, the one it would be registered with for a dry run.
+    pub market_id: u64,
+    /// Market as resolved on chain once the workflow ran, `None` for a dry run of a market which
+    /// is not registered yet.
+    pub market: Option<MarketInfoView>,
+    /// Steps which were already done before the workflow ran.
+    pub skipped: Vec<RegistrationStep>,
+    /// Steps submitted by the workflow, in order, along with their transaction.
+    pub submitted: Vec<(RegistrationStep, EconiaTransaction)>,
+    /// Steps a dry run would have submitted, in order.
+    pub planned: Vec<RegistrationStep>,
+    /// Simulation of the planned steps which could be simulated.
+    pub simulations: Vec<(RegistrationStep, EconiaSimulation)>,
+    /// Report of the onboarding of the market account, if its event handles were initialized. Its
+    /// transactions are not listed in `submitted`.
+    pub market_account: Option<MarketAccountReport>,
+}
+
+impl MarketRegistrationReport {
+    /// Whether the market was already registered and set up, no step being needed.
+    pub fn was_ready(&self) -> bool {
+        self.submitted.is_empty()
+            && self.planned.is_empty()
+            && self.market_account.as_ref().map_or(true, |a| a.was_ready())
+    }
+}
+
+/// Market ID of the market registered by a transaction, from its events.
+fn registered_market_id(events: &[EconiaEvent]) -> Option<u64> {
+    events.iter().find_map(|e| match e {
+        EconiaEvent::MarketRegistration(e) => Some(e.market_id),
+        _ => None,
+    })
+}
+
+impl EconiaClient {
+    /// Register a market if missing and get it ready for trading, see the
+    /// [`registration`](crate::registration) module.
+    ///
+    /// Steps are submitted one after the other and the first failure is returned. The steps
+    /// submitted before it are committed, run the workflow again to complete the remaining ones.
+    ///
+    /// # Arguments:
+    ///
+    /// * `market` - [`MarketRegistration`] parameters of the market.
+    /// * `options` - [`RegistrationOptions`] of the workflow.
+    pub async fn register_market(
+        &self,
+        market: &MarketRegistration,
+        options: RegistrationOptions,
+    ) -> EconiaResult<MarketRegistrationReport> {
+        let view_client = self.view_client();
+        if options.recognize && self.user_address() != self.econia_address {
+            return Err(EconiaError::MarketRegistrationError(format!(
+                "only the Econia account `{}` can recognize a market",
+                self.econia_address.to_hex_literal()
+            )));
+        }
+        let existing = view_client
+            .get_market_id_base_coin(
+                (&market.base).into(),
+                (&market.quote).into(),
+                market.lot_size,
+                market.tick_size,
+                market.min_size,
+            )
+            .await?;
+        let mut report = MarketRegistrationReport {
+            market_id: 0,
+            market: None,
+            skipped: vec![],
+            submitted: vec![],
+            planned: vec![],
+            simulations: vec![],
+            market_account: None,
+        };
+
+        let mut registered = true;
+        report.market_id = match existing {
+            Some(market_id) => {
+                report.skipped.push(RegistrationStep::RegisterMarket);
+                market_id
+            }
+            None => {
+                if !view_client
+                    .is_utility_coin_type((&market.utility_coin).into())
+                    .await?
+                {
+                    return Err(EconiaError::MarketRegistrationError(format!(
+                        "`{}` is not the utility coin",
+                        market.utility_coin
+                    )));
+                }
+                let entry = entry::register_market_base_coin_from_coinstore(
+                    self.econia_address,
+                    &market.base,
+                    &market.quote,
+                    &market.utility_coin,
+                    market.lot_size,
+                    market.tick_size,
+                    market.min_size,
+                )?;
+                let market_id = if options.dry_run {
+                    registered = false;
+                    let simulation = self.simulate_tx(entry).await?;
+                    let market_id = registered_market_id(&simulation.events);
+                    report.planned.push(RegistrationStep::RegisterMarket);
+                    report
+                        .simulations
+                        .push((RegistrationStep::RegisterMarket, simulation));
+                    market_id
+                } else {
+                    let transaction = self.submit_tx(entry).await?;
+                    let market_id = registered_market_id(&transaction.events);
+                    report
+                        .submitted
+                        .push((RegistrationStep::RegisterMarket, transaction));
+                    market_id
+                };
+                market_id.ok_or_else(|| {
+                    EconiaError::MarketRegistrationError(
+                        "the registration emitted no market registration event".to_string(),
+                    )
+                })?
+            }
+        };
+        let market_id = report.market_id;
+        let info = if registered {
+            Some(view_client.get_market_info(market_id).await?)
+        } else {
+            None
+        };
+
+        if options.recognize {
+            if info.as_ref().is_some_and(|i| i.is_recognized) {
+                report.skipped.push(RegistrationStep::RecognizeMarket);
+            } else {
+                let entry = entry::set_recognized_market(self.econia_address, market_id)?;
+                if options.dry_run {
+                    report.planned.push(RegistrationStep::RecognizeMarket);
+                    // A market which is not registered yet cannot be recognized, even simulated.
+                    if registered {
+                        let simulation = self.simulate_tx(entry).await?;
+                        report
+                            .simulations
+                            .push((RegistrationStep::RecognizeMarket, simulation));
+                    }
+                } else {
+                    let transaction = self.submit_tx(entry).await?;
+                    report
+                        .submitted
+                        .push((RegistrationStep::RecognizeMarket, transaction));
+                }
+            }
+        }
+
+        if options.init_event_handles {
+            let has_handles = registered
+                && view_client
+                    .get_market_event_handle_creation_numbers(
+                        self.user_address(),
+                        market_id,
+                        NO_CUSTODIAN,
+                    )
+                    .await?
+                    .is_some();
+            if has_handles {
+                report.skipped.push(RegistrationStep::InitEventHandles);
+            } else if options.dry_run {
+                report.planned.push(RegistrationStep::InitEventHandles);
+            } else {
+                let account = self
+                    .prepare_market_account(market_id, MarketDeposits::default())
+                    .await?;
+                report.market_account = Some(account);
+            }
+        }
+
+        report.market = if registered && !options.dry_run {
+            Some(view_client.get_market_info(market_id).await?)
+        } else {
+            info
+        };
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use aptos_sdk::bcs;
+    use aptos_sdk::crypto::ed25519::Ed25519PrivateKey;
+    use aptos_sdk::types::account_address::AccountAddress;
+    use aptos_sdk::types::{AccountKey, LocalAccount};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::generated::view;
+    use crate::tests::{mock_client, ECONIA};
+    use crate::transport::{MockOutcome, MockTransport};
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn coin(type_tag: &str) -> TypeTag {
+        TypeTag::from_str(type_tag).unwrap()
+    }
+
+    fn registration() -> MarketRegistration {
+        MarketRegistration {
+            base: coin("0x1::assets::BC"),
+            quote: coin("0x1::assets::QC"),
+            utility_coin: coin("0x1::assets::UC"),
+            lot_size: 10,
+            tick_size: 1,
+            min_size: 1,
+        }
+    }
+
+    /// Client of the Econia account, on a [`MockTransport`] of chain 4.
+    async fn econia_client() -> (Arc<MockTransport>, EconiaClient) {
+        let transport = Arc::new(MockTransport::new(4));
+        let private_key = Ed25519PrivateKey::try_from(&[1u8; 32][..]).unwrap();
+        let account = LocalAccount::new(econia(), AccountKey::from(private_key), 0);
+        let client =
+            EconiaClient::connect_with_transport(transport.clone(), econia(), account, None)
+                .await
+                .unwrap();
+        (transport, client)
+    }
+
+    fn type_info(struct_name: &str) -> Value {
+        json!({
+            "account_address": "0x1",
+            "module_name": "assets",
+            "struct_name": struct_name,
+        })
+    }
+
+    /// Serve the market ID of the market of [`registration`], `None` if not registered yet, and
+    /// the info of `market_id` once registered.
+    fn serve_market(transport: &MockTransport, registered: Option<u64>, market_id: u64) {
+        let market = registration();
+        let request = view::registry::get_market_id_base_coin(
+            econia(),
+            &market.base,
+            &market.quote,
+            market.lot_size,
+            market.tick_size,
+            market.min_size,
+        )
+        .unwrap();
+        let ids = registered
+            .map(|id| id.to_string())
+            .into_iter()
+            .collect::<Vec<_>>();
+        transport
+            .push_view(&request, vec![json!({ "vec": ids })])
+            .unwrap();
+        let request =
+            view::incentives::is_utility_coin_type(econia(), &market.utility_coin).unwrap();
+        transport.push_view(&request, vec![json!(true)]).unwrap();
+        let asset = |type_name: &str| {
+            json!({
+                "package_address": "0x1",
+                "module_name": "assets",
+                "type_name": type_name,
+            })
+        };
+        transport
+            .push_view(
+                &view::registry::get_market_info(econia(), market_id).unwrap(),
+                vec![json!({
+                    "market_id": market_id.to_string(),
+                    "is_recognized": false,
+                    "base_type": asset("BC"),
+                    "base_name_generic": "",
+                    "quote_type": asset("QC"),
+                    "lot_size": "10",
+                    "tick_size": "1",
+                    "min_size": "1",
+                    "underwriter_id": "0",
+                })],
+            )
+            .unwrap();
+    }
+
+    /// Outcome of the registration of a market, emitting its registration event.
+    fn registered(market_id: u64) -> MockOutcome {
+        MockOutcome::Committed(vec![(
+            format!("{}::registry::MarketRegistrationEvent", ECONIA),
+            json!({
+                "market_id": market_id.to_string(),
+                "base_type": type_info("BC"),
+                "base_name_generic": "",
+                "quote_type": type_info("QC"),
+                "lot_size": "10",
+                "tick_size": "1",
+                "min_size": "1",
+                "underwriter_id": "0",
+            }),
+        )])
+    }
+
+    fn functions(transport: &MockTransport) -> Vec<String> {
+        transport
+            .submitted_entry_functions()
+            .iter()
+            .map(|entry| entry.function().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn skip_registered_markets() {
+        let (transport, client) = mock_client().await;
+        serve_market(&transport, Some(7), 7);
+
+        let report = client
+            .register_market(&registration(), RegistrationOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(report.market_id, 7);
+        assert_eq!(report.skipped, [RegistrationStep::RegisterMarket]);
+        assert_eq!(report.market.unwrap().market_id, 7);
+        assert!(report.was_ready());
+        assert!(transport.submitted().is_empty());
+    }
+
+    #[tokio::test]
+    async fn register_and_recognize() {
+        let (transport, client) = econia_client().await;
+        serve_market(&transport, None, 9);
+        transport.push_outcome(registered(9));
+        let options = RegistrationOptions {
+            recognize: true,
+            ..Default::default()
+        };
+
+        // The market ID is the one of the registration event.
+        let report = client
+            .register_market(&registration(), options)
+            .await
+            .unwrap();
+        assert_eq!(report.market_id, 9);
+        let steps = report.submitted.iter().map(|(s, _)| *s).collect::<Vec<_>>();
+        assert_eq!(
+            steps,
+            [
+                RegistrationStep::RegisterMarket,
+                RegistrationStep::RecognizeMarket
+            ]
+        );
+        assert_eq!(
+            functions(&transport),
+            [
+                "register_market_base_coin_from_coinstore",
+                "set_recognized_market"
+            ]
+        );
+        let recognize = &transport.submitted_entry_functions()[1];
+        assert_eq!(recognize.args()[0], bcs::to_bytes(&9u64).unwrap());
+    }
+
+    #[tokio::test]
+    async fn reject_registrations_without_event() {
+        let (transport, client) = econia_client().await;
+        serve_market(&transport, None, 9);
+        assert!(matches!(
+            client
+                .register_market(&registration(), RegistrationOptions::default())
+                .await,
+            Err(EconiaError::MarketRegistrationError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn dry_run_plans_and_simulates() {
+        let (transport, client) = econia_client().await;
+        serve_market(&transport, None, 9);
+        // Simulations follow the next outcome without using it.
+        transport.push_outcome(registered(9));
+        let options = RegistrationOptions {
+            recognize: true,
+            init_event_handles: true,
+            dry_run: true,
+        };
+
+        let report = client
+            .register_market(&registration(), options)
+            .await
+            .unwrap();
+        assert_eq!(report.market_id, 9);
+        assert!(report.market.is_none());
+        assert_eq!(
+            report.planned,
+            [
+                RegistrationStep::RegisterMarket,
+                RegistrationStep::RecognizeMarket,
+                RegistrationStep::InitEventHandles
+            ]
+        );
+        // Recognizing a market which is not registered yet cannot be simulated.
+        let simulated = report
+            .simulations
+            .iter()
+            .map(|(s, _)| *s)
+            .collect::<Vec<_>>();
+        assert_eq!(simulated, [RegistrationStep::RegisterMarket]);
+        assert!(report.submitted.is_empty());
+        assert!(!report.was_ready());
+        assert!(transport.submitted().is_empty());
+    }
+
+    #[tokio::test]
+    async fn only_econia_recognizes() {
+        let (transport, client) = mock_client().await;
+        serve_market(&transport, Some(7), 7);
+        let options = RegistrationOptions {
+            recognize: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            client.register_market(&registration(), options).await,
+            Err(EconiaError::MarketRegistrationError(_))
+        ));
+        assert!(transport.submitted().is_empty());
+    }
+}
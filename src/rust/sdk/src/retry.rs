@@ -0,0 +1,334 @@
+//! The [`retry`](crate::retry) module exposes the [`RetryPolicy`] used by
+//! [`EconiaClient::submit_tx`](crate::EconiaClient::submit_tx) to resubmit failed transactions.
+//!
+//! Every failure is sorted into a [`RetryClass`] by an [`ErrorClassifier`]:
+//!
+//! * [`RetryClass::Permanent`] failures are returned immediately. These include Move aborts,
+//!   which would abort again, and transactions whose outcome is unknown, e.g. when waiting for
+//!   them timed out, as resubmitting them could execute them twice. Those fail with an
+//!   [`EconiaError::TransactionOutcomeUnknown`].
+//! * [`RetryClass::Transient`] failures, such as network errors or a full mempool, are retried
+//!   after an exponential backoff with jitter.
+//! * [`RetryClass::Resign`] failures, such as expired transactions or stale sequence numbers, are
+//!   retried immediately, signed with the sequence number of the account fetched from the chain.
+//!
+//! [`RetryHook`]s are notified of every failed attempt and of every success, e.g. to log them or
+//! record metrics.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use aptos_api_types::AptosErrorCode;
+use aptos_sdk::move_types::vm_status::StatusCode;
+use aptos_sdk::rest_client::error::RestError;
+
+use crate::errors::EconiaError;
+use crate::{EconiaResult, SUBMIT_ATTEMPTS};
+
+pub const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+pub const MAX_BACKOFF: Duration = Duration::from_secs(10);
+pub const BACKOFF_MULTIPLIER: f64 = 2.0;
+pub const BACKOFF_JITTER: f64 = 0.5;
+
+/// How a failed submission is handled, see the [module documentation](crate::retry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryClass {
+    /// Retried after a backoff.
+    Transient,
+    /// Retried immediately with a sequence number fetched from the chain.
+    Resign,
+    /// Not retried.
+    Permanent,
+}
+
+/// Sorts submission failures into [`RetryClass`]es.
+pub trait ErrorClassifier: std::fmt::Debug + Send + Sync {
+    fn classify(&self, error: &EconiaError) -> RetryClass;
+}
+
+/// Notified of the attempts made to submit a transaction.
+pub trait RetryHook: std::fmt::Debug + Send + Sync {
+    /// Called after every failed attempt.
+    fn on_failure(&self, _failure: &RetryFailure<'_>) {}
+
+    /// Called once a transaction is committed, with the amount of attempts it took.
+    fn on_success(&self, _attempts: u8) {}
+}
+
+/// A failed attempt, as reported to [`RetryHook::on_failure`].
+#[derive(Debug)]
+pub struct RetryFailure<'a> {
+    /// Attempt that failed, starting at 1.
+    pub attempt: u8,
+    /// Error the attempt failed with.
+    pub error: &'a EconiaError,
+    /// Class of the error.
+    pub class: RetryClass,
+    /// Delay before the next attempt, `None` if the submission is given up.
+    pub delay: Option<Duration>,
+}
+
+/// The [`ErrorClassifier`] used by default.
+///
+/// * Aborts of the Econia modules and failed executions are permanent.
+/// * Expired transactions, stale or too new sequence numbers and conflicting transactions are
+///   re-signed.
+/// * Full mempools, internal node errors, rate limits, server errors and connection errors are
+///   transient.
+/// * Anything else is permanent, including
+///   [`EconiaError::TransactionOutcomeUnknown`], as the transaction may still be committed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultClassifier;
+
+impl ErrorClassifier for DefaultClassifier {
+    fn classify(&self, error: &EconiaError) -> RetryClass {
+        let EconiaError::AptosError(error) = error else {
+            return RetryClass::Permanent;
+        };
+        match error {
+            RestError::Api(a) => match a.error.error_code {
+                AptosErrorCode::SequenceNumberTooOld | AptosErrorCode::InvalidTransactionUpdate => {
+                    RetryClass::Resign
+                }
+                AptosErrorCode::VmError => match a.error.vm_error_code {
+                    Some(code)
+                        if code == StatusCode::SEQUENCE_NUMBER_TOO_OLD as u64
+                            || code == StatusCode::SEQUENCE_NUMBER_TOO_NEW as u64
+                            || code == StatusCode::TRANSACTION_EXPIRED as u64 =>
+                    {
+                        RetryClass::Resign
+                    }
+                    _ => RetryClass::Permanent,
+                },
+                AptosErrorCode::MempoolIsFull
+                | AptosErrorCode::InternalError
+                | AptosErrorCode::HealthCheckFailed => RetryClass::Transient,
+                _ if a.status_code.is_server_error() || a.status_code.as_u16() == 429 => {
+                    RetryClass::Transient
+                }
+                _ => RetryClass::Permanent,
+            },
+            RestError::Http(..) => RetryClass::Transient,
+            RestError::Unknown(e) if e.downcast_ref::<reqwest::Error>().is_some() => {
+                RetryClass::Transient
+            }
+            // The REST client reports transactions which expired while being waited for with an
+            // untyped error, they are guaranteed not to be committed.
+            RestError::Unknown(e) if e.to_string().contains("Transaction expired") => {
+                RetryClass::Resign
+            }
+            _ => RetryClass::Permanent,
+        }
+    }
+}
+
+/// Configures how failed submissions are retried, see the
+/// [module documentation](crate::retry).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum amount of times to attempt submitting a transaction.
+    pub max_attempts: u8,
+    /// Backoff before the first retry of a transient failure.
+    pub initial_backoff: Duration,
+    /// Maximum backoff between two attempts.
+    pub max_backoff: Duration,
+    /// Factor the backoff is multiplied by after every transient failure.
+    pub multiplier: f64,
+    /// Fraction of the backoff, between 0 and 1, randomly taken off it so that clients failing
+    /// at the same time do not retry at the same time.
+    pub jitter: f64,
+    /// Classifier of the failures.
+    pub classifier: Arc<dyn ErrorClassifier>,
+    /// Hooks notified of every attempt.
+    pub hooks: Vec<Arc<dyn RetryHook>>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: SUBMIT_ATTEMPTS,
+            initial_backoff: INITIAL_BACKOFF,
+            max_backoff: MAX_BACKOFF,
+            multiplier: BACKOFF_MULTIPLIER,
+            jitter: BACKOFF_JITTER,
+            classifier: Arc::new(DefaultClassifier),
+            hooks: vec![],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Replace the classifier of the policy.
+    ///
+    /// # Arguments:
+    ///
+    /// * `classifier` - [`ErrorClassifier`] sorting the failures.
+    pub fn with_classifier(mut self, classifier: impl ErrorClassifier + 'static) -> Self {
+        self.classifier = Arc::new(classifier);
+        self
+    }
+
+    /// Add a hook to the policy.
+    ///
+    /// # Arguments:
+    ///
+    /// * `hook` - [`RetryHook`] to notify of every attempt.
+    pub fn with_hook(mut self, hook: impl RetryHook + 'static) -> Self {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Check the policy can be used by a client: at least one attempt must be made, the
+    /// multiplier must be finite and positive and the jitter between 0 and 1.
+    pub fn validate(&self) -> EconiaResult<()> {
+        if self.max_attempts == 0 {
+            return Err(EconiaError::InvalidClientConfig(
+                "retry policy must make at least one attempt".to_string(),
+            ));
+        }
+        if !self.multiplier.is_finite() || self.multiplier <= 0.0 {
+            return Err(EconiaError::InvalidClientConfig(format!(
+                "retry backoff multiplier must be finite and positive, got: {}",
+                self.multiplier
+            )));
+        }
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err(EconiaError::InvalidClientConfig(format!(
+                "retry backoff jitter must be between 0 and 1, got: {}",
+                self.jitter
+            )));
+        }
+        Ok(())
+    }
+
+    /// Delay before the attempt following the failed `attempt`, `None` if the submission must be
+    /// given up. Backoffs are capped at `max_backoff`, which is also used if the policy is
+    /// invalid.
+    ///
+    /// # Arguments:
+    ///
+    /// * `attempt` - Attempt that failed, starting at 1.
+    /// * `class` - [`RetryClass`] of the failure.
+    pub fn delay(&self, attempt: u8, class: RetryClass) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        match class {
+            RetryClass::Permanent => None,
+            RetryClass::Resign => Some(Duration::ZERO),
+            RetryClass::Transient => {
+                let backoff =
+                    self.initial_backoff.as_secs_f64() * self.multiplier.powi(attempt as i32 - 1);
+                let backoff = backoff.min(self.max_backoff.as_secs_f64());
+                let random = rand::random::<f64>();
+                let jitter = self.jitter.clamp(0.0, 1.0) * random;
+                let delay = Duration::try_from_secs_f64(backoff * (1.0 - jitter))
+                    .map_or(self.max_backoff, |d| d.min(self.max_backoff));
+                Some(delay)
+            }
+        }
+    }
+
+    /// Classify the failure of `attempt`, notify the hooks and return the delay before the next
+    /// attempt along with the class of the failure.
+    pub(crate) fn on_failure(
+        &self,
+        attempt: u8,
+        error: &EconiaError,
+    ) -> (RetryClass, Option<Duration>) {
+        let class = self.classifier.classify(error);
+        let delay = self.delay(attempt, class);
+        let failure = RetryFailure {
+            attempt,
+            error,
+            class,
+            delay,
+        };
+        for hook in &self.hooks {
+            hook.on_failure(&failure);
+        }
+        (class, delay)
+    }
+
+    /// Notify the hooks that a transaction was committed after `attempts` attempts.
+    pub(crate) fn on_success(&self, attempts: u8) {
+        for hook in &self.hooks {
+            hook.on_success(attempts);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_policy() {
+        RetryPolicy::default().validate().unwrap();
+        RetryPolicy::no_retry().validate().unwrap();
+        let invalid = [
+            RetryPolicy {
+                max_attempts: 0,
+                ..Default::default()
+            },
+            RetryPolicy {
+                multiplier: f64::NAN,
+                ..Default::default()
+            },
+            RetryPolicy {
+                multiplier: -2.0,
+                ..Default::default()
+            },
+            RetryPolicy {
+                jitter: 1.5,
+                ..Default::default()
+            },
+        ];
+        for policy in invalid {
+            assert!(matches!(
+                policy.validate(),
+                Err(EconiaError::InvalidClientConfig(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn cap_backoff() {
+        let policy = RetryPolicy {
+            max_attempts: u8::MAX,
+            ..Default::default()
+        };
+        for attempt in 1..u8::MAX {
+            let delay = policy.delay(attempt, RetryClass::Transient).unwrap();
+            assert!(delay <= MAX_BACKOFF);
+        }
+        assert_eq!(policy.delay(1, RetryClass::Resign), Some(Duration::ZERO));
+        assert_eq!(policy.delay(1, RetryClass::Permanent), None);
+        assert_eq!(policy.delay(u8::MAX, RetryClass::Transient), None);
+    }
+
+    #[test]
+    fn fall_back_to_max_backoff() {
+        for multiplier in [f64::NAN, f64::INFINITY, -3.0] {
+            let policy = RetryPolicy {
+                multiplier,
+                jitter: 0.0,
+                ..Default::default()
+            };
+            assert_eq!(
+                policy.delay(2, RetryClass::Transient),
+                Some(MAX_BACKOFF),
+                "{}",
+                multiplier
+            );
+        }
+    }
+}
@@ -0,0 +1,586 @@
+//! The [`signer`](crate::signer) module exposes the [`Signer`] trait, used by
+//! [`EconiaClient`](crate::EconiaClient) to sign the transactions of its user account.
+//!
+//! Three kinds of signers are provided:
+//!
+//! * In memory keys, as a `LocalAccount` from the Aptos SDK.
+//! * Password protected [`Keystore`] files, decrypted into a `LocalAccount`.
+//! * A [`ProcessSigner`], which delegates signing to an external process, so that the private key
+//!   never enters the process using the SDK.
+//!
+//! # External signer protocol
+//!
+//! The external process receives one JSON request per line on its stdin, and must answer each
+//! of them with one JSON response per line on its stdout, in order:
+//!
+//! * `{"method": "account"}` must be answered with
+//!   `{"address": "0x...", "public_key": "0x..."}`, the hex encoded address and Ed25519 public key
+//!   of the account.
+//! * `{"method": "sign", "message": "0x..."}` must be answered with `{"signature": "0x..."}`, the
+//!   hex encoded Ed25519 signature of the hex encoded message.
+//!
+//! Failures are answered with `{"error": "..."}`. A request fails if its response does not
+//! arrive within the timeout of the [`ProcessSigner`].
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use aptos_sdk::crypto::ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature};
+use aptos_sdk::crypto::{
+    signing_message, Signature, SigningKey, ValidCryptoMaterial, ValidCryptoMaterialStringExt,
+};
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::transaction::authenticator::AccountAuthenticator;
+use aptos_sdk::types::transaction::{RawTransaction, RawTransactionWithData, SignedTransaction};
+use aptos_sdk::types::{AccountKey, LocalAccount};
+use async_trait::async_trait;
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::Mutex;
+
+use crate::errors::EconiaError;
+use crate::EconiaResult;
+
+pub const KEYSTORE_VERSION: u8 = 1;
+pub const SCRYPT_LOG_N: u8 = 15;
+pub const SCRYPT_R: u32 = 8;
+pub const SCRYPT_P: u32 = 1;
+/// Default time a [`ProcessSigner`] waits for each response of the external signer.
+pub const PROCESS_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Signs the transactions of an account.
+#[async_trait]
+pub trait Signer: std::fmt::Debug + Send + Sync {
+    /// Address of the account.
+    fn address(&self) -> AccountAddress;
+
+    /// Public key of the account.
+    fn public_key(&self) -> Ed25519PublicKey;
+
+    /// The `LocalAccount` holding the private key, for signers which sign with an in memory key.
+    fn local_account(&self) -> Option<&LocalAccount> {
+        None
+    }
+
+    /// Sign a transaction.
+    async fn sign_transaction(&self, tx: RawTransaction) -> EconiaResult<SignedTransaction>;
+
+    /// Sign a transaction with additional signers, e.g. a fee-payer transaction, either as its
+    /// sender or as one of its additional signers.
+    ///
+    /// Not supported unless implemented.
+    async fn sign_transaction_with_data(
+        &self,
+        _tx: &RawTransactionWithData,
+    ) -> EconiaResult<AccountAuthenticator> {
+        Err(EconiaError::SignerError(
+            "transactions with additional signers are not supported".to_string(),
+        ))
+    }
+}
+
+/// `LocalAccount`s sign with their in memory private key. Their sequence number is not used, as
+/// [`EconiaClient`](crate::EconiaClient) keeps track of it.
+#[async_trait]
+impl Signer for LocalAccount {
+    fn address(&self) -> AccountAddress {
+        LocalAccount::address(self)
+    }
+
+    fn public_key(&self) -> Ed25519PublicKey {
+        LocalAccount::public_key(self).clone()
+    }
+
+    fn local_account(&self) -> Option<&LocalAccount> {
+        Some(self)
+    }
+
+    async fn sign_transaction(&self, tx: RawTransaction) -> EconiaResult<SignedTransaction> {
+        Ok(LocalAccount::sign_transaction(self, tx))
+    }
+
+    async fn sign_transaction_with_data(
+        &self,
+        tx: &RawTransactionWithData,
+    ) -> EconiaResult<AccountAuthenticator> {
+        let signature = self
+            .private_key()
+            .sign_arbitrary_message(&signing_message(tx)?);
+        Ok(AccountAuthenticator::ed25519(
+            Signer::public_key(self),
+            signature,
+        ))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScryptParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    /// Hex encoded salt.
+    pub salt: String,
+}
+
+/// Private key encrypted with a password, stored as JSON.
+///
+/// The encryption key is derived from the password with scrypt, and the private key is encrypted
+/// with XChaCha20-Poly1305, authenticating the address of the account along with it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Keystore {
+    pub version: u8,
+    pub address: AccountAddress,
+    pub scrypt: ScryptParams,
+    /// Hex encoded nonce.
+    pub nonce: String,
+    /// Hex encoded encrypted private key.
+    pub ciphertext: String,
+}
+
+impl Keystore {
+    /// Encrypt the private key of an account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `account` - `LocalAccount` holding the private key to encrypt.
+    /// * `password` - Password to encrypt the private key with.
+    pub fn encrypt(account: &LocalAccount, password: &str) -> EconiaResult<Self> {
+        Self::encrypt_with_log_n(account, password, SCRYPT_LOG_N)
+    }
+
+    fn encrypt_with_log_n(account: &LocalAccount, password: &str, log_n: u8) -> EconiaResult<Self> {
+        let mut salt = [0u8; 32];
+        OsRng.fill_bytes(&mut salt);
+        let scrypt = ScryptParams {
+            log_n,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: hex::encode(salt),
+        };
+        let cipher = Self::cipher(&scrypt, password)?;
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let address = account.address();
+        let ciphertext = cipher
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: &account.private_key().to_bytes(),
+                    aad: address.as_ref(),
+                },
+            )
+            .map_err(|e| EconiaError::KeystoreError(e.to_string()))?;
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address,
+            scrypt,
+            nonce: hex::encode(nonce),
+            ciphertext: hex::encode(ciphertext),
+        })
+    }
+
+    /// Decrypt the private key, returning the account it belongs to.
+    ///
+    /// # Arguments:
+    ///
+    /// * `password` - Password the private key was encrypted with.
+    pub fn decrypt(&self, password: &str) -> EconiaResult<LocalAccount> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(EconiaError::KeystoreError(format!(
+                "unsupported keystore version {}",
+                self.version
+            )));
+        }
+        let cipher = Self::cipher(&self.scrypt, password)?;
+        let nonce = decode_hex(&self.nonce).map_err(EconiaError::KeystoreError)?;
+        if nonce.len() != 24 {
+            return Err(EconiaError::KeystoreError("invalid nonce".to_string()));
+        }
+        let ciphertext = decode_hex(&self.ciphertext).map_err(EconiaError::KeystoreError)?;
+        let private_key = cipher
+            .decrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: self.address.as_ref(),
+                },
+            )
+            .map_err(|_| {
+                EconiaError::KeystoreError("wrong password or corrupted keystore".to_string())
+            })?;
+        let private_key = Ed25519PrivateKey::try_from(&private_key[..])?;
+        Ok(LocalAccount::new(
+            self.address,
+            AccountKey::from(private_key),
+            0,
+        ))
+    }
+
+    /// Read a keystore file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the keystore file.
+    pub fn load(path: impl AsRef<Path>) -> EconiaResult<Self> {
+        let content =
+            fs::read_to_string(path).map_err(|e| EconiaError::KeystoreError(e.to_string()))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Write the keystore to a file.
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the keystore file.
+    pub fn save(&self, path: impl AsRef<Path>) -> EconiaResult<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content).map_err(|e| EconiaError::KeystoreError(e.to_string()))
+    }
+
+    /// Read a keystore file and decrypt it, see [`Keystore::decrypt`].
+    ///
+    /// # Arguments:
+    ///
+    /// * `path` - Path to the keystore file.
+    /// * `password` - Password the private key was encrypted with.
+    pub fn open(path: impl AsRef<Path>, password: &str) -> EconiaResult<LocalAccount> {
+        Self::load(path)?.decrypt(password)
+    }
+
+    fn cipher(params: &ScryptParams, password: &str) -> EconiaResult<XChaCha20Poly1305> {
+        let salt = decode_hex(&params.salt).map_err(EconiaError::KeystoreError)?;
+        let params = scrypt::Params::new(params.log_n, params.r, params.p, 32)
+            .map_err(|e| EconiaError::KeystoreError(e.to_string()))?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key)
+            .map_err(|e| EconiaError::KeystoreError(e.to_string()))?;
+        Ok(XChaCha20Poly1305::new(&key.into()))
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum SignerRequest {
+    Account,
+    Sign { message: String },
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct SignerResponse {
+    address: Option<String>,
+    public_key: Option<String>,
+    signature: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug)]
+struct SignerProcess {
+    // Kept so that the process is killed when the signer is dropped.
+    _child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+/// Delegates signing to an external process, see the [module documentation](crate::signer).
+#[derive(Debug)]
+pub struct ProcessSigner {
+    address: AccountAddress,
+    public_key: Ed25519PublicKey,
+    timeout: Duration,
+    process: Mutex<SignerProcess>,
+}
+
+impl ProcessSigner {
+    /// Spawn the external signer and ask it for its account, waiting up to
+    /// [`PROCESS_SIGNER_TIMEOUT`] for each of its responses.
+    ///
+    /// Its stdin and stdout are piped, its stderr is inherited.
+    ///
+    /// # Arguments:
+    ///
+    /// * `command` - Command running the external signer.
+    pub async fn spawn(command: Command) -> EconiaResult<Self> {
+        Self::spawn_with_timeout(command, PROCESS_SIGNER_TIMEOUT).await
+    }
+
+    /// Spawn the external signer and ask it for its account, see [`ProcessSigner::spawn`].
+    ///
+    /// # Arguments:
+    ///
+    /// * `command` - Command running the external signer.
+    /// * `timeout` - Time to wait for each response of the external signer before failing.
+    pub async fn spawn_with_timeout(mut command: Command, timeout: Duration) -> EconiaResult<Self> {
+        let mut child = command
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| EconiaError::SignerError(e.to_string()))?;
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| EconiaError::SignerError("missing stdin".to_string()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| EconiaError::SignerError("missing stdout".to_string()))?;
+        let mut process = SignerProcess {
+            _child: child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        };
+
+        let response = Self::request(&mut process, &SignerRequest::Account, timeout).await?;
+        let address = response
+            .address
+            .ok_or_else(|| EconiaError::SignerError("missing address".to_string()))?;
+        let public_key = response
+            .public_key
+            .ok_or_else(|| EconiaError::SignerError("missing public key".to_string()))?;
+        Ok(Self {
+            address: AccountAddress::from_hex_literal(&address)?,
+            public_key: Ed25519PublicKey::from_encoded_string(&public_key)?,
+            timeout,
+            process: Mutex::new(process),
+        })
+    }
+
+    async fn request(
+        process: &mut SignerProcess,
+        request: &SignerRequest,
+        timeout: Duration,
+    ) -> EconiaResult<SignerResponse> {
+        let mut line = serde_json::to_string(request)?;
+        line.push('\n');
+        process
+            .stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| EconiaError::SignerError(e.to_string()))?;
+        process
+            .stdin
+            .flush()
+            .await
+            .map_err(|e| EconiaError::SignerError(e.to_string()))?;
+
+        // A signer which never answers would otherwise hold the lock, and every transaction, forever.
+        let mut line = String::new();
+        let read = tokio::time::timeout(timeout, process.stdout.read_line(&mut line))
+            .await
+            .map_err(|_| EconiaError::SignerError("signer timed out".to_string()))?
+            .map_err(|e| EconiaError::SignerError(e.to_string()))?;
+        if read == 0 {
+            return Err(EconiaError::SignerError("signer exited".to_string()));
+        }
+        let response: SignerResponse = serde_json::from_str(&line)?;
+        match response.error {
+            Some(error) => Err(EconiaError::SignerError(error)),
+            None => Ok(response),
+        }
+    }
+
+    async fn sign_message(&self, message: Vec<u8>) -> EconiaResult<Ed25519Signature> {
+        let request = SignerRequest::Sign {
+            message: format!("0x{}", hex::encode(&message)),
+        };
+        let response =
+            Self::request(&mut *self.process.lock().await, &request, self.timeout).await?;
+        let signature = response
+            .signature
+            .ok_or_else(|| EconiaError::SignerError("missing signature".to_string()))?;
+        let signature = decode_hex(&signature).map_err(EconiaError::SignerError)?;
+        let signature = Ed25519Signature::try_from(&signature[..])?;
+        // Catch signers holding another key before the transaction gets rejected by the node.
+        signature
+            .verify_arbitrary_msg(&message, &self.public_key)
+            .map_err(|e| EconiaError::SignerError(e.to_string()))?;
+        Ok(signature)
+    }
+}
+
+#[async_trait]
+impl Signer for ProcessSigner {
+    fn address(&self) -> AccountAddress {
+        self.address
+    }
+
+    fn public_key(&self) -> Ed25519PublicKey {
+        self.public_key.clone()
+    }
+
+    async fn sign_transaction(&self, tx: RawTransaction) -> EconiaResult<SignedTransaction> {
+        let signature = self.sign_message(signing_message(&tx)?).await?;
+        Ok(SignedTransaction::new(
+            tx,
+            self.public_key.clone(),
+            signature,
+        ))
+    }
+
+    async fn sign_transaction_with_data(
+        &self,
+        tx: &RawTransactionWithData,
+    ) -> EconiaResult<AccountAuthenticator> {
+        let signature = self.sign_message(signing_message(tx)?).await?;
+        Ok(AccountAuthenticator::ed25519(
+            self.public_key.clone(),
+            signature,
+        ))
+    }
+}
+
+pub(crate) fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keeps scrypt fast in tests.
+    const TEST_LOG_N: u8 = 4;
+
+    fn account() -> LocalAccount {
+        LocalAccount::new(
+            AccountAddress::from_hex_literal("0xa11ce").unwrap(),
+            AccountKey::from(Ed25519PrivateKey::try_from(&[7u8; 32][..]).unwrap()),
+            0,
+        )
+    }
+
+    fn keystore_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("econia-keystore-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn keystore_round_trip() {
+        let account = account();
+        let path = keystore_path("round-trip");
+        Keystore::encrypt_with_log_n(&account, "hunter2", TEST_LOG_N)
+            .unwrap()
+            .save(&path)
+            .unwrap();
+        let opened = Keystore::open(&path, "hunter2").unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(opened.address(), account.address());
+        assert_eq!(
+            opened.private_key().to_bytes(),
+            account.private_key().to_bytes()
+        );
+    }
+
+    #[test]
+    fn keystore_rejects_wrong_password() {
+        let keystore = Keystore::encrypt_with_log_n(&account(), "hunter2", TEST_LOG_N).unwrap();
+
+        assert!(matches!(
+            keystore.decrypt("hunter3"),
+            Err(EconiaError::KeystoreError(_))
+        ));
+    }
+
+    #[test]
+    fn keystore_rejects_tampering() {
+        let keystore = Keystore::encrypt_with_log_n(&account(), "hunter2", TEST_LOG_N).unwrap();
+
+        let mut ciphertext = decode_hex(&keystore.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        let tampered = Keystore {
+            ciphertext: hex::encode(ciphertext),
+            ..keystore.clone()
+        };
+        assert!(matches!(
+            tampered.decrypt("hunter2"),
+            Err(EconiaError::KeystoreError(_))
+        ));
+
+        // The address is authenticated along with the private key.
+        let tampered = Keystore {
+            address: AccountAddress::from_hex_literal("0xb0b").unwrap(),
+            ..keystore.clone()
+        };
+        assert!(matches!(
+            tampered.decrypt("hunter2"),
+            Err(EconiaError::KeystoreError(_))
+        ));
+
+        let tampered = Keystore {
+            version: KEYSTORE_VERSION + 1,
+            ..keystore
+        };
+        assert!(matches!(
+            tampered.decrypt("hunter2"),
+            Err(EconiaError::KeystoreError(_))
+        ));
+    }
+
+    /// Spawn a shell script answering the account request, then each of `responses` in turn, and
+    /// never answering after that.
+    #[cfg(unix)]
+    async fn scripted_signer(account: &LocalAccount, responses: &[String]) -> ProcessSigner {
+        let mut script = format!(
+            "read -r line; echo '{{\"address\":\"{}\",\"public_key\":\"{}\"}}'\n",
+            account.address().to_hex_literal(),
+            Signer::public_key(account).to_encoded_string().unwrap(),
+        );
+        for response in responses {
+            script.push_str(&format!("read -r line; echo '{}'\n", response));
+        }
+        script.push_str("read -r line; exec sleep 10\n");
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(script);
+        ProcessSigner::spawn_with_timeout(command, Duration::from_millis(500))
+            .await
+            .unwrap()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn process_signer_signs_and_reports_errors() {
+        let account = account();
+        let message = b"econia".to_vec();
+        let signature = account.private_key().sign_arbitrary_message(&message);
+        let other_key = Ed25519PrivateKey::try_from(&[8u8; 32][..]).unwrap();
+        let other_signature = other_key.sign_arbitrary_message(&message);
+        let signer = scripted_signer(
+            &account,
+            &[
+                format!(
+                    r#"{{"signature":"0x{}"}}"#,
+                    hex::encode(signature.to_bytes())
+                ),
+                r#"{"error":"rejected by user"}"#.to_string(),
+                format!(
+                    r#"{{"signature":"0x{}"}}"#,
+                    hex::encode(other_signature.to_bytes())
+                ),
+            ],
+        )
+        .await;
+
+        assert_eq!(Signer::address(&signer), account.address());
+        assert_eq!(
+            signer.sign_message(message.clone()).await.unwrap(),
+            signature
+        );
+        assert!(matches!(
+            signer.sign_message(message.clone()).await,
+            Err(EconiaError::SignerError(error)) if error == "rejected by user"
+        ));
+        // Signatures by another key are caught before they reach a node.
+        assert!(matches!(
+            signer.sign_message(message.clone()).await,
+            Err(EconiaError::SignerError(_))
+        ));
+        assert!(matches!(
+            signer.sign_message(message).await,
+            Err(EconiaError::SignerError(error)) if error == "signer timed out"
+        ));
+    }
+}
@@ -0,0 +1,506 @@
+//! The [`sponsor`](crate::sponsor) module supports Aptos fee-payer transactions, in which a
+//! sponsor account pays the gas of transactions sent by users, e.g. an integrator paying for the
+//! orders of its traders.
+//!
+//! Signing is split between the user and the sponsor, which usually run on different machines:
+//!
+//! 1. The [`EconiaClient`] of the user builds the transaction from an entry function, e.g. from
+//!    the [`entry`](crate::entry) module, and signs it as its sender with
+//!    [`EconiaClient::sign_sponsored_tx`]. The resulting [`SponsoredTransaction`] is sent to the
+//!    sponsor, e.g. BCS encoded with [`SponsoredTransaction::to_bytes`].
+//! 2. The [`EconiaClient`] of the sponsor checks the transaction against its [`SponsorPolicy`],
+//!    signs it as fee payer and submits it with [`EconiaClient::submit_sponsored_tx`].
+//!
+//! Sponsors only pay gas, they do not sign for anything else. The policy still restricts which
+//! transactions get sponsored, so that the sponsor account cannot be drained by arbitrary ones.
+//!
+//! The user hands out its sequence number when signing. If the transaction is not submitted,
+//! resync it with [`EconiaClient::get_sequence_number`] and
+//! [`EconiaClient::set_sequence_number`].
+//!
+//! The sponsor must submit the transaction before it expires, [`DEFAULT_EXPIRATION`] after being
+//! signed unless set otherwise with [`SponsoredTxOptions`].
+
+use std::time::Duration;
+
+use aptos_sdk::bcs;
+use aptos_sdk::transaction_builder::TransactionFactory;
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::transaction::authenticator::AccountAuthenticator;
+use aptos_sdk::types::transaction::{
+    EntryFunction, RawTransaction, RawTransactionWithData, SignedTransaction, TransactionPayload,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::EconiaError;
+use crate::{wait_for_transaction, EconiaClient, EconiaResult, EconiaTransaction, MAX_GAS_AMOUNT};
+
+pub const MAX_GAS_UNIT_PRICE: u64 = 1_000;
+/// Leaves time to hand the transaction over to the sponsor.
+pub const DEFAULT_EXPIRATION: Duration = Duration::from_secs(10 * 60);
+
+/// Options of the transactions built by [`EconiaClient::sign_sponsored_tx`].
+#[derive(Debug, Clone)]
+pub struct SponsoredTxOptions {
+    /// Gas unit price of the transaction, in octas, estimated by the node if `None`.
+    pub gas_unit_price: Option<u64>,
+    /// Time from the signature, as measured by the chain, after which the transaction expires.
+    pub expiration: Duration,
+}
+
+impl Default for SponsoredTxOptions {
+    fn default() -> Self {
+        Self {
+            gas_unit_price: None,
+            expiration: DEFAULT_EXPIRATION,
+        }
+    }
+}
+
+/// Which transactions a sponsor accepts to pay for.
+#[derive(Debug, Clone)]
+pub struct SponsorPolicy {
+    /// Maximum max gas amount of a sponsored transaction.
+    pub max_gas_amount: u64,
+    /// Maximum gas unit price of a sponsored transaction, in octas.
+    pub max_gas_unit_price: u64,
+    /// Econia entry functions which can be sponsored, as `module::function`, e.g.
+    /// `market::place_limit_order_user_entry`. Every Econia entry function if `None`.
+    ///
+    /// Transactions calling anything else than an Econia entry function are never sponsored.
+    pub functions: Option<Vec<String>>,
+}
+
+impl Default for SponsorPolicy {
+    fn default() -> Self {
+        Self {
+            max_gas_amount: MAX_GAS_AMOUNT,
+            max_gas_unit_price: MAX_GAS_UNIT_PRICE,
+            functions: None,
+        }
+    }
+}
+
+impl SponsorPolicy {
+    fn check(&self, econia_address: &AccountAddress, tx: &RawTransaction) -> EconiaResult<()> {
+        let TransactionPayload::EntryFunction(entry) = tx.payload() else {
+            return Err(EconiaError::SponsorError(
+                "only entry functions can be sponsored".to_string(),
+            ));
+        };
+        let function = format!("{}::{}", entry.module().name(), entry.function());
+        if entry.module().address() != econia_address {
+            return Err(EconiaError::SponsorError(format!(
+                "`{}` is not an econia function",
+                entry.module()
+            )));
+        }
+        if let Some(functions) = &self.functions {
+            if !functions.contains(&function) {
+                return Err(EconiaError::SponsorError(format!(
+                    "`{}` cannot be sponsored",
+                    function
+                )));
+            }
+        }
+        if tx.max_gas_amount() > self.max_gas_amount {
+            return Err(EconiaError::SponsorError(format!(
+                "max gas amount: {} exceeds {}",
+                tx.max_gas_amount(),
+                self.max_gas_amount
+            )));
+        }
+        if tx.gas_unit_price() > self.max_gas_unit_price {
+            return Err(EconiaError::SponsorError(format!(
+                "gas unit price: {} exceeds {}",
+                tx.gas_unit_price(),
+                self.max_gas_unit_price
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A fee-payer transaction signed by its sender, waiting for the signature of its sponsor.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SponsoredTransaction {
+    pub raw_transaction: RawTransaction,
+    /// Aptos `AccountAddress` of the sponsor.
+    pub fee_payer_address: AccountAddress,
+    /// Signature of the sender.
+    pub sender_authenticator: AccountAuthenticator,
+}
+
+impl SponsoredTransaction {
+    /// BCS encode the transaction, e.g. to send it to the sponsor.
+    pub fn to_bytes(&self) -> EconiaResult<Vec<u8>> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// Decode a BCS encoded transaction.
+    ///
+    /// # Arguments:
+    ///
+    /// * `bytes` - Transaction encoded with [`SponsoredTransaction::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> EconiaResult<Self> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    fn message(&self) -> RawTransactionWithData {
+        RawTransactionWithData::new_fee_payer(
+            self.raw_transaction.clone(),
+            vec![],
+            self.fee_payer_address,
+        )
+    }
+}
+
+impl EconiaClient {
+    /// Build a fee-payer transaction calling an entry function and sign it as its sender, see
+    /// the [`sponsor`](crate::sponsor) module.
+    ///
+    /// # Arguments:
+    ///
+    /// * `entry` - `EntryFunction` to call.
+    /// * `fee_payer_address` - Aptos `AccountAddress` of the sponsor paying for the gas.
+    /// * `options` - [`SponsoredTxOptions`] of the transaction, if `None` default values will be
+    ///   used.
+    pub async fn sign_sponsored_tx(
+        &self,
+        entry: EntryFunction,
+        fee_payer_address: AccountAddress,
+        options: Option<SponsoredTxOptions>,
+    ) -> EconiaResult<SponsoredTransaction> {
+        let options = options.unwrap_or_default();
+        let index = self.transport.get_index().await?;
+        let gas_unit_price = match options.gas_unit_price {
+            Some(gas_unit_price) => gas_unit_price,
+            None => self.transport.estimate_gas_price().await?,
+        };
+        // Expire relative to the time of the chain rather than to the local clock.
+        let expiration_timestamp_secs =
+            index.ledger_timestamp.0 / 1_000_000 + options.expiration.as_secs();
+        let sequence_number = self.increment_sequence_number();
+        let raw_transaction = TransactionFactory::new(self.chain_id)
+            .payload(TransactionPayload::EntryFunction(entry))
+            .sender(self.user_address())
+            .sequence_number(sequence_number)
+            .max_gas_amount(self.config.max_gas_amount)
+            .gas_unit_price(gas_unit_price)
+            .expiration_timestamp_secs(expiration_timestamp_secs)
+            .build();
+        let message = RawTransactionWithData::new_fee_payer(
+            raw_transaction.clone(),
+            vec![],
+            fee_payer_address,
+        );
+        let sender_authenticator = match self.signer.sign_transaction_with_data(&message).await {
+            Ok(authenticator) => authenticator,
+            Err(e) => {
+                self.release_sequence_number(sequence_number).await;
+                return Err(e);
+            }
+        };
+        Ok(SponsoredTransaction {
+            raw_transaction,
+            fee_payer_address,
+            sender_authenticator,
+        })
+    }
+
+    /// Check a transaction against a policy and sign it as its fee payer, the signer of this
+    /// client being the sponsor.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tx` - [`SponsoredTransaction`] signed by its sender.
+    /// * `policy` - [`SponsorPolicy`] the transaction must comply with.
+    pub async fn sponsor_tx(
+        &self,
+        tx: &SponsoredTransaction,
+        policy: &SponsorPolicy,
+    ) -> EconiaResult<SignedTransaction> {
+        if tx.fee_payer_address != self.user_address() {
+            return Err(EconiaError::SponsorError(format!(
+                "the fee payer is: `{}`, not: `{}`",
+                tx.fee_payer_address,
+                self.user_address()
+            )));
+        }
+        if tx.raw_transaction.chain_id() != self.chain_id {
+            return Err(EconiaError::SponsorError(format!(
+                "the transaction is for chain: {}",
+                tx.raw_transaction.chain_id()
+            )));
+        }
+        policy.check(&self.econia_address, &tx.raw_transaction)?;
+        let fee_payer_authenticator = self
+            .signer
+            .sign_transaction_with_data(&tx.message())
+            .await?;
+        let signed_transaction = SignedTransaction::new_fee_payer(
+            tx.raw_transaction.clone(),
+            tx.sender_authenticator.clone(),
+            vec![],
+            vec![],
+            tx.fee_payer_address,
+            fee_payer_authenticator,
+        );
+        // Reject transactions whose sender signature is invalid before paying for them.
+        signed_transaction
+            .verify_signature()
+            .map_err(|e| EconiaError::SponsorError(e.to_string()))?;
+        Ok(signed_transaction)
+    }
+
+    /// Sign a transaction as its fee payer with [`EconiaClient::sponsor_tx`], submit it and wait
+    /// for it to be committed.
+    ///
+    /// Transactions are not retried, as they cannot change without being signed again by their
+    /// sender.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tx` - [`SponsoredTransaction`] signed by its sender.
+    /// * `policy` - [`SponsorPolicy`] the transaction must comply with.
+    pub async fn submit_sponsored_tx(
+        &self,
+        tx: &SponsoredTransaction,
+        policy: &SponsorPolicy,
+    ) -> EconiaResult<EconiaTransaction> {
+        let signed_transaction = self.sponsor_tx(tx, policy).await?;
+        let pending = self
+            .transport
+            .submit(&signed_transaction)
+            .await
+            .map_err(|e| EconiaError::from_rest_error(&self.econia_address, e))?;
+        let tx =
+            wait_for_transaction(self.transport.as_ref(), &self.econia_address, &pending).await?;
+        self.econia_transaction(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use aptos_sdk::crypto::ed25519::Ed25519PrivateKey;
+    use aptos_sdk::move_types::ident_str;
+    use aptos_sdk::move_types::language_storage::ModuleId;
+    use aptos_sdk::types::chain_id::ChainId;
+    use aptos_sdk::types::transaction::Script;
+    use aptos_sdk::types::{AccountKey, LocalAccount};
+
+    use super::*;
+    use crate::generated::entry;
+    use crate::tests::{mock_client, ECONIA};
+    use crate::transport::{MockTransport, MOCK_GAS_UNIT_PRICE};
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn sponsor_address() -> AccountAddress {
+        AccountAddress::from_hex_literal("0x5905").unwrap()
+    }
+
+    /// Client of the sponsor, on the same transport as the user or on a transport of its own.
+    async fn sponsor_client(transport: Arc<MockTransport>) -> EconiaClient {
+        let private_key = Ed25519PrivateKey::try_from(&[9u8; 32][..]).unwrap();
+        let account = LocalAccount::new(sponsor_address(), AccountKey::from(private_key), 0);
+        EconiaClient::connect_with_transport(transport, econia(), account, None)
+            .await
+            .unwrap()
+    }
+
+    fn cancel_all_orders(market_id: u64) -> EntryFunction {
+        entry::market::cancel_all_orders_user(econia(), market_id, true).unwrap()
+    }
+
+    fn raw_transaction(
+        payload: TransactionPayload,
+        max_gas_amount: u64,
+        price: u64,
+    ) -> RawTransaction {
+        TransactionFactory::new(ChainId::new(4))
+            .payload(payload)
+            .sender(AccountAddress::from_hex_literal("0xa11ce").unwrap())
+            .sequence_number(0)
+            .max_gas_amount(max_gas_amount)
+            .gas_unit_price(price)
+            .build()
+    }
+
+    #[test]
+    fn check_policy() {
+        let policy = SponsorPolicy {
+            max_gas_amount: 1_000,
+            max_gas_unit_price: 100,
+            functions: Some(vec!["market::cancel_all_orders_user".to_string()]),
+        };
+        let check = |payload, max_gas_amount, price| {
+            policy.check(&econia(), &raw_transaction(payload, max_gas_amount, price))
+        };
+        let allowed = || TransactionPayload::EntryFunction(cancel_all_orders(1));
+        assert!(check(allowed(), 1_000, 100).is_ok());
+        assert!(check(allowed(), 1_001, 100).is_err());
+        assert!(check(allowed(), 1_000, 101).is_err());
+
+        let other_function = entry::market::cancel_order_user(econia(), 1, true, 2).unwrap();
+        assert!(check(
+            TransactionPayload::EntryFunction(other_function),
+            1_000,
+            100
+        )
+        .is_err());
+        let other_module = EntryFunction::new(
+            ModuleId::new(AccountAddress::ONE, ident_str!("market").to_owned()),
+            ident_str!("cancel_all_orders_user").to_owned(),
+            vec![],
+            vec![],
+        );
+        assert!(check(TransactionPayload::EntryFunction(other_module), 1_000, 100).is_err());
+        let script = Script::new(vec![], vec![], vec![]);
+        assert!(check(TransactionPayload::Script(script), 1_000, 100).is_err());
+
+        // Every Econia entry function is allowed without a list of functions.
+        let policy = SponsorPolicy::default();
+        let other_function = entry::market::cancel_order_user(econia(), 1, true, 2).unwrap();
+        let tx = raw_transaction(
+            TransactionPayload::EntryFunction(other_function),
+            1_000,
+            100,
+        );
+        assert!(policy.check(&econia(), &tx).is_ok());
+    }
+
+    #[tokio::test]
+    async fn sign_with_options() {
+        let (_, client) = mock_client().await;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), None)
+            .await
+            .unwrap();
+        let raw = &tx.raw_transaction;
+        assert_eq!(raw.sequence_number(), 0);
+        assert_eq!(raw.gas_unit_price(), MOCK_GAS_UNIT_PRICE);
+        let expiration = raw.expiration_timestamp_secs();
+        assert!(expiration >= now + DEFAULT_EXPIRATION.as_secs());
+        assert!(expiration <= now + DEFAULT_EXPIRATION.as_secs() + 5);
+
+        let options = SponsoredTxOptions {
+            gas_unit_price: Some(150),
+            expiration: Duration::from_secs(30),
+        };
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), Some(options))
+            .await
+            .unwrap();
+        let raw = &tx.raw_transaction;
+        assert_eq!(raw.sequence_number(), 1);
+        assert_eq!(raw.gas_unit_price(), 150);
+        assert!(raw.expiration_timestamp_secs() <= now + 35);
+    }
+
+    #[tokio::test]
+    async fn bcs_round_trip() {
+        let (_, client) = mock_client().await;
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), None)
+            .await
+            .unwrap();
+        let bytes = tx.to_bytes().unwrap();
+        let decoded = SponsoredTransaction::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.raw_transaction, tx.raw_transaction);
+        assert_eq!(decoded.fee_payer_address, tx.fee_payer_address);
+        assert_eq!(decoded.sender_authenticator, tx.sender_authenticator);
+        assert!(SponsoredTransaction::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[tokio::test]
+    async fn sponsor_and_submit() {
+        let (transport, client) = mock_client().await;
+        let sponsor = sponsor_client(transport.clone()).await;
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), None)
+            .await
+            .unwrap();
+
+        let signed = sponsor
+            .sponsor_tx(&tx, &SponsorPolicy::default())
+            .await
+            .unwrap();
+        assert!(signed.verify_signature().is_ok());
+        sponsor
+            .submit_sponsored_tx(&tx, &SponsorPolicy::default())
+            .await
+            .unwrap();
+        let submitted = transport.submitted();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].sender(), client.user_address());
+    }
+
+    #[tokio::test]
+    async fn reject_other_fee_payer_or_chain() {
+        let (transport, client) = mock_client().await;
+        let policy = SponsorPolicy::default();
+
+        // Signed for another sponsor.
+        let sponsor = sponsor_client(transport).await;
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), AccountAddress::ONE, None)
+            .await
+            .unwrap();
+        assert!(matches!(
+            sponsor.sponsor_tx(&tx, &policy).await,
+            Err(EconiaError::SponsorError(_))
+        ));
+
+        // Sponsor of another chain.
+        let sponsor = sponsor_client(Arc::new(MockTransport::new(5))).await;
+        let tx = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), None)
+            .await
+            .unwrap();
+        assert!(matches!(
+            sponsor.sponsor_tx(&tx, &policy).await,
+            Err(EconiaError::SponsorError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_sender_signature() {
+        let (transport, client) = mock_client().await;
+        let sponsor = sponsor_client(transport.clone()).await;
+        let policy = SponsorPolicy::default();
+        let first = client
+            .sign_sponsored_tx(cancel_all_orders(1), sponsor_address(), None)
+            .await
+            .unwrap();
+        let second = client
+            .sign_sponsored_tx(cancel_all_orders(2), sponsor_address(), None)
+            .await
+            .unwrap();
+
+        // The signature of another transaction of the same sender.
+        let tampered = SponsoredTransaction {
+            sender_authenticator: second.sender_authenticator,
+            ..first
+        };
+        assert!(matches!(
+            sponsor.sponsor_tx(&tampered, &policy).await,
+            Err(EconiaError::SponsorError(_))
+        ));
+        assert!(matches!(
+            sponsor.submit_sponsored_tx(&tampered, &policy).await,
+            Err(EconiaError::SponsorError(_))
+        ));
+        assert!(transport.submitted().is_empty());
+    }
+}
@@ -0,0 +1,393 @@
+//! The [`subscription`](crate::subscription) module exposes an [`EventSubscription`] which polls
+//! Econia event handles and streams their events.
+//!
+//! [`EconiaClient::get_events_by_creation_number`] fetches a single page of a single event
+//! handle. A subscription instead polls every event handle of a market, a user market account or
+//! a swapper, keeping track of the next sequence number of each handle so that every event is
+//! streamed exactly once. Events fetched by a same poll are ordered by transaction version.
+//!
+//! Failed polls are retried with an exponential backoff, the error being yielded by the stream
+//! so that callers can log it or give up. Events which cannot be decoded, e.g. emitted by a newer
+//! version of Econia, are yielded once as an [`EconiaError::UndecodableEvent`] and skipped.
+//!
+//! Cursors can be saved with [`EventSubscription::cursors`], or from the handle and sequence
+//! number of the last processed [`SubscriptionEvent`] once streaming, and restored with
+//! [`EventSubscription::resume_from`] to pick up where a previous subscription stopped.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use aptos_sdk::move_types::account_address::AccountAddress;
+use econia_types::events::EconiaEvent;
+use futures::Stream;
+
+use crate::errors::EconiaError;
+use crate::{EconiaClient, EconiaResult};
+
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+pub const PAGE_LIMIT: u16 = 100;
+pub const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionConfig {
+    /// Time to wait before polling again when no new event was found.
+    pub poll_interval: Duration,
+    /// Maximum amount of events to fetch per event handle and poll.
+    pub page_limit: u16,
+    /// Time to wait before retrying after a first failed poll.
+    pub initial_backoff: Duration,
+    /// Maximum time to wait before retrying, the backoff doubling after each failed poll.
+    pub max_backoff: Duration,
+}
+
+impl Default for SubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: POLL_INTERVAL,
+            page_limit: PAGE_LIMIT,
+            initial_backoff: INITIAL_BACKOFF,
+            max_backoff: MAX_BACKOFF,
+        }
+    }
+}
+
+/// An event handle, identified by the account holding it and its creation number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EventHandle {
+    pub address: AccountAddress,
+    pub creation_number: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionEvent {
+    /// Event handle the event was emitted to.
+    pub handle: EventHandle,
+    /// Sequence number of the event within its event handle.
+    pub sequence_number: u64,
+    /// Version of the transaction which emitted the event.
+    pub version: u64,
+    /// The [`EconiaEvent`] itself.
+    pub event: EconiaEvent,
+}
+
+/// Polls event handles for new events, see the [module documentation](crate::subscription).
+pub struct EventSubscription<'a> {
+    client: &'a EconiaClient,
+    config: SubscriptionConfig,
+    handles: Vec<EventHandle>,
+    cursors: HashMap<EventHandle, u64>,
+}
+
+impl<'a> EventSubscription<'a> {
+    /// Create a subscription to the given event handles, starting from their first event.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to poll the event handles with.
+    /// * `handles` - Event handles to subscribe to.
+    /// * `config` - `SubscriptionConfig` to configure the subscription, if `None` default values will be used.
+    pub fn new(
+        client: &'a EconiaClient,
+        handles: Vec<EventHandle>,
+        config: Option<SubscriptionConfig>,
+    ) -> Self {
+        Self {
+            client,
+            config: config.unwrap_or_default(),
+            cursors: handles.iter().map(|h| (*h, 0)).collect(),
+            handles,
+        }
+    }
+
+    /// Subscribe to the events of a market, emitted for cancelled orders and swaps which are not
+    /// placed from a market account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to poll the event handles with.
+    /// * `market_id` - Market ID of the market.
+    /// * `config` - `SubscriptionConfig` to configure the subscription, if `None` default values will be used.
+    pub async fn market(
+        client: &'a EconiaClient,
+        market_id: u64,
+        config: Option<SubscriptionConfig>,
+    ) -> EconiaResult<EventSubscription<'a>> {
+        let info = client
+            .view_client()
+            .get_market_event_handle_creation_info(market_id)
+            .await?
+            .ok_or(EconiaError::EventHandlesNotFound)?;
+        let address = info.resource_account_address;
+        let handles = [
+            info.cancel_order_events_handle_creation_num,
+            info.place_swap_order_events_handle_creation_num,
+        ]
+        .into_iter()
+        .map(|creation_number| EventHandle {
+            address,
+            creation_number,
+        })
+        .collect();
+        Ok(Self::new(client, handles, config))
+    }
+
+    /// Subscribe to the events of a user market account.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to poll the event handles with.
+    /// * `user` - Aptos `AccountAddress` of the user.
+    /// * `market_id` - Market ID of the market account.
+    /// * `custodian_id` - Custodian ID of the market account.
+    /// * `config` - `SubscriptionConfig` to configure the subscription, if `None` default values will be used.
+    pub async fn user(
+        client: &'a EconiaClient,
+        user: AccountAddress,
+        market_id: u64,
+        custodian_id: u64,
+        config: Option<SubscriptionConfig>,
+    ) -> EconiaResult<EventSubscription<'a>> {
+        let numbers = client
+            .view_client()
+            .get_market_event_handle_creation_numbers(user, market_id, custodian_id)
+            .await?
+            .ok_or(EconiaError::EventHandlesNotFound)?;
+        let handles = [
+            numbers.cancel_order_events_handle_creation_num,
+            numbers.change_order_size_events_handle_creation_num,
+            numbers.fill_events_handle_creation_num,
+            numbers.place_limit_order_events_handle_creation_num,
+            numbers.place_market_order_events_handle_creation_num,
+        ]
+        .into_iter()
+        .map(|creation_number| EventHandle {
+            address: user,
+            creation_number,
+        })
+        .collect();
+        Ok(Self::new(client, handles, config))
+    }
+
+    /// Subscribe to the events of a swapper, emitted for swaps placed from its coin stores.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to poll the event handles with.
+    /// * `swapper` - Aptos `AccountAddress` of the swapper.
+    /// * `market_id` - Market ID of the market swapped on.
+    /// * `config` - `SubscriptionConfig` to configure the subscription, if `None` default values will be used.
+    pub async fn swapper(
+        client: &'a EconiaClient,
+        swapper: AccountAddress,
+        market_id: u64,
+        config: Option<SubscriptionConfig>,
+    ) -> EconiaResult<EventSubscription<'a>> {
+        let numbers = client
+            .view_client()
+            .get_swapper_event_handle_creation_numbers(swapper, market_id)
+            .await?;
+        let handles = [
+            numbers.cancel_order_events_handle_creation_num,
+            numbers.fill_events_handle_creation_num,
+            numbers.place_swap_order_events_handle_creation_num,
+        ]
+        .into_iter()
+        .map(|creation_number| EventHandle {
+            address: swapper,
+            creation_number,
+        })
+        .collect();
+        Ok(Self::new(client, handles, config))
+    }
+
+    /// Resume the subscription from saved cursors, see [`EventSubscription::cursors`].
+    ///
+    /// Cursors of event handles the subscription is not subscribed to are ignored.
+    ///
+    /// # Arguments:
+    ///
+    /// * `cursors` - Sequence number of the next event to stream, per event handle.
+    pub fn resume_from(mut self, cursors: &HashMap<EventHandle, u64>) -> Self {
+        for (handle, cursor) in self.cursors.iter_mut() {
+            if let Some(saved) = cursors.get(handle) {
+                *cursor = *saved;
+            }
+        }
+        self
+    }
+
+    /// Event handles of the subscription.
+    pub fn handles(&self) -> &[EventHandle] {
+        &self.handles
+    }
+
+    /// Sequence number of the next event to stream, per event handle.
+    pub fn cursors(&self) -> &HashMap<EventHandle, u64> {
+        &self.cursors
+    }
+
+    /// Fetch the events emitted since the last poll, ordered by transaction version.
+    ///
+    /// Cursors are only moved if every event handle could be polled, so that no event is lost
+    /// when a poll fails. Events which cannot be decoded are returned as an
+    /// [`EconiaError::UndecodableEvent`] in their place, the cursors moving past them.
+    pub async fn poll(&mut self) -> EconiaResult<Vec<EconiaResult<SubscriptionEvent>>> {
+        let mut cursors = self.cursors.clone();
+        let mut events = vec![];
+        for handle in &self.handles {
+            let cursor = cursors
+                .get_mut(handle)
+                .ok_or(EconiaError::InvalidResponse)?;
+            let page = self
+                .client
+                .get_events_by_creation_number(
+                    handle.creation_number,
+                    handle.address,
+                    Some(*cursor),
+                    Some(self.config.page_limit),
+                )
+                .await?;
+            for versioned in page {
+                let sequence_number = versioned.sequence_number.0;
+                // Nodes may return events which were already streamed, e.g. if a cursor was
+                // restored from before the start of the page.
+                if sequence_number < *cursor {
+                    continue;
+                }
+                *cursor = sequence_number + 1;
+                let version = versioned.version.0;
+                let event = match serde_json::from_value(versioned.data) {
+                    Ok(event) => Ok(SubscriptionEvent {
+                        handle: *handle,
+                        sequence_number,
+                        version,
+                        event,
+                    }),
+                    Err(e) => Err(EconiaError::UndecodableEvent(format!(
+                        "event {} of handle {} of `{}`, of type `{}`: {}",
+                        sequence_number,
+                        handle.creation_number,
+                        handle.address.to_hex_literal(),
+                        versioned.typ,
+                        e
+                    ))),
+                };
+                events.push((version, event));
+            }
+        }
+        // The sort is stable, so events of a same transaction and handle stay in order.
+        events.sort_by_key(|(version, _)| *version);
+        self.cursors = cursors;
+        Ok(events.into_iter().map(|(_, event)| event).collect())
+    }
+
+    /// Turn the subscription into a never ending `Stream` of events.
+    ///
+    /// Errors are yielded as they happen, the next poll being delayed by the backoff. Events which
+    /// cannot be decoded are yielded as errors without delaying the stream.
+    ///
+    /// Must be polled from within a tokio runtime.
+    pub fn into_stream(self) -> impl Stream<Item = EconiaResult<SubscriptionEvent>> + 'a {
+        let state = (self, VecDeque::new(), None);
+        futures::stream::unfold(
+            state,
+            |(mut subscription, mut buffer, mut backoff)| async move {
+                loop {
+                    if let Some(event) = buffer.pop_front() {
+                        return Some((event, (subscription, buffer, backoff)));
+                    }
+                    if let Some(delay) = backoff {
+                        tokio::time::sleep(delay).await;
+                    }
+                    match subscription.poll().await {
+                        Ok(events) if events.is_empty() => {
+                            backoff = None;
+                            tokio::time::sleep(subscription.config.poll_interval).await;
+                        }
+                        Ok(events) => {
+                            backoff = None;
+                            buffer.extend(events);
+                        }
+                        Err(e) => {
+                            let delay = backoff.map_or(subscription.config.initial_backoff, |d| {
+                                (d * 2).min(subscription.config.max_backoff)
+                            });
+                            return Some((Err(e), (subscription, buffer, Some(delay))));
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use serde_json::json;
+
+    use super::*;
+    use crate::tests::mock_client;
+
+    fn cancel_order(order_id: u128) -> serde_json::Value {
+        json!({
+            "market_id": "1",
+            "order_id": order_id.to_string(),
+            "user": "0xa11ce",
+            "custodian_id": "0",
+            "reason": 3,
+        })
+    }
+
+    #[tokio::test]
+    async fn skip_undecodable_event() {
+        let (transport, client) = mock_client().await;
+        let handle = EventHandle {
+            address: client.user_address(),
+            creation_number: 4,
+        };
+        let event_type = "0xc0deb00c::user::CancelOrderEvent";
+        transport
+            .push_event(handle.address, 4, event_type, cancel_order(1))
+            .unwrap();
+        transport
+            .push_event(handle.address, 4, event_type, json!({ "unknown": true }))
+            .unwrap();
+        transport
+            .push_event(handle.address, 4, event_type, cancel_order(2))
+            .unwrap();
+
+        let subscription = EventSubscription::new(&client, vec![handle], None);
+        let events: Vec<_> = subscription.into_stream().take(3).collect().await;
+        assert!(matches!(&events[0], Ok(e) if e.sequence_number == 0));
+        assert!(matches!(&events[1], Err(EconiaError::UndecodableEvent(_))));
+        match &events[2] {
+            Ok(SubscriptionEvent {
+                sequence_number: 2,
+                event: EconiaEvent::CancelOrder(e),
+                ..
+            }) => assert_eq!(e.order_id, 2),
+            e => panic!("unexpected item {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn poll_past_undecodable_event() {
+        let (transport, client) = mock_client().await;
+        let handle = EventHandle {
+            address: client.user_address(),
+            creation_number: 4,
+        };
+        transport
+            .push_event(handle.address, 4, "0x1::m::E", json!({ "unknown": true }))
+            .unwrap();
+
+        let mut subscription = EventSubscription::new(&client, vec![handle], None);
+        let events = subscription.poll().await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_err());
+        assert_eq!(subscription.cursors()[&handle], 1);
+        assert!(subscription.poll().await.unwrap().is_empty());
+    }
+}
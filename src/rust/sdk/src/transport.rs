@@ -0,0 +1,822 @@
+//! The [`transport`](crate::transport) module exposes the [`AptosTransport`] trait, through which
+//! the SDK sends every request to the Aptos node.
+//!
+//! The Aptos REST `Client` implements it, and is used by
+//! [`EconiaClient::connect`](crate::EconiaClient::connect). Any other transport, e.g. one adding
+//! metrics or caching, can be given to
+//! [`EconiaClient::connect_with_transport`](crate::EconiaClient::connect_with_transport).
+//!
+//! [`MockTransport`] is an in-memory transport for unit tests, which need no node: it serves
+//! scripted view function responses, matched on the whole request, resources, module ABIs and
+//! events, and records the transactions submitted through it.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::anyhow;
+use aptos_api_types::{
+    IndexResponse, MoveModule, MoveModuleBytecode, PendingTransaction, Transaction,
+    UserTransaction, VersionedEvent, ViewRequest,
+};
+use aptos_sdk::crypto::HashValue;
+use aptos_sdk::move_types::language_storage::{StructTag, TypeTag};
+use aptos_sdk::rest_client::error::RestError;
+use aptos_sdk::rest_client::{Account, Client, Resource};
+use aptos_sdk::types::account_address::AccountAddress;
+use aptos_sdk::types::transaction::authenticator::AuthenticationKey;
+use aptos_sdk::types::transaction::{EntryFunction, SignedTransaction, TransactionPayload};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use crate::errors::EconiaError;
+use crate::EconiaResult;
+
+/// Gas used by every transaction of a [`MockTransport`].
+pub const MOCK_GAS_USED: u64 = 10;
+/// Gas unit price estimated by a [`MockTransport`], in octas.
+pub const MOCK_GAS_UNIT_PRICE: u64 = 100;
+
+/// Values returned by a view function.
+#[derive(Debug, Clone)]
+pub struct ViewResponse {
+    pub values: Vec<Value>,
+    /// Ledger version the function was called at.
+    pub ledger_version: u64,
+}
+
+/// Requests sent by the SDK to an Aptos node.
+///
+/// Failures are returned as a `RestError`, so that they are classified the same way whatever the
+/// transport, e.g. by the [`RetryPolicy`](crate::retry::RetryPolicy) of a client.
+#[async_trait]
+pub trait AptosTransport: std::fmt::Debug + Send + Sync {
+    /// Chain ID, ledger version and timestamp of the node.
+    async fn get_index(&self) -> Result<IndexResponse, RestError>;
+
+    async fn get_account(&self, address: AccountAddress) -> Result<Account, RestError>;
+
+    /// Resource of an account, `None` if the account does not hold it.
+    ///
+    /// # Arguments:
+    ///
+    /// * `address` - Aptos `AccountAddress` of the account.
+    /// * `resource_type` - Type of the resource, e.g.
+    ///   `0x1::coin::CoinInfo<0x1::aptos_coin::AptosCoin>`.
+    async fn get_account_resource(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+    ) -> Result<Option<Resource>, RestError>;
+
+    async fn get_account_module(
+        &self,
+        address: AccountAddress,
+        module_name: &str,
+    ) -> Result<MoveModuleBytecode, RestError>;
+
+    /// Call a view function at a ledger version, the latest one if `None`.
+    async fn view(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> Result<ViewResponse, RestError>;
+
+    /// Estimated gas unit price, in octas.
+    async fn estimate_gas_price(&self) -> Result<u64, RestError>;
+
+    /// Simulate a transaction, estimating its gas unit price and max gas amount.
+    async fn simulate(&self, tx: &SignedTransaction) -> Result<Vec<UserTransaction>, RestError>;
+
+    async fn submit(&self, tx: &SignedTransaction) -> Result<PendingTransaction, RestError>;
+
+    /// Wait for a submitted transaction to be committed or to expire.
+    async fn wait_for_transaction(
+        &self,
+        pending: &PendingTransaction,
+    ) -> Result<Transaction, RestError>;
+
+    /// Events of an event handle, identified by the creation number of its GUID.
+    ///
+    /// # Arguments:
+    ///
+    /// * `address` - Aptos `AccountAddress` of the account holding the event handle.
+    /// * `creation_number` - Creation number of the event handle.
+    /// * `start` - Sequence number of the first event to return, the oldest one if `None`.
+    /// * `limit` - Maximum amount of events to return, the node default if `None`.
+    async fn get_events_by_creation_number(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<VersionedEvent>, RestError>;
+}
+
+#[async_trait]
+impl AptosTransport for Client {
+    async fn get_index(&self) -> Result<IndexResponse, RestError> {
+        Ok(Client::get_index(self).await?.into_inner())
+    }
+
+    async fn get_account(&self, address: AccountAddress) -> Result<Account, RestError> {
+        Ok(Client::get_account(self, address).await?.into_inner())
+    }
+
+    async fn get_account_resource(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+    ) -> Result<Option<Resource>, RestError> {
+        Ok(Client::get_account_resource(self, address, resource_type)
+            .await?
+            .into_inner())
+    }
+
+    async fn get_account_module(
+        &self,
+        address: AccountAddress,
+        module_name: &str,
+    ) -> Result<MoveModuleBytecode, RestError> {
+        Ok(Client::get_account_module(self, address, module_name)
+            .await?
+            .into_inner())
+    }
+
+    async fn view(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> Result<ViewResponse, RestError> {
+        let response = Client::view(self, request, version).await?;
+        Ok(ViewResponse {
+            ledger_version: response.state().version,
+            values: response.into_inner(),
+        })
+    }
+
+    async fn estimate_gas_price(&self) -> Result<u64, RestError> {
+        Ok(Client::estimate_gas_price(self)
+            .await?
+            .into_inner()
+            .gas_estimate)
+    }
+
+    async fn simulate(&self, tx: &SignedTransaction) -> Result<Vec<UserTransaction>, RestError> {
+        Ok(self
+            .simulate_with_gas_estimation(tx, true, true)
+            .await?
+            .into_inner())
+    }
+
+    async fn submit(&self, tx: &SignedTransaction) -> Result<PendingTransaction, RestError> {
+        Ok(Client::submit(self, tx).await?.into_inner())
+    }
+
+    async fn wait_for_transaction(
+        &self,
+        pending: &PendingTransaction,
+    ) -> Result<Transaction, RestError> {
+        Ok(Client::wait_for_transaction(self, pending)
+            .await?
+            .into_inner())
+    }
+
+    async fn get_events_by_creation_number(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<VersionedEvent>, RestError> {
+        let url = self.build_path(&format!(
+            "accounts/{}/events/{}",
+            address.to_hex_literal(),
+            creation_number,
+        ))?;
+
+        let client = reqwest::Client::new();
+        let mut request = client.get(url);
+
+        if let Some(start) = start {
+            request = request.query(&[("start", start)])
+        }
+
+        if let Some(limit) = limit {
+            request = request.query(&[("limit", limit)])
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| RestError::Unknown(anyhow!(e)))?;
+        response
+            .json()
+            .await
+            .map_err(|e| RestError::Unknown(anyhow!(e)))
+    }
+}
+
+/// Outcome of a transaction submitted to a [`MockTransport`].
+#[derive(Debug)]
+pub enum MockOutcome {
+    /// The transaction is committed and emits events, as their type and JSON data, e.g.
+    /// `("0xc0deb00c::user::FillEvent", json!({...}))`.
+    Committed(Vec<(String, Value)>),
+    /// The transaction is committed but fails execution with a VM status, e.g.
+    /// `Move abort in 0xc0deb00c::market: 0x1c`.
+    Failed(String),
+    /// The submission is rejected by the node.
+    Rejected(RestError),
+    /// The transaction is accepted but waiting for it times out, leaving its outcome unknown. It
+    /// is committed without events if `committed`, otherwise it is never committed.
+    Unconfirmed { committed: bool },
+}
+
+/// In-memory [`AptosTransport`] for unit tests, see the [`transport`](crate::transport) module.
+///
+/// Accounts start at sequence number 0 and the sequence number of a sender increases with each
+/// of its committed transactions. Submitted transactions are committed with the outcomes queued
+/// by [`MockTransport::push_outcome`], in order, or without events once the queue is empty.
+/// Module ABIs are only served once set with [`MockTransport::set_module_abi`].
+#[derive(Debug)]
+pub struct MockTransport {
+    chain_id: u8,
+    state: Mutex<MockState>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    version: u64,
+    sequence_numbers: HashMap<AccountAddress, u64>,
+    resources: HashMap<(AccountAddress, String), Value>,
+    /// ABIs of modules, by address and name.
+    modules: HashMap<(AccountAddress, String), Value>,
+    /// Responses of view functions, by JSON serialized `ViewRequest`.
+    views: HashMap<String, VecDeque<Vec<Value>>>,
+    events: HashMap<(AccountAddress, u64), Vec<VersionedEvent>>,
+    outcomes: VecDeque<MockOutcome>,
+    submitted: Vec<SignedTransaction>,
+    pending: HashMap<HashValue, Value>,
+    unconfirmed: HashSet<HashValue>,
+}
+
+impl MockTransport {
+    /// Create a mock of a chain without any state.
+    ///
+    /// # Arguments:
+    ///
+    /// * `chain_id` - Chain ID reported by the mock.
+    pub fn new(chain_id: u8) -> Self {
+        Self {
+            chain_id,
+            state: Mutex::new(MockState::default()),
+        }
+    }
+
+    /// Set the sequence number of an account.
+    pub fn set_sequence_number(&self, address: AccountAddress, sequence_number: u64) {
+        self.state
+            .lock()
+            .unwrap()
+            .sequence_numbers
+            .insert(address, sequence_number);
+    }
+
+    /// Give a resource to an account, replacing the one of the same type if any.
+    ///
+    /// # Arguments:
+    ///
+    /// * `address` - Aptos `AccountAddress` of the account.
+    /// * `resource_type` - Type of the resource, e.g.
+    ///   `0x1::coin::CoinInfo<0x1::aptos_coin::AptosCoin>`.
+    /// * `data` - JSON data of the resource, as returned by the node.
+    pub fn set_resource(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+        data: Value,
+    ) -> EconiaResult<()> {
+        let tag = StructTag::from_str(resource_type).map_err(EconiaError::Custom)?;
+        self.state
+            .lock()
+            .unwrap()
+            .resources
+            .insert((address, tag.to_string()), data);
+        Ok(())
+    }
+
+    /// Serve the ABI of a module, so that batches calling it can be built. Its bytecode is not
+    /// served.
+    ///
+    /// # Arguments:
+    ///
+    /// * `abi` - JSON ABI of the module, as returned by the node, e.g.
+    ///   `{"address": "0xc0deb00c", "name": "market", "exposed_functions": [...], ...}`.
+    pub fn set_module_abi(&self, abi: Value) -> EconiaResult<()> {
+        let module: MoveModule = serde_json::from_value(abi.clone())?;
+        self.state
+            .lock()
+            .unwrap()
+            .modules
+            .insert((module.address.into(), module.name.0.to_string()), abi);
+        Ok(())
+    }
+
+    /// Queue the values returned by the next call of a view function with the same type
+    /// arguments and arguments. The last queued values are returned by every later call.
+    ///
+    /// # Arguments:
+    ///
+    /// * `request` - `ViewRequest` of the call, e.g. built with the
+    ///   [`generated::view`](crate::generated::view) builders.
+    /// * `values` - JSON values returned by the function, as returned by the node.
+    pub fn push_view(&self, request: &ViewRequest, values: Vec<Value>) -> EconiaResult<()> {
+        let key = serde_json::to_string(request)?;
+        self.state
+            .lock()
+            .unwrap()
+            .views
+            .entry(key)
+            .or_default()
+            .push_back(values);
+        Ok(())
+    }
+
+    /// Emit an event to an event handle, with the next sequence number of the handle.
+    ///
+    /// # Arguments:
+    ///
+    /// * `address` - Aptos `AccountAddress` of the account holding the event handle.
+    /// * `creation_number` - Creation number of the event handle.
+    /// * `event_type` - Type of the event, e.g. `0xc0deb00c::user::FillEvent`.
+    /// * `data` - JSON data of the event, as returned by the node.
+    pub fn push_event(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        event_type: &str,
+        data: Value,
+    ) -> EconiaResult<()> {
+        let mut state = self.state.lock().unwrap();
+        state.version += 1;
+        let version = state.version;
+        let events = state.events.entry((address, creation_number)).or_default();
+        let mut event = event_json(address, creation_number, events.len(), event_type, data);
+        event["version"] = json!(version.to_string());
+        events.push(serde_json::from_value(event)?);
+        Ok(())
+    }
+
+    /// Queue the outcome of the next submitted transaction.
+    pub fn push_outcome(&self, outcome: MockOutcome) {
+        self.state.lock().unwrap().outcomes.push_back(outcome);
+    }
+
+    /// Transactions submitted so far, rejected ones excluded, in order.
+    pub fn submitted(&self) -> Vec<SignedTransaction> {
+        self.state.lock().unwrap().submitted.clone()
+    }
+
+    /// Entry functions of the transactions submitted so far, in order.
+    pub fn submitted_entry_functions(&self) -> Vec<EntryFunction> {
+        self.submitted()
+            .into_iter()
+            .filter_map(|tx| match tx.payload() {
+                TransactionPayload::EntryFunction(entry) => Some(entry.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AptosTransport for MockTransport {
+    async fn get_index(&self) -> Result<IndexResponse, RestError> {
+        let version = self.state.lock().unwrap().version.to_string();
+        Ok(serde_json::from_value(json!({
+            "chain_id": self.chain_id,
+            "epoch": "1",
+            "ledger_version": version,
+            "oldest_ledger_version": "0",
+            "ledger_timestamp": now_micros().to_string(),
+            "node_role": "full_node",
+            "oldest_block_height": "0",
+            "block_height": version,
+        }))?)
+    }
+
+    async fn get_account(&self, address: AccountAddress) -> Result<Account, RestError> {
+        let state = self.state.lock().unwrap();
+        Ok(Account {
+            authentication_key: AuthenticationKey::zero(),
+            sequence_number: state.sequence_numbers.get(&address).copied().unwrap_or(0),
+        })
+    }
+
+    async fn get_account_resource(
+        &self,
+        address: AccountAddress,
+        resource_type: &str,
+    ) -> Result<Option<Resource>, RestError> {
+        let Ok(tag) = StructTag::from_str(resource_type) else {
+            return Ok(None);
+        };
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .resources
+            .get(&(address, tag.to_string()))
+            .map(|data| Resource {
+                resource_type: tag,
+                data: data.clone(),
+            }))
+    }
+
+    async fn get_account_module(
+        &self,
+        address: AccountAddress,
+        module_name: &str,
+    ) -> Result<MoveModuleBytecode, RestError> {
+        let state = self.state.lock().unwrap();
+        let Some(abi) = state.modules.get(&(address, module_name.to_string())) else {
+            return Err(RestError::Unknown(anyhow!(
+                "module {}::{} is not served by the mock transport",
+                address.to_hex_literal(),
+                module_name
+            )));
+        };
+        Ok(serde_json::from_value(
+            json!({ "bytecode": "0x", "abi": abi }),
+        )?)
+    }
+
+    async fn view(
+        &self,
+        request: &ViewRequest,
+        version: Option<u64>,
+    ) -> Result<ViewResponse, RestError> {
+        let key = serde_json::to_string(request)?;
+        let mut state = self.state.lock().unwrap();
+        // Responses do not depend on the version, but report the one they were asked at.
+        let ledger_version = version.unwrap_or(state.version);
+        let responses = state.views.get_mut(&key).ok_or_else(|| {
+            RestError::Unknown(anyhow!("no response queued for view request {}", key))
+        })?;
+        let values = if responses.len() > 1 {
+            responses.pop_front().unwrap_or_default()
+        } else {
+            responses.front().cloned().unwrap_or_default()
+        };
+        Ok(ViewResponse {
+            values,
+            ledger_version,
+        })
+    }
+
+    async fn estimate_gas_price(&self) -> Result<u64, RestError> {
+        Ok(MOCK_GAS_UNIT_PRICE)
+    }
+
+    async fn simulate(&self, tx: &SignedTransaction) -> Result<Vec<UserTransaction>, RestError> {
+        let state = self.state.lock().unwrap();
+        // Simulations follow the outcome of the next submitted transaction, without using it.
+        let transaction = match state.outcomes.front() {
+            Some(MockOutcome::Committed(events)) => {
+                user_transaction_json(tx, state.version + 1, events, None)
+            }
+            Some(MockOutcome::Failed(vm_status)) => {
+                user_transaction_json(tx, state.version + 1, &[], Some(vm_status))
+            }
+            _ => user_transaction_json(tx, state.version + 1, &[], None),
+        }?;
+        Ok(vec![serde_json::from_value(transaction)?])
+    }
+
+    async fn submit(&self, tx: &SignedTransaction) -> Result<PendingTransaction, RestError> {
+        let mut state = self.state.lock().unwrap();
+        let outcome = state
+            .outcomes
+            .pop_front()
+            .unwrap_or(MockOutcome::Committed(vec![]));
+        state.version += 1;
+        let version = state.version;
+        let hash = tx.clone().committed_hash();
+        let (transaction, committed) = match outcome {
+            MockOutcome::Committed(events) => {
+                (user_transaction_json(tx, version, &events, None)?, true)
+            }
+            MockOutcome::Failed(vm_status) => (
+                user_transaction_json(tx, version, &[], Some(vm_status.as_str()))?,
+                true,
+            ),
+            MockOutcome::Rejected(e) => return Err(e),
+            MockOutcome::Unconfirmed { committed } => {
+                state.unconfirmed.insert(hash);
+                (user_transaction_json(tx, version, &[], None)?, committed)
+            }
+        };
+        if committed {
+            let sequence_number = state.sequence_numbers.entry(tx.sender()).or_default();
+            *sequence_number = (*sequence_number).max(tx.sequence_number() + 1);
+        }
+        state.submitted.push(tx.clone());
+        let mut pending = json!({ "hash": hash.to_hex_literal() });
+        for key in PENDING_FIELDS {
+            pending[key] = transaction[key].clone();
+        }
+        state.pending.insert(hash, transaction);
+        Ok(serde_json::from_value(pending)?)
+    }
+
+    async fn wait_for_transaction(
+        &self,
+        pending: &PendingTransaction,
+    ) -> Result<Transaction, RestError> {
+        let hash: HashValue = pending.hash.into();
+        let state = self.state.lock().unwrap();
+        let Some(transaction) = state.pending.get(&hash) else {
+            return Err(RestError::Unknown(anyhow!(
+                "transaction {} was not submitted to the mock transport",
+                pending.hash
+            )));
+        };
+        if state.unconfirmed.contains(&hash) {
+            return Err(RestError::Unknown(anyhow!(
+                "timed out waiting for transaction {}",
+                pending.hash
+            )));
+        }
+        if transaction["success"] == json!(false) {
+            // Mirror the error of the Aptos `Client` for transactions failing execution.
+            return Err(RestError::Unknown(anyhow!(
+                "Transaction committed on chain, but failed execution: {}",
+                transaction["vm_status"].as_str().unwrap_or_default()
+            )));
+        }
+        let mut transaction = transaction.clone();
+        transaction["type"] = json!("user_transaction");
+        Ok(serde_json::from_value(transaction)?)
+    }
+
+    async fn get_events_by_creation_number(
+        &self,
+        address: AccountAddress,
+        creation_number: u64,
+        start: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<VersionedEvent>, RestError> {
+        let state = self.state.lock().unwrap();
+        let events = state
+            .events
+            .get(&(address, creation_number))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let start = start.unwrap_or(0) as usize;
+        let limit = limit.map(usize::from).unwrap_or(usize::MAX);
+        Ok(events.iter().skip(start).take(limit).cloned().collect())
+    }
+}
+
+/// Fields of a user transaction that are part of a pending transaction.
+const PENDING_FIELDS: [&str; 7] = [
+    "sender",
+    "sequence_number",
+    "max_gas_amount",
+    "gas_unit_price",
+    "expiration_timestamp_secs",
+    "payload",
+    "signature",
+];
+
+fn now_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64
+}
+
+fn event_json(
+    address: AccountAddress,
+    creation_number: u64,
+    sequence_number: usize,
+    event_type: &str,
+    data: Value,
+) -> Value {
+    json!({
+        "guid": {
+            "creation_number": creation_number.to_string(),
+            "account_address": address.to_hex_literal(),
+        },
+        "sequence_number": sequence_number.to_string(),
+        "type": event_type,
+        "data": data,
+    })
+}
+
+/// JSON of a transaction committed by a [`MockTransport`], as returned by a node, failing with
+/// `vm_status` if set.
+fn user_transaction_json(
+    tx: &SignedTransaction,
+    version: u64,
+    events: &[(String, Value)],
+    vm_status: Option<&str>,
+) -> Result<Value, RestError> {
+    let type_arguments =
+        |ty_args: &[TypeTag]| -> Vec<String> { ty_args.iter().map(|t| t.to_string()).collect() };
+    let payload = match tx.payload() {
+        TransactionPayload::EntryFunction(entry) => json!({
+            "type": "entry_function_payload",
+            "function": format!(
+                "{}::{}::{}",
+                entry.module().address().to_hex_literal(),
+                entry.module().name(),
+                entry.function()
+            ),
+            "type_arguments": type_arguments(entry.ty_args()),
+            // Arguments cannot be decoded without the ABI of the function.
+            "arguments": entry
+                .args()
+                .iter()
+                .map(|a| format!("0x{}", hex::encode(a)))
+                .collect::<Vec<_>>(),
+        }),
+        TransactionPayload::Script(script) => json!({
+            "type": "script_payload",
+            "code": { "bytecode": format!("0x{}", hex::encode(script.code())) },
+            "type_arguments": type_arguments(script.ty_args()),
+            "arguments": [],
+        }),
+        _ => {
+            return Err(RestError::Unknown(anyhow!(
+                "payload not supported by the mock transport"
+            )))
+        }
+    };
+    let events = events
+        .iter()
+        .enumerate()
+        .map(|(i, (event_type, data))| event_json(tx.sender(), 0, i, event_type, data.clone()))
+        .collect::<Vec<_>>();
+    let zero = HashValue::zero().to_hex_literal();
+    Ok(json!({
+        "version": version.to_string(),
+        "hash": tx.clone().committed_hash().to_hex_literal(),
+        "state_change_hash": zero,
+        "event_root_hash": zero,
+        "state_checkpoint_hash": null,
+        "gas_used": MOCK_GAS_USED.to_string(),
+        "success": vm_status.is_none(),
+        "vm_status": vm_status.unwrap_or("Executed successfully"),
+        "accumulator_root_hash": zero,
+        "changes": [],
+        "sender": tx.sender().to_hex_literal(),
+        "sequence_number": tx.sequence_number().to_string(),
+        "max_gas_amount": tx.max_gas_amount().to_string(),
+        "gas_unit_price": tx.gas_unit_price().to_string(),
+        "expiration_timestamp_secs": tx.expiration_timestamp_secs().to_string(),
+        "payload": payload,
+        "signature": {
+            "type": "ed25519_signature",
+            "public_key": format!("0x{}", "00".repeat(32)),
+            "signature": format!("0x{}", "00".repeat(64)),
+        },
+        "events": events,
+        "timestamp": now_micros().to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{entry, view};
+    use crate::subscription::{EventHandle, EventSubscription, SubscriptionConfig};
+    use crate::tests::{mock_client, ECONIA};
+    use crate::types::events::EconiaEvent;
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn market_info(market_id: u64, lot_size: u64) -> Value {
+        let coin = |type_name: &str| {
+            json!({
+                "package_address": "0x1",
+                "module_name": "coin",
+                "type_name": type_name,
+            })
+        };
+        json!({
+            "market_id": market_id.to_string(),
+            "is_recognized": true,
+            "base_type": coin("Base"),
+            "base_name_generic": "",
+            "quote_type": coin("Quote"),
+            "lot_size": lot_size.to_string(),
+            "tick_size": "1",
+            "min_size": "1",
+            "underwriter_id": "0",
+        })
+    }
+
+    #[tokio::test]
+    async fn match_views_on_arguments() {
+        let (transport, client) = mock_client().await;
+        let request = |market_id| view::registry::get_market_info(econia(), market_id).unwrap();
+        transport
+            .push_view(&request(1), vec![market_info(1, 10)])
+            .unwrap();
+        transport
+            .push_view(&request(2), vec![market_info(2, 20)])
+            .unwrap();
+        transport
+            .push_view(&request(2), vec![market_info(2, 30)])
+            .unwrap();
+
+        let view_client = client.view_client();
+        let market = view_client.get_market_info(1).await.unwrap();
+        assert_eq!((market.market_id, market.lot_size), (1, 10));
+        assert_eq!(market.base_type.type_name, "Base");
+        // Responses are returned in order, the last one for every later call.
+        for lot_size in [20, 30, 30] {
+            let market = view_client.get_market_info(2).await.unwrap();
+            assert_eq!((market.market_id, market.lot_size), (2, lot_size));
+        }
+        assert!(view_client.get_market_info(3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn submit_and_decode_events() {
+        let (transport, client) = mock_client().await;
+        transport.push_outcome(MockOutcome::Committed(vec![(
+            "0xc0deb00c::user::CancelOrderEvent".to_string(),
+            json!({
+                "market_id": "1",
+                "order_id": "42",
+                "user": "0xa11ce",
+                "custodian_id": "0",
+                "reason": 3,
+            }),
+        )]));
+        transport.push_outcome(MockOutcome::Failed(
+            "Move abort in 0xc0deb00c::market: 0x1c".to_string(),
+        ));
+
+        let cancel = entry::market::cancel_order_user(econia(), 1, false, 42).unwrap();
+        let transaction = client.submit_tx(cancel.clone()).await.unwrap();
+        match transaction.events.as_slice() {
+            [EconiaEvent::CancelOrder(e)] => assert_eq!((e.market_id, e.order_id), (1, 42)),
+            events => panic!("unexpected events {:?}", events),
+        }
+        assert!(client.submit_tx(cancel.clone()).await.is_err());
+
+        assert_eq!(
+            transport.submitted_entry_functions(),
+            [cancel.clone(), cancel]
+        );
+        assert_eq!(client.get_sequence_number().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn page_events() {
+        let (transport, client) = mock_client().await;
+        let address = client.user_address();
+        for order_id in 0..5 {
+            transport
+                .push_event(
+                    address,
+                    4,
+                    "0xc0deb00c::user::CancelOrderEvent",
+                    json!({
+                        "market_id": "1",
+                        "order_id": order_id.to_string(),
+                        "user": "0xa11ce",
+                        "custodian_id": "0",
+                        "reason": 3,
+                    }),
+                )
+                .unwrap();
+        }
+
+        let page = client
+            .get_events_by_creation_number(4, address, Some(1), Some(2))
+            .await
+            .unwrap();
+        let sequence_numbers = page.iter().map(|e| e.sequence_number.0).collect::<Vec<_>>();
+        assert_eq!(sequence_numbers, [1, 2]);
+
+        let config = SubscriptionConfig {
+            page_limit: 2,
+            ..Default::default()
+        };
+        let handle = EventHandle {
+            address,
+            creation_number: 4,
+        };
+        let mut subscription = EventSubscription::new(&client, vec![handle], Some(config));
+        for len in [2, 2, 1, 0] {
+            assert_eq!(subscription.poll().await.unwrap().len(), len);
+        }
+        assert_eq!(subscription.cursors()[&handle], 5);
+    }
+}
@@ -0,0 +1,654 @@
+//! The [`units`](crate::units) module exposes a [`MarketUnits`] converter between decimal
+//! quantities and the integer units Econia trades in.
+//!
+//! Econia markets are denominated in:
+//!
+//! * Lots, for sizes. A lot is `lot_size` subunits of the base asset.
+//! * Ticks per lot, for prices. A tick is `tick_size` subunits of the quote asset.
+//!
+//! A decimal size of `1.5` on a market whose base coin has 8 decimals and a lot size of `10^5`
+//! subunits is then `1.5 * 10^8 / 10^5 = 1500` lots. Decimal prices are expressed in whole quote
+//! coins per whole base coin.
+//!
+//! Decimal quantities rarely fall on the grid of a market, so every conversion takes a
+//! [`Rounding`] mode. The order builders of [`MarketUnits`] use [`Rounding::Exact`], rejecting
+//! sizes and prices which are not a whole amount of lots and ticks.
+
+use std::str::FromStr;
+
+use aptos_sdk::move_types::account_address::AccountAddress;
+use aptos_sdk::move_types::language_storage::{StructTag, TypeTag};
+use aptos_sdk::types::transaction::EntryFunction;
+use bigdecimal::num_bigint::{BigInt, Sign};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use econia_types::order::{Restriction, SelfMatchBehavior, Side};
+
+use crate::entry;
+use crate::errors::{ConversionError, RegistryError};
+use crate::view::{AssetTypeView, MarketInfoView};
+use crate::{EconiaClient, EconiaResult};
+
+/// Largest exponent, positive or negative, of the decimal quantities [`MarketUnits`] converts.
+///
+/// Keeps a quantity such as `1e-1000000000` from allocating its power of ten.
+pub const MAX_EXPONENT: i64 = 38;
+
+/// How to convert a decimal quantity that does not fall on the grid of a market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round toward zero.
+    Down,
+    /// Round away from zero.
+    Up,
+    /// Round to the nearest unit, half away from zero.
+    Nearest,
+    /// Fail with a [`ConversionError`] unless the quantity falls on the grid.
+    Exact,
+}
+
+/// Converts decimal quantities to the units of a market and back, see the
+/// [module documentation](crate::units).
+#[derive(Debug, Clone)]
+pub struct MarketUnits {
+    pub market_id: u64,
+    /// Aptos `TypeTag` of the base asset, `GenericAsset` for markets with a generic base.
+    pub base_type: TypeTag,
+    pub quote_type: TypeTag,
+    /// Base subunits per lot.
+    pub lot_size: u64,
+    /// Quote subunits per tick.
+    pub tick_size: u64,
+    /// Minimum order size, in lots.
+    pub min_size: u64,
+    pub base_decimals: u8,
+    pub quote_decimals: u8,
+}
+
+impl MarketUnits {
+    /// # Arguments:
+    ///
+    /// * `market` - [`MarketInfoView`] of the market, as returned by `get_market_info`.
+    /// * `base_decimals` - Decimals of the base asset.
+    /// * `quote_decimals` - Decimals of the quote coin.
+    pub fn new(
+        market: &MarketInfoView,
+        base_decimals: u8,
+        quote_decimals: u8,
+    ) -> EconiaResult<Self> {
+        if market.lot_size == 0 {
+            return Err(RegistryError::LotSizeZero.into());
+        }
+        if market.tick_size == 0 {
+            return Err(RegistryError::TickSizeZero.into());
+        }
+        Ok(Self {
+            market_id: market.market_id,
+            base_type: asset_type_tag(&market.base_type)?,
+            quote_type: asset_type_tag(&market.quote_type)?,
+            lot_size: market.lot_size,
+            tick_size: market.tick_size,
+            min_size: market.min_size,
+            base_decimals,
+            quote_decimals,
+        })
+    }
+
+    /// Load the market info of a market and the decimals of its coins.
+    ///
+    /// Generic base assets have no decimals on chain, use [`MarketUnits::new`] for such markets.
+    ///
+    /// # Arguments:
+    ///
+    /// * `client` - [`EconiaClient`] to fetch the market info and coin decimals with.
+    /// * `market_id` - Market ID of the market.
+    pub async fn load(client: &EconiaClient, market_id: u64) -> EconiaResult<Self> {
+        let market = client.view_client().get_market_info(market_id).await?;
+        if !market.base_name_generic.is_empty() {
+            return Err(ConversionError::GenericBaseDecimals.into());
+        }
+        let base_decimals = client
+            .get_coin_decimals(&asset_type_tag(&market.base_type)?)
+            .await?;
+        let quote_decimals = client
+            .get_coin_decimals(&asset_type_tag(&market.quote_type)?)
+            .await?;
+        Self::new(&market, base_decimals, quote_decimals)
+    }
+
+    /// Lot size, in base subunits, of lots of a decimal amount of base coins, e.g. to register a
+    /// market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `lot` - Size of a lot, in base coins. Must be a whole amount of subunits.
+    /// * `base_decimals` - Decimals of the base asset.
+    pub fn lot_size_for(lot: &BigDecimal, base_decimals: u8) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(lot)?;
+        divide(
+            numerator * pow10(base_decimals),
+            denominator,
+            Rounding::Exact,
+            ConversionError::AmountNotOnGrid,
+        )
+    }
+
+    /// Tick size, in quote subunits, of ticks of a decimal amount of quote coins, e.g. to register
+    /// a market.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tick` - Size of a tick, in quote coins. Must be a whole amount of subunits.
+    /// * `quote_decimals` - Decimals of the quote coin.
+    pub fn tick_size_for(tick: &BigDecimal, quote_decimals: u8) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(tick)?;
+        divide(
+            numerator * pow10(quote_decimals),
+            denominator,
+            Rounding::Exact,
+            ConversionError::AmountNotOnGrid,
+        )
+    }
+
+    /// Convert a decimal size, in base coins, to lots.
+    ///
+    /// # Arguments:
+    ///
+    /// * `size` - Size in base coins.
+    /// * `rounding` - [`Rounding`] mode if `size` is not a whole amount of lots.
+    pub fn size_to_lots(&self, size: &BigDecimal, rounding: Rounding) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(size)?;
+        divide(
+            numerator * pow10(self.base_decimals),
+            denominator * self.lot_size,
+            rounding,
+            ConversionError::SizeNotOnGrid,
+        )
+    }
+
+    /// Convert a size in lots to base coins.
+    ///
+    /// # Arguments:
+    ///
+    /// * `lots` - Size in lots.
+    pub fn lots_to_size(&self, lots: u64) -> BigDecimal {
+        let subunits = BigInt::from(lots) * self.lot_size;
+        BigDecimal::new(subunits, self.base_decimals as i64).normalized()
+    }
+
+    /// Convert a decimal price, in quote coins per base coin, to ticks per lot.
+    ///
+    /// # Arguments:
+    ///
+    /// * `price` - Price in quote coins per base coin.
+    /// * `rounding` - [`Rounding`] mode if `price` is not a whole amount of ticks per lot.
+    pub fn price_to_ticks(&self, price: &BigDecimal, rounding: Rounding) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(price)?;
+        divide(
+            numerator * self.lot_size * pow10(self.quote_decimals),
+            denominator * self.tick_size * pow10(self.base_decimals),
+            rounding,
+            ConversionError::PriceNotOnGrid,
+        )
+    }
+
+    /// Convert a price in ticks per lot to quote coins per base coin.
+    ///
+    /// # Arguments:
+    ///
+    /// * `ticks` - Price in ticks per lot.
+    pub fn ticks_to_price(&self, ticks: u64) -> BigDecimal {
+        let numerator = BigInt::from(ticks) * self.tick_size * pow10(self.base_decimals);
+        let denominator = BigInt::from(self.lot_size) * pow10(self.quote_decimals);
+        (BigDecimal::from(numerator) / BigDecimal::from(denominator)).normalized()
+    }
+
+    /// Convert a decimal amount of base coins to subunits, e.g. to deposit them.
+    ///
+    /// # Arguments:
+    ///
+    /// * `amount` - Amount of base coins.
+    /// * `rounding` - [`Rounding`] mode if `amount` has more decimals than the base coin.
+    pub fn base_to_subunits(&self, amount: &BigDecimal, rounding: Rounding) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(amount)?;
+        divide(
+            numerator * pow10(self.base_decimals),
+            denominator,
+            rounding,
+            ConversionError::AmountNotOnGrid,
+        )
+    }
+
+    /// Convert a decimal amount of quote coins to subunits, e.g. to deposit them.
+    ///
+    /// # Arguments:
+    ///
+    /// * `amount` - Amount of quote coins.
+    /// * `rounding` - [`Rounding`] mode if `amount` has more decimals than the quote coin.
+    pub fn quote_to_subunits(&self, amount: &BigDecimal, rounding: Rounding) -> EconiaResult<u64> {
+        let (numerator, denominator) = ratio(amount)?;
+        divide(
+            numerator * pow10(self.quote_decimals),
+            denominator,
+            rounding,
+            ConversionError::AmountNotOnGrid,
+        )
+    }
+
+    /// Convert an amount of base subunits to base coins.
+    pub fn subunits_to_base(&self, subunits: u64) -> BigDecimal {
+        BigDecimal::new(subunits.into(), self.base_decimals as i64).normalized()
+    }
+
+    /// Convert an amount of quote subunits to quote coins.
+    pub fn subunits_to_quote(&self, subunits: u64) -> BigDecimal {
+        BigDecimal::new(subunits.into(), self.quote_decimals as i64).normalized()
+    }
+
+    /// Create the `EntryFunction` for [place_limit_order_user_entry](entry::place_limit_order_user_entry)
+    /// from a decimal size and price.
+    ///
+    /// # Arguments:
+    ///
+    /// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `integrator` - Integrator's `AccountAddress`.
+    /// * `side` - Order [`Side`].
+    /// * `size` - Size of the order, in base coins. Must be a whole amount of lots.
+    /// * `price` - Price of the order, in quote coins per base coin. Must be a whole amount of
+    ///   ticks per lot.
+    /// * `restriction` - The [`Restriction`] of the order.
+    /// * `self_match_behavior` - The [`SelfMatchBehavior`] of the order.
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_limit_order(
+        &self,
+        econia_address: AccountAddress,
+        integrator: &AccountAddress,
+        side: Side,
+        size: &BigDecimal,
+        price: &BigDecimal,
+        restriction: Restriction,
+        self_match_behavior: SelfMatchBehavior,
+    ) -> EconiaResult<EntryFunction> {
+        entry::place_limit_order_user_entry(
+            econia_address,
+            &self.base_type,
+            &self.quote_type,
+            self.market_id,
+            integrator,
+            side,
+            self.size_to_lots(size, Rounding::Exact)?,
+            self.price_to_ticks(price, Rounding::Exact)?,
+            restriction,
+            self_match_behavior,
+        )
+    }
+
+    /// Create the `EntryFunction` for [place_market_order_user_entry](entry::place_market_order_user_entry)
+    /// from a decimal size.
+    ///
+    /// # Arguments:
+    ///
+    /// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `integrator` - Integrator's `AccountAddress`.
+    /// * `side` - Order [`Side`].
+    /// * `size` - Size of the order, in base coins. Must be a whole amount of lots.
+    /// * `self_match_behavior` - The [`SelfMatchBehavior`] of the order.
+    pub fn place_market_order(
+        &self,
+        econia_address: AccountAddress,
+        integrator: &AccountAddress,
+        side: Side,
+        size: &BigDecimal,
+        self_match_behavior: SelfMatchBehavior,
+    ) -> EconiaResult<EntryFunction> {
+        entry::place_market_order_user_entry(
+            econia_address,
+            &self.base_type,
+            &self.quote_type,
+            self.market_id,
+            integrator,
+            side,
+            self.size_to_lots(size, Rounding::Exact)?,
+            self_match_behavior,
+        )
+    }
+
+    /// Create the `EntryFunction` for [change_order_size_user](entry::change_order_size_user)
+    /// from a decimal size.
+    ///
+    /// # Arguments:
+    ///
+    /// * `econia_address` - Aptos `AccountAddress` of the account that holds the econia modules.
+    /// * `side` - Order [`Side`].
+    /// * `market_order_id` - ID of the order to change.
+    /// * `new_size` - New size of the order, in base coins. Must be a whole amount of lots.
+    pub fn change_order_size(
+        &self,
+        econia_address: AccountAddress,
+        side: Side,
+        market_order_id: u128,
+        new_size: &BigDecimal,
+    ) -> EconiaResult<EntryFunction> {
+        entry::change_order_size_user(
+            econia_address,
+            self.market_id,
+            side,
+            market_order_id,
+            self.size_to_lots(new_size, Rounding::Exact)?,
+        )
+    }
+}
+
+/// Build the `TypeTag` of an asset from its [`AssetTypeView`].
+pub fn asset_type_tag(asset: &AssetTypeView) -> EconiaResult<TypeTag> {
+    let tag = StructTag::from_str(&format!(
+        "{}::{}::{}",
+        asset.package_address.to_hex_literal(),
+        asset.module_name,
+        asset.type_name
+    ))?;
+    Ok(TypeTag::Struct(Box::new(tag)))
+}
+
+fn pow10(exponent: u8) -> BigInt {
+    BigInt::from(10u8).pow(exponent as u32)
+}
+
+/// Split a decimal into an integer numerator and denominator.
+fn ratio(value: &BigDecimal) -> EconiaResult<(BigInt, BigInt)> {
+    let (digits, scale) = value.as_bigint_and_exponent();
+    if scale.unsigned_abs() > MAX_EXPONENT as u64 {
+        return Err(ConversionError::ExponentOutOfRange.into());
+    }
+    Ok(if scale >= 0 {
+        (digits, BigInt::from(10u8).pow(scale as u32))
+    } else {
+        (
+            digits * BigInt::from(10u8).pow(-scale as u32),
+            BigInt::from(1u8),
+        )
+    })
+}
+
+fn divide(
+    numerator: BigInt,
+    denominator: BigInt,
+    rounding: Rounding,
+    off_grid: ConversionError,
+) -> EconiaResult<u64> {
+    if numerator.sign() == Sign::Minus {
+        return Err(ConversionError::Negative.into());
+    }
+    let quotient = &numerator / &denominator;
+    let remainder = &numerator % &denominator;
+    let quotient = if remainder.sign() == Sign::NoSign {
+        quotient
+    } else {
+        match rounding {
+            Rounding::Down => quotient,
+            Rounding::Up => quotient + 1u8,
+            Rounding::Nearest if remainder * 2u8 >= denominator => quotient + 1u8,
+            Rounding::Nearest => quotient,
+            Rounding::Exact => return Err(off_grid.into()),
+        }
+    };
+    quotient
+        .to_u64()
+        .ok_or_else(|| ConversionError::Overflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::errors::EconiaError;
+    use crate::generated::view;
+    use crate::tests::{mock_client, ECONIA};
+
+    const BASE_DECIMALS: u8 = 8;
+    const QUOTE_DECIMALS: u8 = 6;
+    const LOT_SIZE: u64 = 100_000;
+    const TICK_SIZE: u64 = 10;
+
+    fn market(base_name_generic: &str, lot_size: u64, tick_size: u64) -> MarketInfoView {
+        let asset = |type_name: &str| AssetTypeView {
+            package_address: AccountAddress::ONE,
+            module_name: "assets".to_string(),
+            type_name: type_name.to_string(),
+        };
+        MarketInfoView {
+            market_id: 1,
+            is_recognized: true,
+            base_type: asset("BC"),
+            base_name_generic: base_name_generic.to_string(),
+            quote_type: asset("QC"),
+            lot_size,
+            tick_size,
+            min_size: 1,
+            underwriter_id: 0,
+        }
+    }
+
+    fn units() -> MarketUnits {
+        MarketUnits::new(
+            &market("", LOT_SIZE, TICK_SIZE),
+            BASE_DECIMALS,
+            QUOTE_DECIMALS,
+        )
+        .unwrap()
+    }
+
+    fn decimal(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let units = units();
+        // 1.5 * 10^8 / 10^5 lots.
+        assert_eq!(
+            units
+                .size_to_lots(&decimal("1.5"), Rounding::Exact)
+                .unwrap(),
+            1500
+        );
+        assert_eq!(units.lots_to_size(1500), decimal("1.5"));
+        // 12.34 * 10^5 * 10^6 / (10 * 10^8) ticks per lot.
+        assert_eq!(
+            units
+                .price_to_ticks(&decimal("12.34"), Rounding::Exact)
+                .unwrap(),
+            1234
+        );
+        assert_eq!(units.ticks_to_price(1234), decimal("12.34"));
+        assert_eq!(
+            units
+                .base_to_subunits(&decimal("0.00000001"), Rounding::Exact)
+                .unwrap(),
+            1
+        );
+        assert_eq!(units.subunits_to_base(1), decimal("0.00000001"));
+        assert_eq!(
+            units
+                .quote_to_subunits(&decimal("2.5"), Rounding::Exact)
+                .unwrap(),
+            2_500_000
+        );
+        assert_eq!(units.subunits_to_quote(2_500_000), decimal("2.5"));
+        for lots in [0, 1, 7, 1_000_000] {
+            let size = units.lots_to_size(lots);
+            assert_eq!(units.size_to_lots(&size, Rounding::Exact).unwrap(), lots);
+        }
+        for ticks in [1, 3, 999, 1_000_000] {
+            let price = units.ticks_to_price(ticks);
+            assert_eq!(
+                units.price_to_ticks(&price, Rounding::Exact).unwrap(),
+                ticks
+            );
+        }
+    }
+
+    #[test]
+    fn rounding_modes() {
+        let units = units();
+        let lots = |size: &str, rounding| units.size_to_lots(&decimal(size), rounding).unwrap();
+        // 1.50049 and 1.5005 base coins are 1500.49 and 1500.5 lots.
+        assert_eq!(lots("1.50049", Rounding::Down), 1500);
+        assert_eq!(lots("1.50049", Rounding::Up), 1501);
+        assert_eq!(lots("1.50049", Rounding::Nearest), 1500);
+        assert_eq!(lots("1.5005", Rounding::Nearest), 1501);
+        assert_eq!(lots("1.5", Rounding::Up), 1500);
+
+        let ticks =
+            |price: &str, rounding| units.price_to_ticks(&decimal(price), rounding).unwrap();
+        // 12.3449 and 12.3451 quote coins per base coin are 1234.49 and 1234.51 ticks per lot.
+        assert_eq!(ticks("12.3451", Rounding::Down), 1234);
+        assert_eq!(ticks("12.3449", Rounding::Up), 1235);
+        assert_eq!(ticks("12.3449", Rounding::Nearest), 1234);
+        assert_eq!(ticks("12.3451", Rounding::Nearest), 1235);
+    }
+
+    #[test]
+    fn reject_off_grid() {
+        let units = units();
+        assert!(matches!(
+            units.size_to_lots(&decimal("1.50049"), Rounding::Exact),
+            Err(EconiaError::ConversionError(ConversionError::SizeNotOnGrid))
+        ));
+        assert!(matches!(
+            units.price_to_ticks(&decimal("12.3451"), Rounding::Exact),
+            Err(EconiaError::ConversionError(
+                ConversionError::PriceNotOnGrid
+            ))
+        ));
+        assert!(matches!(
+            units.quote_to_subunits(&decimal("0.0000001"), Rounding::Exact),
+            Err(EconiaError::ConversionError(
+                ConversionError::AmountNotOnGrid
+            ))
+        ));
+        assert!(matches!(
+            MarketUnits::lot_size_for(&decimal("0.000000001"), BASE_DECIMALS),
+            Err(EconiaError::ConversionError(
+                ConversionError::AmountNotOnGrid
+            ))
+        ));
+        assert!(matches!(
+            units.size_to_lots(&decimal("-1.5"), Rounding::Down),
+            Err(EconiaError::ConversionError(ConversionError::Negative))
+        ));
+        assert!(matches!(
+            units.size_to_lots(&decimal("1e30"), Rounding::Exact),
+            Err(EconiaError::ConversionError(ConversionError::Overflow))
+        ));
+        for extreme in ["1e-1000000000", "1e1000000000"] {
+            assert!(matches!(
+                units.size_to_lots(&decimal(extreme), Rounding::Down),
+                Err(EconiaError::ConversionError(
+                    ConversionError::ExponentOutOfRange
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    fn decimals_mismatch() {
+        // A lot of 10^5 subunits is 0.001 base coins with 8 decimals, 0.1 with 6, so that the
+        // same price is a hundred times as many quote coins per lot.
+        let price = decimal("12.34");
+        assert_eq!(
+            units().price_to_ticks(&price, Rounding::Exact).unwrap(),
+            1234
+        );
+        let same_decimals = MarketUnits::new(&market("", LOT_SIZE, TICK_SIZE), 6, 6).unwrap();
+        assert_eq!(
+            same_decimals
+                .price_to_ticks(&price, Rounding::Exact)
+                .unwrap(),
+            123_400
+        );
+        // Quote coins with 8 decimals have a hundred times as many subunits as with 6.
+        let swapped_decimals = MarketUnits::new(&market("", LOT_SIZE, TICK_SIZE), 6, 8).unwrap();
+        assert_eq!(
+            swapped_decimals
+                .price_to_ticks(&price, Rounding::Exact)
+                .unwrap(),
+            12_340_000
+        );
+        assert_eq!(swapped_decimals.ticks_to_price(12_340_000), price);
+        assert_eq!(same_decimals.lots_to_size(1), decimal("0.1"));
+        assert_eq!(units().lots_to_size(1), decimal("0.001"));
+    }
+
+    #[test]
+    fn reject_zero_lot_or_tick_size() {
+        assert!(matches!(
+            MarketUnits::new(&market("", 0, TICK_SIZE), BASE_DECIMALS, QUOTE_DECIMALS),
+            Err(EconiaError::RegistryError(RegistryError::LotSizeZero))
+        ));
+        assert!(matches!(
+            MarketUnits::new(&market("", LOT_SIZE, 0), BASE_DECIMALS, QUOTE_DECIMALS),
+            Err(EconiaError::RegistryError(RegistryError::TickSizeZero))
+        ));
+    }
+
+    #[tokio::test]
+    async fn load_decimals_of_both_coins() {
+        let (transport, client) = mock_client().await;
+        let econia = AccountAddress::from_hex_literal(ECONIA).unwrap();
+        let asset = |type_name: &str| {
+            json!({
+                "package_address": "0x1",
+                "module_name": "assets",
+                "type_name": type_name,
+            })
+        };
+        // Market 1 trades coins, market 2 a generic base asset.
+        for (market_id, base_name_generic) in [(1u64, ""), (2, "Index")] {
+            transport
+                .push_view(
+                    &view::registry::get_market_info(econia, market_id).unwrap(),
+                    vec![json!({
+                        "market_id": market_id.to_string(),
+                        "is_recognized": true,
+                        "base_type": asset("BC"),
+                        "base_name_generic": base_name_generic,
+                        "quote_type": asset("QC"),
+                        "lot_size": LOT_SIZE.to_string(),
+                        "tick_size": TICK_SIZE.to_string(),
+                        "min_size": "1",
+                        "underwriter_id": "0",
+                    })],
+                )
+                .unwrap();
+        }
+        for (coin, decimals) in [("BC", BASE_DECIMALS), ("QC", QUOTE_DECIMALS)] {
+            transport
+                .set_resource(
+                    AccountAddress::ONE,
+                    &format!("0x1::coin::CoinInfo<0x1::assets::{}>", coin),
+                    json!({ "decimals": decimals }),
+                )
+                .unwrap();
+        }
+
+        let loaded = MarketUnits::load(&client, 1).await.unwrap();
+        assert_eq!(
+            (loaded.base_decimals, loaded.quote_decimals),
+            (BASE_DECIMALS, QUOTE_DECIMALS)
+        );
+        assert_eq!(
+            loaded
+                .price_to_ticks(&decimal("12.34"), Rounding::Exact)
+                .unwrap(),
+            1234
+        );
+        // Generic base assets have no decimals on chain.
+        assert!(matches!(
+            MarketUnits::load(&client, 2).await,
+            Err(EconiaError::ConversionError(
+                ConversionError::GenericBaseDecimals
+            ))
+        ));
+    }
+}
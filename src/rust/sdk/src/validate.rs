@@ -0,0 +1,568 @@
+//! The [`validate`](crate::validate) module exposes an [`OrderValidator`] which checks orders
+//! against the parameters of a market before they are submitted.
+//!
+//! The validator replays the assertions the Econia `market` and `user` modules run before
+//! matching, in the same order, and returns the same typed error the transaction would abort
+//! with. Checks that need data the validator was not given are skipped:
+//!
+//! * Without a [`MarketAccountView`], balances and ceilings are not checked.
+//! * Without [`PriceLevels`], whether a limit order crosses the spread is unknown. The order is
+//!   then checked as if it posted entirely, and fill-or-abort and post-or-abort restrictions are
+//!   not checked.
+//!
+//! Assertions that depend on the outcome of matching, e.g. self matches or minimum trade amounts,
+//! are not checked either.
+
+use econia_types::order::{Restriction, Side, HI_64, HI_PRICE, MAX_POSSIBLE};
+
+use crate::errors::{MarketError, UserError};
+use crate::view::{MarketAccountView, MarketInfoView, PriceLevels};
+use crate::EconiaResult;
+
+/// Validates orders against a market, see the [module documentation](crate::validate).
+#[derive(Debug, Clone, Copy)]
+pub struct OrderValidator<'a> {
+    market: &'a MarketInfoView,
+    account: Option<&'a MarketAccountView>,
+    price_levels: Option<&'a PriceLevels>,
+}
+
+impl<'a> OrderValidator<'a> {
+    /// # Arguments:
+    ///
+    /// * `market` - [`MarketInfoView`] of the market, as returned by `get_market_info`.
+    /// * `account` - [`MarketAccountView`] of the market account placing the orders, if `None`
+    ///   balances and ceilings are not checked.
+    /// * `price_levels` - Current [`PriceLevels`] of the market, if `None` limit orders are
+    ///   checked as if they do not cross the spread.
+    pub fn new(
+        market: &'a MarketInfoView,
+        account: Option<&'a MarketAccountView>,
+        price_levels: Option<&'a PriceLevels>,
+    ) -> Self {
+        Self {
+            market,
+            account,
+            price_levels,
+        }
+    }
+
+    /// Whether a limit order would cross the spread, `None` if no price levels were given.
+    ///
+    /// # Arguments:
+    ///
+    /// * `side` - Order [`Side`].
+    /// * `price` - Price of the order, in ticks per lot.
+    pub fn crosses_spread(&self, side: Side, price: u64) -> Option<bool> {
+        let levels = self.price_levels?;
+        Some(match side {
+            Side::Ask => levels.bids.iter().map(|l| l.price).max() >= Some(price),
+            Side::Bid => levels
+                .asks
+                .iter()
+                .map(|l| l.price)
+                .min()
+                .is_some_and(|best_ask| price >= best_ask),
+        })
+    }
+
+    /// Check a limit order, as placed by `place_limit_order_user_entry`.
+    ///
+    /// # Arguments:
+    ///
+    /// * `side` - Order [`Side`].
+    /// * `size` - Size of the order in lots.
+    /// * `price` - Price of the order, in ticks per lot.
+    /// * `restriction` - The [`Restriction`] of the order.
+    pub fn validate_limit_order(
+        &self,
+        side: Side,
+        size: u64,
+        price: u64,
+        restriction: Restriction,
+    ) -> EconiaResult<()> {
+        if price == 0 {
+            return Err(MarketError::PriceZero.into());
+        }
+        if price > HI_PRICE {
+            return Err(MarketError::PriceTooHigh.into());
+        }
+        if size < self.market.min_size {
+            return Err(MarketError::SizeTooSmall.into());
+        }
+        let crosses_spread = self.crosses_spread(side, price);
+        if restriction == Restriction::FillOrAbort && crosses_spread == Some(false) {
+            return Err(MarketError::FillOrAbortNotCrossSpread.into());
+        }
+        if restriction == Restriction::PostOrAbort && crosses_spread == Some(true) {
+            return Err(MarketError::PostOrAbortCrossesSpread.into());
+        }
+        let base = size as u128 * self.market.lot_size as u128;
+        if base > HI_64 as u128 {
+            return Err(MarketError::SizeBaseOverflow.into());
+        }
+        let ticks = size as u128 * price as u128;
+        if ticks > HI_64 as u128 {
+            return Err(MarketError::SizePriceTicksOverflow.into());
+        }
+        let quote = ticks * self.market.tick_size as u128;
+        if quote > HI_64 as u128 {
+            return Err(MarketError::SizePriceQuoteOverflow.into());
+        }
+
+        let Some(account) = self.account else {
+            return Ok(());
+        };
+        let crosses_spread = crosses_spread.unwrap_or(false);
+        let max_base = base as u64;
+        let min_base = if restriction == Restriction::FillOrAbort {
+            max_base
+        } else {
+            0
+        };
+        let max_quote = match (crosses_spread, side) {
+            // A taker sell receives at least the quote from size and price.
+            (true, Side::Ask) => HI_64 - account.quote_ceiling,
+            // A taker buy pays at most the quote from size and price, plus fees.
+            (true, Side::Bid) => account.quote_available,
+            (false, _) => quote as u64,
+        };
+        range_check_trade(
+            side,
+            min_base,
+            max_base,
+            0,
+            max_quote,
+            account.base_available,
+            account.base_ceiling,
+            account.quote_available,
+            account.quote_ceiling,
+        )?;
+
+        // A maker order reserves the assets needed to fill it.
+        if !crosses_spread {
+            let (in_ceiling, out_available, in_fill, out_fill) = match side {
+                Side::Ask => (account.quote_ceiling, account.base_available, quote, base),
+                Side::Bid => (account.base_ceiling, account.quote_available, base, quote),
+            };
+            if in_fill + in_ceiling as u128 > HI_64 as u128 {
+                return Err(UserError::OverflowAssetIn.into());
+            }
+            if out_fill > out_available as u128 {
+                return Err(UserError::NotEnoughAssetOut.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a market order, as placed by `place_market_order_user_entry`.
+    ///
+    /// # Arguments:
+    ///
+    /// * `direction` - Order direction, [`Side::Ask`] to sell and [`Side::Bid`] to buy.
+    /// * `size` - Size of the order in lots.
+    pub fn validate_market_order(&self, direction: Side, size: u64) -> EconiaResult<()> {
+        if size < self.market.min_size {
+            return Err(MarketError::SizeTooSmall.into());
+        }
+        let base = size as u128 * self.market.lot_size as u128;
+        if base > HI_64 as u128 {
+            return Err(MarketError::SizeBaseOverflow.into());
+        }
+
+        let Some(account) = self.account else {
+            return Ok(());
+        };
+        let max_quote = match direction {
+            Side::Ask => HI_64 - account.quote_ceiling,
+            Side::Bid => account.quote_available,
+        };
+        range_check_trade(
+            direction,
+            0,
+            base as u64,
+            0,
+            max_quote,
+            account.base_available,
+            account.base_ceiling,
+            account.quote_available,
+            account.quote_ceiling,
+        )?;
+        Ok(())
+    }
+
+    /// Check a swap between coin stores, as placed by `swap_between_coinstores_entry`.
+    ///
+    /// Swaps do not use a market account, the coin store balances of the swapper are used
+    /// instead.
+    ///
+    /// # Arguments:
+    ///
+    /// * `direction` - Swap direction, [`Side::Ask`] to sell and [`Side::Bid`] to buy.
+    /// * `min_base` - Minimum base to trade, in subunits.
+    /// * `max_base` - Maximum base to trade, in subunits, or `MAX_POSSIBLE`.
+    /// * `min_quote` - Minimum quote to trade, in subunits.
+    /// * `max_quote` - Maximum quote to trade, in subunits, or `MAX_POSSIBLE`.
+    /// * `limit_price` - Limit price of the swap, in ticks per lot.
+    /// * `base_balance` - Base coin store balance of the swapper.
+    /// * `quote_balance` - Quote coin store balance of the swapper.
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_swap(
+        &self,
+        direction: Side,
+        min_base: u64,
+        max_base: u64,
+        min_quote: u64,
+        max_quote: u64,
+        limit_price: u64,
+        base_balance: u64,
+        quote_balance: u64,
+    ) -> EconiaResult<()> {
+        let max_base = match (max_base, direction) {
+            (MAX_POSSIBLE, Side::Bid) => HI_64 - base_balance,
+            (MAX_POSSIBLE, Side::Ask) => base_balance,
+            (max_base, _) => max_base,
+        };
+        let max_quote = match (max_quote, direction) {
+            (MAX_POSSIBLE, Side::Bid) => quote_balance,
+            (MAX_POSSIBLE, Side::Ask) => HI_64 - quote_balance,
+            (max_quote, _) => max_quote,
+        };
+        range_check_trade(
+            direction,
+            min_base,
+            max_base,
+            min_quote,
+            max_quote,
+            base_balance,
+            base_balance,
+            quote_balance,
+            quote_balance,
+        )?;
+        if limit_price > HI_PRICE {
+            return Err(MarketError::PriceTooHigh.into());
+        }
+        Ok(())
+    }
+}
+
+/// Mirror of `range_check_trade` in the `market` module.
+#[allow(clippy::too_many_arguments)]
+fn range_check_trade(
+    direction: Side,
+    min_base: u64,
+    max_base: u64,
+    min_quote: u64,
+    max_quote: u64,
+    base_available: u64,
+    base_ceiling: u64,
+    quote_available: u64,
+    quote_ceiling: u64,
+) -> Result<(), MarketError> {
+    if max_base == 0 {
+        return Err(MarketError::MaxBaseZero);
+    }
+    if max_quote == 0 {
+        return Err(MarketError::MaxQuoteZero);
+    }
+    if min_base > max_base {
+        return Err(MarketError::MinBaseExceedsMax);
+    }
+    if min_quote > max_quote {
+        return Err(MarketError::MinQuoteExceedsMax);
+    }
+    let (in_ceiling, in_max, out_available, out_max) = match direction {
+        Side::Bid => (base_ceiling, max_base, quote_available, max_quote),
+        Side::Ask => (quote_ceiling, max_quote, base_available, max_base),
+    };
+    if in_ceiling as u128 + in_max as u128 > HI_64 as u128 {
+        return Err(MarketError::OverflowAssetIn);
+    }
+    if out_max > out_available {
+        return Err(MarketError::NotEnoughAssetOut);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aptos_sdk::move_types::account_address::AccountAddress;
+
+    use super::*;
+    use crate::errors::EconiaError;
+    use crate::view::{AssetTypeView, PriceLevel};
+
+    // Parameters of the `market` module tests.
+    const LOT_SIZE_COIN: u64 = 2;
+    const TICK_SIZE_COIN: u64 = 3;
+    const MIN_SIZE_COIN: u64 = 4;
+
+    fn market() -> MarketInfoView {
+        let asset = |type_name: &str| AssetTypeView {
+            package_address: AccountAddress::ONE,
+            module_name: "assets".to_string(),
+            type_name: type_name.to_string(),
+        };
+        MarketInfoView {
+            market_id: 1,
+            is_recognized: true,
+            base_type: asset("BC"),
+            base_name_generic: String::new(),
+            quote_type: asset("QC"),
+            lot_size: LOT_SIZE_COIN,
+            tick_size: TICK_SIZE_COIN,
+            min_size: MIN_SIZE_COIN,
+            underwriter_id: 0,
+        }
+    }
+
+    fn account(
+        base_available: u64,
+        base_ceiling: u64,
+        quote_available: u64,
+        quote_ceiling: u64,
+    ) -> MarketAccountView {
+        MarketAccountView {
+            market_id: 1,
+            custodian_id: 0,
+            asks: Vec::new(),
+            bids: Vec::new(),
+            base_total: base_available,
+            base_available,
+            base_ceiling,
+            quote_total: quote_available,
+            quote_available,
+            quote_ceiling,
+        }
+    }
+
+    /// Price levels with a single ask and bid level, of one lot each.
+    fn levels(ask: Option<u64>, bid: Option<u64>) -> PriceLevels {
+        let level = |price| PriceLevel { price, size: 1 };
+        PriceLevels {
+            market_id: 1,
+            asks: ask.into_iter().map(level).collect(),
+            bids: bid.into_iter().map(level).collect(),
+        }
+    }
+
+    fn market_error(result: EconiaResult<()>) -> MarketError {
+        match result {
+            Err(EconiaError::MarketError(error)) => error,
+            result => panic!("expected a market error, got {:?}", result),
+        }
+    }
+
+    /// `user::test_place_order_internal_price_0`, `market::test_place_limit_order_price_hi`,
+    /// `market::test_place_limit_order_size_lo` and
+    /// `market::test_place_market_order_size_too_small`.
+    #[test]
+    fn reject_price_and_size() {
+        let market = market();
+        let validator = OrderValidator::new(&market, None, None);
+        let limit = |size, price| {
+            market_error(validator.validate_limit_order(
+                Side::Ask,
+                size,
+                price,
+                Restriction::NoRestriction,
+            ))
+        };
+        assert_eq!(limit(MIN_SIZE_COIN, 0), MarketError::PriceZero);
+        assert_eq!(limit(123, HI_PRICE + 1), MarketError::PriceTooHigh);
+        assert_eq!(limit(MIN_SIZE_COIN - 1, 1), MarketError::SizeTooSmall);
+        assert_eq!(
+            market_error(validator.validate_market_order(Side::Bid, MIN_SIZE_COIN - 1)),
+            MarketError::SizeTooSmall
+        );
+        assert!(validator
+            .validate_limit_order(
+                Side::Ask,
+                MIN_SIZE_COIN,
+                HI_PRICE,
+                Restriction::NoRestriction
+            )
+            .is_ok());
+    }
+
+    /// `market::test_place_limit_order_base_overflow`,
+    /// `market::test_place_limit_order_ticks_overflow`,
+    /// `market::test_place_limit_order_quote_overflow` and
+    /// `market::test_place_market_order_size_base_overflow`.
+    #[test]
+    fn reject_overflows() {
+        let market = market();
+        let validator = OrderValidator::new(&market, None, None);
+        let limit = |size, price| {
+            market_error(validator.validate_limit_order(
+                Side::Ask,
+                size,
+                price,
+                Restriction::NoRestriction,
+            ))
+        };
+        assert_eq!(
+            limit(HI_64 / LOT_SIZE_COIN + 1, HI_PRICE),
+            MarketError::SizeBaseOverflow
+        );
+        let size = HI_64 / LOT_SIZE_COIN;
+        assert_eq!(
+            limit(size, HI_64 / size + 1),
+            MarketError::SizePriceTicksOverflow
+        );
+        assert_eq!(
+            limit(size, HI_64 / size),
+            MarketError::SizePriceQuoteOverflow
+        );
+        assert_eq!(
+            market_error(validator.validate_market_order(Side::Ask, HI_64 / LOT_SIZE_COIN + 1)),
+            MarketError::SizeBaseOverflow
+        );
+    }
+
+    /// `market::test_place_limit_order_post_or_abort_crosses` and
+    /// `market::test_place_limit_order_fill_or_abort_not_cross`.
+    #[test]
+    fn check_restrictions_against_the_spread() {
+        let market = market();
+        let book = levels(Some(800), Some(789));
+        let validator = OrderValidator::new(&market, None, Some(&book));
+        assert_eq!(validator.crosses_spread(Side::Ask, 789), Some(true));
+        assert_eq!(validator.crosses_spread(Side::Ask, 790), Some(false));
+        assert_eq!(validator.crosses_spread(Side::Bid, 800), Some(true));
+        assert_eq!(validator.crosses_spread(Side::Bid, 799), Some(false));
+        assert_eq!(
+            market_error(validator.validate_limit_order(
+                Side::Ask,
+                MIN_SIZE_COIN,
+                789,
+                Restriction::PostOrAbort
+            )),
+            MarketError::PostOrAbortCrossesSpread
+        );
+        assert!(validator
+            .validate_limit_order(Side::Ask, MIN_SIZE_COIN, 789, Restriction::FillOrAbort)
+            .is_ok());
+
+        let empty = levels(None, None);
+        let validator = OrderValidator::new(&market, None, Some(&empty));
+        assert_eq!(
+            market_error(validator.validate_limit_order(
+                Side::Ask,
+                MIN_SIZE_COIN,
+                HI_PRICE,
+                Restriction::FillOrAbort
+            )),
+            MarketError::FillOrAbortNotCrossSpread
+        );
+        assert!(validator
+            .validate_limit_order(Side::Ask, MIN_SIZE_COIN, HI_PRICE, Restriction::PostOrAbort)
+            .is_ok());
+
+        // Without price levels, restrictions that depend on the spread are not checked.
+        let validator = OrderValidator::new(&market, None, None);
+        assert_eq!(validator.crosses_spread(Side::Ask, 789), None);
+        for restriction in [Restriction::FillOrAbort, Restriction::PostOrAbort] {
+            assert!(validator
+                .validate_limit_order(Side::Ask, MIN_SIZE_COIN, 789, restriction)
+                .is_ok());
+        }
+    }
+
+    /// `market::test_range_check_trade_asset_out_sell`,
+    /// `market::test_range_check_trade_asset_out_buy`,
+    /// `market::test_range_check_trade_asset_in_sell` and
+    /// `market::test_range_check_trade_asset_in_buy`, through orders which post entirely.
+    #[test]
+    fn check_balances_and_ceilings() {
+        let market = market();
+        let size = MIN_SIZE_COIN;
+        let price = 789;
+        let base = size * LOT_SIZE_COIN;
+        let quote = size * price * TICK_SIZE_COIN;
+        let check = |account: &MarketAccountView, side| {
+            OrderValidator::new(&market, Some(account), None).validate_limit_order(
+                side,
+                size,
+                price,
+                Restriction::NoRestriction,
+            )
+        };
+
+        // Exactly enough asset out, and room for exactly as much asset in.
+        assert!(check(&account(base, base, 0, HI_64 - quote), Side::Ask).is_ok());
+        assert!(check(&account(0, HI_64 - base, quote, quote), Side::Bid).is_ok());
+
+        assert_eq!(
+            market_error(check(&account(base - 1, base - 1, 0, 0), Side::Ask)),
+            MarketError::NotEnoughAssetOut
+        );
+        assert_eq!(
+            market_error(check(&account(0, 0, quote - 1, quote - 1), Side::Bid)),
+            MarketError::NotEnoughAssetOut
+        );
+        // The quote ceiling of an ask, and the base ceiling of a bid, would overflow once filled.
+        assert_eq!(
+            market_error(check(&account(base, base, 0, HI_64 - quote + 1), Side::Ask)),
+            MarketError::OverflowAssetIn
+        );
+        assert_eq!(
+            market_error(check(
+                &account(0, HI_64 - base + 1, quote, quote),
+                Side::Bid
+            )),
+            MarketError::OverflowAssetIn
+        );
+
+        // A market sell needs the base it sells, a market buy room for the base it buys.
+        let validator = OrderValidator::new(&market, None, None);
+        assert!(validator.validate_market_order(Side::Ask, size).is_ok());
+        let account = account(base - 1, base - 1, HI_64, HI_64 - 1);
+        let validator = OrderValidator::new(&market, Some(&account), None);
+        assert_eq!(
+            market_error(validator.validate_market_order(Side::Ask, size)),
+            MarketError::NotEnoughAssetOut
+        );
+    }
+
+    /// `market::test_range_check_trade_base_0`,
+    /// `market::test_range_check_trade_min_base_exceeds_max`,
+    /// `market::test_range_check_trade_min_quote_exceeds_max` and
+    /// `market::test_swap_between_coinstores_max_possible_base_sell`.
+    #[test]
+    fn check_swaps() {
+        let market = market();
+        let validator = OrderValidator::new(&market, None, None);
+        let swap = |direction, min_base, max_base, min_quote, max_quote, base, quote| {
+            validator.validate_swap(
+                direction, min_base, max_base, min_quote, max_quote, HI_PRICE, base, quote,
+            )
+        };
+
+        assert!(swap(Side::Ask, 0, MAX_POSSIBLE, 0, MAX_POSSIBLE, 100, 0).is_ok());
+        // Selling as much base as possible without any base.
+        assert_eq!(
+            market_error(swap(Side::Ask, 0, MAX_POSSIBLE, 0, MAX_POSSIBLE, 0, 0)),
+            MarketError::MaxBaseZero
+        );
+        assert_eq!(
+            market_error(swap(Side::Ask, 2, 1, 0, 1, 100, 0)),
+            MarketError::MinBaseExceedsMax
+        );
+        assert_eq!(
+            market_error(swap(Side::Bid, 0, 1, 2, 1, 0, 100)),
+            MarketError::MinQuoteExceedsMax
+        );
+        assert_eq!(
+            market_error(swap(Side::Bid, 0, 1, 0, 101, 0, 100)),
+            MarketError::NotEnoughAssetOut
+        );
+        assert_eq!(
+            market_error(validator.validate_swap(Side::Ask, 0, 1, 0, 1, HI_PRICE + 1, 1, 0)),
+            MarketError::PriceTooHigh
+        );
+        // Whether the minimums get traded depends on matching, left to the chain to check, e.g.
+        // in `market::test_match_min_base_not_traded`.
+        assert!(swap(Side::Bid, 100, 100, 0, 100, 0, 100).is_ok());
+    }
+}
@@ -12,19 +12,22 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use aptos_api_types::{IdentifierWrapper, MoveModuleId, MoveType, ViewRequest};
-use aptos_sdk::rest_client::Client;
 use aptos_sdk::types::account_address::AccountAddress;
 use econia_types::order::{Order, Side, SHIFT_MARKET_ID};
 use econia_types::order::{HI_64, HI_PRICE, NIL, SHIFT_COUNTER};
+use futures::Stream;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde::{Deserialize, Deserializer};
-use serde_json::json;
+use serde_json::{json, Value};
 
 use crate::errors::*;
+use crate::transport::AptosTransport;
 use crate::EconiaResult;
 
+#[derive(Clone)]
 pub struct EconiaViewClient<'a> {
-    client: &'a Client,
+    client: &'a dyn AptosTransport,
     econia_address: AccountAddress,
 }
 
@@ -85,6 +88,32 @@ pub struct PriceLevels {
     pub bids: Vec<PriceLevel>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OrdersViewPage {
+    /// Orders indexed in this page.
+    pub orders: OrdersView,
+    /// Order ID of the ask to start the next page from, `NIL` if done indexing asks.
+    pub next_ask_order_id: u128,
+    /// Order ID of the bid to start the next page from, `NIL` if done indexing bids.
+    pub next_bid_order_id: u128,
+    /// Ledger version the page was indexed at.
+    pub ledger_version: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PriceLevelsPage {
+    /// Price levels indexed in this page.
+    ///
+    /// The last level of a side may be continued on the next page.
+    pub levels: PriceLevels,
+    /// Order ID of the ask to start the next page from, `NIL` if done indexing asks.
+    pub next_ask_order_id: u128,
+    /// Order ID of the bid to start the next page from, `NIL` if done indexing bids.
+    pub next_bid_order_id: u128,
+    /// Ledger version the page was indexed at.
+    pub ledger_version: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SwapperEventHandleCreationNumbers {
     #[serde(deserialize_with = "from_str")]
@@ -150,6 +179,66 @@ pub struct MarketAccountView {
     pub quote_ceiling: u64,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeeStoreTierView {
+    /// 0-indexed tier number.
+    pub tier: u8,
+    /// Divisor of the quote fill reserved for integrators activated to this tier.
+    pub fee_share_divisor: u64,
+    /// Cumulative cost, in utility coins, to activate to this tier.
+    pub tier_activation_fee: u64,
+    /// Cost, in utility coins, to withdraw from a fee store activated to this tier.
+    pub withdrawal_fee: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FeeScheduleView {
+    /// Utility coins required to register a market.
+    pub market_registration_fee: u64,
+    /// Utility coins required to register as an underwriter.
+    pub underwriter_registration_fee: u64,
+    /// Utility coins required to register as a custodian.
+    pub custodian_registration_fee: u64,
+    /// Divisor of the quote fill charged to takers.
+    pub taker_fee_divisor: u64,
+    /// Integrator fee store tiers, indexed by tier number.
+    pub integrator_fee_store_tiers: Vec<FeeStoreTierView>,
+}
+
+impl FeeScheduleView {
+    /// Get the parameters of a tier, if it exists.
+    pub fn tier(&self, tier: u8) -> Option<&FeeStoreTierView> {
+        self.integrator_fee_store_tiers.get(tier as usize)
+    }
+
+    /// Compute the taker fee charged on a quote fill, as done by `incentives::assess_taker_fees`.
+    ///
+    /// Returns `None` if the taker fee divisor is 0.
+    pub fn taker_fee(&self, quote_fill: u64) -> Option<u64> {
+        quote_fill.checked_div(self.taker_fee_divisor)
+    }
+
+    /// Compute the share of the taker fee an integrator activated to `tier` gets on a quote fill.
+    ///
+    /// Returns `None` if the tier does not exist or if its fee share divisor is 0.
+    pub fn integrator_fee_share(&self, tier: u8, quote_fill: u64) -> Option<u64> {
+        quote_fill.checked_div(self.tier(tier)?.fee_share_divisor)
+    }
+
+    /// Compute the cost, in utility coins, to upgrade a fee store from `current_tier` to
+    /// `new_tier`, as done by `incentives::get_cost_to_upgrade_integrator_fee_store`.
+    ///
+    /// Returns `None` if either tier does not exist or if it would not be an upgrade.
+    pub fn cost_to_upgrade(&self, current_tier: u8, new_tier: u8) -> Option<u64> {
+        if new_tier <= current_tier {
+            return None;
+        }
+        let current_fee = self.tier(current_tier)?.tier_activation_fee;
+        let new_fee = self.tier(new_tier)?.tier_activation_fee;
+        new_fee.checked_sub(current_fee).filter(|cost| *cost > 0)
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MarketEventHandleCreationNumbers {
     #[serde(deserialize_with = "from_str")]
@@ -165,13 +254,343 @@ pub struct MarketEventHandleCreationNumbers {
 }
 
 impl<'a> EconiaViewClient<'a> {
-    pub fn new(client: &'a Client, econia_address: AccountAddress) -> Self {
+    pub fn new(client: &'a dyn AptosTransport, econia_address: AccountAddress) -> Self {
         Self {
             client,
             econia_address,
         }
     }
 
+    /// Call a view function from a request built by the
+    /// [`generated::view`](crate::generated::view) module.
+    ///
+    /// The value returned by the function is deserialized into `T`, or the array of values if it
+    /// returns several. Integers wider than 32 bits are returned as strings, deserialize them with
+    /// `aptos_api_types::U64` and `aptos_api_types::U128`.
+    ///
+    /// Arguments:
+    /// * `request`: the `ViewRequest` to call.
+    pub async fn call<T: DeserializeOwned>(&self, request: &ViewRequest) -> EconiaResult<T> {
+        let mut values = self.client.view(request, None).await?.values;
+        let value = if values.len() == 1 {
+            values.remove(0)
+        } else {
+            Value::Array(values)
+        };
+        Ok(serde_json::from_value(value)?)
+    }
+
+    // Incentives related view functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
+
+    /// Call `get_cost_to_upgrade_integrator_fee_store_view` view function.
+    ///
+    /// Arguments:
+    /// * `quote_type`: the quote coin type of the market.
+    /// * `utility_type`: the utility coin type.
+    /// * `integrator`: the address of the integrator owning the fee store.
+    /// * `market_id`: the id of the market of the fee store.
+    /// * `new_tier`: the tier to upgrade to.
+    pub async fn get_cost_to_upgrade_integrator_fee_store_view(
+        &self,
+        quote_type: MoveType,
+        utility_type: MoveType,
+        integrator: AccountAddress,
+        market_id: u64,
+        new_tier: u8,
+    ) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name =
+            IdentifierWrapper::from_str("get_cost_to_upgrade_integrator_fee_store_view").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![quote_type, utility_type],
+                    arguments: vec![
+                        json!(integrator.to_string()),
+                        json!(market_id.to_string()),
+                        json!(new_tier),
+                    ],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_custodian_registration_fee` view function.
+    pub async fn get_custodian_registration_fee(&self) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_custodian_registration_fee").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_fee_share_divisor` view function.
+    ///
+    /// Arguments:
+    /// * `tier`: the integrator fee store tier.
+    pub async fn get_fee_share_divisor(&self, tier: u8) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_fee_share_divisor").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![json!(tier)],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_integrator_withdrawal_fee_view` view function.
+    ///
+    /// Arguments:
+    /// * `quote_type`: the quote coin type of the market.
+    /// * `integrator`: the address of the integrator owning the fee store.
+    /// * `market_id`: the id of the market of the fee store.
+    pub async fn get_integrator_withdrawal_fee_view(
+        &self,
+        quote_type: MoveType,
+        integrator: AccountAddress,
+        market_id: u64,
+    ) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_integrator_withdrawal_fee_view").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![quote_type],
+                    arguments: vec![json!(integrator.to_string()), json!(market_id.to_string())],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_market_registration_fee` view function.
+    pub async fn get_market_registration_fee(&self) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_market_registration_fee").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_n_fee_store_tiers` view function.
+    pub async fn get_n_fee_store_tiers(&self) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_n_fee_store_tiers").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_taker_fee_divisor` view function.
+    pub async fn get_taker_fee_divisor(&self) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_taker_fee_divisor").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_tier_activation_fee` view function.
+    ///
+    /// Arguments:
+    /// * `tier`: the integrator fee store tier.
+    pub async fn get_tier_activation_fee(&self, tier: u8) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_tier_activation_fee").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![json!(tier)],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_tier_withdrawal_fee` view function.
+    ///
+    /// Arguments:
+    /// * `tier`: the integrator fee store tier.
+    pub async fn get_tier_withdrawal_fee(&self, tier: u8) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_tier_withdrawal_fee").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![json!(tier)],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `get_underwriter_registration_fee` view function.
+    pub async fn get_underwriter_registration_fee(&self) -> EconiaResult<u64> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_underwriter_registration_fee").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        parse_u64(response.values.get(0))
+    }
+
+    /// Call `is_utility_coin_type` view function.
+    ///
+    /// Arguments:
+    /// * `coin_type`: the coin type to check.
+    pub async fn is_utility_coin_type(&self, coin_type: MoveType) -> EconiaResult<bool> {
+        let managed_coin = format!("{}::incentives", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("is_utility_coin_type").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![coin_type],
+                    arguments: vec![],
+                },
+                None,
+            )
+            .await?;
+        let value = response
+            .values
+            .get(0)
+            .ok_or(EconiaError::InvalidResponse)?
+            .as_bool()
+            .ok_or(EconiaError::InvalidResponse)?;
+        Ok(value)
+    }
+
+    /// Get the full fee schedule by calling the incentives view functions.
+    ///
+    /// This issues one view call per incentive parameter and three per integrator fee store tier.
+    pub async fn get_fee_schedule(&self) -> EconiaResult<FeeScheduleView> {
+        let (
+            market_registration_fee,
+            underwriter_registration_fee,
+            custodian_registration_fee,
+            taker_fee_divisor,
+            n_fee_store_tiers,
+        ) = futures::try_join!(
+            self.get_market_registration_fee(),
+            self.get_underwriter_registration_fee(),
+            self.get_custodian_registration_fee(),
+            self.get_taker_fee_divisor(),
+            self.get_n_fee_store_tiers(),
+        )?;
+        let tiers = (0..n_fee_store_tiers).map(|tier| async move {
+            let tier = u8::try_from(tier).map_err(|_| EconiaError::InvalidResponse)?;
+            let (fee_share_divisor, tier_activation_fee, withdrawal_fee) = futures::try_join!(
+                self.get_fee_share_divisor(tier),
+                self.get_tier_activation_fee(tier),
+                self.get_tier_withdrawal_fee(tier),
+            )?;
+            Ok::<_, EconiaError>(FeeStoreTierView {
+                tier,
+                fee_share_divisor,
+                tier_activation_fee,
+                withdrawal_fee,
+            })
+        });
+        Ok(FeeScheduleView {
+            market_registration_fee,
+            underwriter_registration_fee,
+            custodian_registration_fee,
+            taker_fee_divisor,
+            integrator_fee_store_tiers: futures::future::try_join_all(tiers).await?,
+        })
+    }
+
+    // Incentives related view functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
+
     // Market related view functions >>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>
 
     /// Call `did_order_post` view function.
@@ -207,11 +626,7 @@ impl<'a> EconiaViewClient<'a> {
                 None,
             )
             .await?;
-        let data = response
-            .inner()
-            .get(0)
-            .ok_or(EconiaError::InvalidResponse)?["vec"]
-            .get(0);
+        let data = response.values.get(0).ok_or(EconiaError::InvalidResponse)?["vec"].get(0);
         Ok(match data {
             Some(n) => Some(serde_json::from_value(n.clone())?),
             None => None,
@@ -263,11 +678,7 @@ impl<'a> EconiaViewClient<'a> {
                 None,
             )
             .await?;
-        let data = response
-            .inner()
-            .get(0)
-            .ok_or(EconiaError::InvalidResponse)?["vec"]
-            .get(0);
+        let data = response.values.get(0).ok_or(EconiaError::InvalidResponse)?["vec"].get(0);
         Ok(match data {
             Some(n) => Some(serde_json::from_value(n.clone())?),
             None => None,
@@ -307,7 +718,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: OrdersView = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -337,7 +748,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: OrdersView = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -345,6 +756,98 @@ impl<'a> EconiaViewClient<'a> {
         Ok(value)
     }
 
+    /// Call `get_open_orders_paginated` view function.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the orders from.
+    /// * `n_asks_to_index_max`: the maximum number of asks to index.
+    /// * `n_bids_to_index_max`: the maximum number of bids to index.
+    /// * `starting_ask_order_id`: the order id of the ask to start indexing from, `NIL` to start
+    ///   from the best ask.
+    /// * `starting_bid_order_id`: the order id of the bid to start indexing from, `NIL` to start
+    ///   from the best bid.
+    /// * `ledger_version`: the ledger version to index at, if `None` the latest version is used.
+    ///
+    /// When walking several pages, pass the `ledger_version` of the first page to every following
+    /// call, or use an [`OpenOrdersPaginator`] which does that for you.
+    pub async fn get_open_orders_paginated(
+        &self,
+        market_id: u64,
+        n_asks_to_index_max: u64,
+        n_bids_to_index_max: u64,
+        starting_ask_order_id: u128,
+        starting_bid_order_id: u128,
+        ledger_version: Option<u64>,
+    ) -> EconiaResult<OrdersViewPage> {
+        let managed_coin = format!("{}::market", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_open_orders_paginated").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![
+                        json!(market_id.to_string()),
+                        json!(n_asks_to_index_max.to_string()),
+                        json!(n_bids_to_index_max.to_string()),
+                        json!(starting_ask_order_id.to_string()),
+                        json!(starting_bid_order_id.to_string()),
+                    ],
+                },
+                ledger_version,
+            )
+            .await?;
+        let orders: OrdersView = serde_json::from_value(
+            response
+                .values
+                .get(0)
+                .ok_or(EconiaError::InvalidResponse)?
+                .clone(),
+        )?;
+        Ok(OrdersViewPage {
+            orders,
+            next_ask_order_id: parse_u128(response.values.get(1))?,
+            next_bid_order_id: parse_u128(response.values.get(2))?,
+            ledger_version: response.ledger_version,
+        })
+    }
+
+    /// Create an [`OpenOrdersPaginator`] walking every open order of a market.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the orders from.
+    /// * `n_asks_per_page`: the maximum number of asks to index per page.
+    /// * `n_bids_per_page`: the maximum number of bids to index per page.
+    pub fn open_orders_paginator(
+        &self,
+        market_id: u64,
+        n_asks_per_page: u64,
+        n_bids_per_page: u64,
+    ) -> OpenOrdersPaginator<'a> {
+        OpenOrdersPaginator::new(self.clone(), market_id, n_asks_per_page, n_bids_per_page)
+    }
+
+    /// Get all open orders of a market by walking `get_open_orders_paginated`.
+    ///
+    /// Unlike [`get_open_orders_all`](Self::get_open_orders_all), this does not fail on deep
+    /// order books. All pages are indexed at the same ledger version.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the orders from.
+    /// * `page_size`: the maximum number of orders per side to index per page.
+    pub async fn get_open_orders_all_paginated(
+        &self,
+        market_id: u64,
+        page_size: u64,
+    ) -> EconiaResult<OrdersView> {
+        self.open_orders_paginator(market_id, page_size, page_size)
+            .collect_all()
+            .await
+    }
+
     /// Call `get_posted_order_id_side` view function.
     ///
     /// Arguments:
@@ -392,7 +895,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: PriceLevels = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -422,7 +925,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: PriceLevels = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -430,6 +933,99 @@ impl<'a> EconiaViewClient<'a> {
         Ok(value)
     }
 
+    /// Call `get_price_levels_paginated` view function.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the price levels from.
+    /// * `n_asks_to_index_max`: the maximum number of asks to index.
+    /// * `n_bids_to_index_max`: the maximum number of bids to index.
+    /// * `starting_ask_order_id`: the order id of the ask to start indexing from, `NIL` to start
+    ///   from the best ask.
+    /// * `starting_bid_order_id`: the order id of the bid to start indexing from, `NIL` to start
+    ///   from the best bid.
+    /// * `ledger_version`: the ledger version to index at, if `None` the latest version is used.
+    ///
+    /// Note that the page size is counted in orders, not in price levels. When walking several
+    /// pages, pass the `ledger_version` of the first page to every following call, or use a
+    /// [`PriceLevelsPaginator`] which does that for you.
+    pub async fn get_price_levels_paginated(
+        &self,
+        market_id: u64,
+        n_asks_to_index_max: u64,
+        n_bids_to_index_max: u64,
+        starting_ask_order_id: u128,
+        starting_bid_order_id: u128,
+        ledger_version: Option<u64>,
+    ) -> EconiaResult<PriceLevelsPage> {
+        let managed_coin = format!("{}::market", self.econia_address);
+        let module = MoveModuleId::from_str(&managed_coin)
+            .map_err(|a| EconiaError::InvalidModuleId(a.to_string()))?;
+        let name = IdentifierWrapper::from_str("get_price_levels_paginated").unwrap();
+        let response = self
+            .client
+            .view(
+                &ViewRequest {
+                    function: aptos_api_types::EntryFunctionId { module, name },
+                    type_arguments: vec![],
+                    arguments: vec![
+                        json!(market_id.to_string()),
+                        json!(n_asks_to_index_max.to_string()),
+                        json!(n_bids_to_index_max.to_string()),
+                        json!(starting_ask_order_id.to_string()),
+                        json!(starting_bid_order_id.to_string()),
+                    ],
+                },
+                ledger_version,
+            )
+            .await?;
+        let levels: PriceLevels = serde_json::from_value(
+            response
+                .values
+                .get(0)
+                .ok_or(EconiaError::InvalidResponse)?
+                .clone(),
+        )?;
+        Ok(PriceLevelsPage {
+            levels,
+            next_ask_order_id: parse_u128(response.values.get(1))?,
+            next_bid_order_id: parse_u128(response.values.get(2))?,
+            ledger_version: response.ledger_version,
+        })
+    }
+
+    /// Create a [`PriceLevelsPaginator`] walking every price level of a market.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the price levels from.
+    /// * `n_asks_per_page`: the maximum number of asks to index per page.
+    /// * `n_bids_per_page`: the maximum number of bids to index per page.
+    pub fn price_levels_paginator(
+        &self,
+        market_id: u64,
+        n_asks_per_page: u64,
+        n_bids_per_page: u64,
+    ) -> PriceLevelsPaginator<'a> {
+        PriceLevelsPaginator::new(self.clone(), market_id, n_asks_per_page, n_bids_per_page)
+    }
+
+    /// Get all price levels of a market by walking `get_price_levels_paginated`.
+    ///
+    /// Unlike [`get_price_levels_all`](Self::get_price_levels_all), this does not fail on deep
+    /// order books. All pages are indexed at the same ledger version.
+    ///
+    /// Arguments:
+    /// * `market_id`: the id of the market to get the price levels from.
+    /// * `page_size`: the maximum number of orders per side to index per page.
+    pub async fn get_price_levels_all_paginated(
+        &self,
+        market_id: u64,
+        page_size: u64,
+    ) -> EconiaResult<PriceLevels> {
+        self.price_levels_paginator(market_id, page_size, page_size)
+            .collect_all()
+            .await
+    }
+
     /// Call `get_swapper_event_handle_creation_numbers` view function.
     pub async fn get_swapper_event_handle_creation_numbers(
         &self,
@@ -454,7 +1050,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: SwapperEventHandleCreationNumbers = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -480,7 +1076,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -511,7 +1107,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: MarketCounts = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -547,11 +1143,7 @@ impl<'a> EconiaViewClient<'a> {
                 None,
             )
             .await?;
-        let data = response
-            .inner()
-            .get(0)
-            .ok_or(EconiaError::InvalidResponse)?["vec"]
-            .get(0);
+        let data = response.values.get(0).ok_or(EconiaError::InvalidResponse)?["vec"].get(0);
         Ok(match data {
             Some(n) => Some(n.as_str().unwrap().parse().unwrap()),
             None => None,
@@ -589,11 +1181,7 @@ impl<'a> EconiaViewClient<'a> {
                 None,
             )
             .await?;
-        let data = response
-            .inner()
-            .get(0)
-            .ok_or(EconiaError::InvalidResponse)?["vec"]
-            .get(0);
+        let data = response.values.get(0).ok_or(EconiaError::InvalidResponse)?["vec"].get(0);
         Ok(match data {
             Some(n) => Some(n.as_str().unwrap().parse().unwrap()),
             None => None,
@@ -619,7 +1207,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: MarketInfoView = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -650,7 +1238,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: u64 = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -681,7 +1269,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: u64 = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -711,7 +1299,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -742,7 +1330,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -781,7 +1369,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: Vec<u128> = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -814,7 +1402,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: Vec<u128> = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -860,7 +1448,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: MarketAccountView = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -900,7 +1488,7 @@ impl<'a> EconiaViewClient<'a> {
             .await?;
         let value: Vec<MarketAccountView> = serde_json::from_value(
             response
-                .inner()
+                .values
                 .get(0)
                 .ok_or(EconiaError::InvalidResponse)?
                 .clone(),
@@ -934,11 +1522,7 @@ impl<'a> EconiaViewClient<'a> {
                 None,
             )
             .await?;
-        let data = response
-            .inner()
-            .get(0)
-            .ok_or(EconiaError::InvalidResponse)?["vec"]
-            .get(0);
+        let data = response.values.get(0).ok_or(EconiaError::InvalidResponse)?["vec"].get(0);
         Ok(match data {
             Some(n) => Some(serde_json::from_value(n.clone())?),
             None => None,
@@ -987,7 +1571,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -1024,7 +1608,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -1058,7 +1642,7 @@ impl<'a> EconiaViewClient<'a> {
             )
             .await?;
         let value = response
-            .inner()
+            .values
             .get(0)
             .ok_or(EconiaError::InvalidResponse)?
             .as_bool()
@@ -1069,6 +1653,238 @@ impl<'a> EconiaViewClient<'a> {
     // User related view functions <<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<<
 }
 
+/// Walks the pages of the `get_open_orders_paginated` view function.
+///
+/// The paginator keeps the cursor of each side and pins every page to the ledger version of the
+/// first one, so that the pages form a consistent snapshot of the order book.
+pub struct OpenOrdersPaginator<'a> {
+    view_client: EconiaViewClient<'a>,
+    market_id: u64,
+    n_asks_per_page: u64,
+    n_bids_per_page: u64,
+    next_ask_order_id: u128,
+    next_bid_order_id: u128,
+    asks_done: bool,
+    bids_done: bool,
+    ledger_version: Option<u64>,
+}
+
+impl<'a> OpenOrdersPaginator<'a> {
+    pub fn new(
+        view_client: EconiaViewClient<'a>,
+        market_id: u64,
+        n_asks_per_page: u64,
+        n_bids_per_page: u64,
+    ) -> Self {
+        Self {
+            view_client,
+            market_id,
+            n_asks_per_page,
+            n_bids_per_page,
+            next_ask_order_id: NIL as u128,
+            next_bid_order_id: NIL as u128,
+            asks_done: n_asks_per_page == 0,
+            bids_done: n_bids_per_page == 0,
+            ledger_version: None,
+        }
+    }
+
+    /// Ledger version the pages are indexed at, `None` until the first page is fetched.
+    pub fn ledger_version(&self) -> Option<u64> {
+        self.ledger_version
+    }
+
+    /// Whether every page has been fetched.
+    pub fn is_done(&self) -> bool {
+        self.asks_done && self.bids_done
+    }
+
+    /// Fetch the next page, returning `None` once every page has been fetched.
+    pub async fn next_page(&mut self) -> EconiaResult<Option<OrdersView>> {
+        if self.is_done() {
+            return Ok(None);
+        }
+        // A side that is done must not be indexed again, as a `NIL` starting order id would
+        // restart it from the best order.
+        let page = self
+            .view_client
+            .get_open_orders_paginated(
+                self.market_id,
+                if self.asks_done {
+                    0
+                } else {
+                    self.n_asks_per_page
+                },
+                if self.bids_done {
+                    0
+                } else {
+                    self.n_bids_per_page
+                },
+                self.next_ask_order_id,
+                self.next_bid_order_id,
+                self.ledger_version,
+            )
+            .await?;
+        self.ledger_version = Some(page.ledger_version);
+        self.next_ask_order_id = page.next_ask_order_id;
+        self.next_bid_order_id = page.next_bid_order_id;
+        self.asks_done |= page.next_ask_order_id == NIL as u128;
+        self.bids_done |= page.next_bid_order_id == NIL as u128;
+        Ok(Some(page.orders))
+    }
+
+    /// Fetch every remaining page and merge them into a single [`OrdersView`].
+    pub async fn collect_all(mut self) -> EconiaResult<OrdersView> {
+        let mut orders = OrdersView {
+            asks: vec![],
+            bids: vec![],
+        };
+        while let Some(page) = self.next_page().await? {
+            orders.asks.extend(page.asks);
+            orders.bids.extend(page.bids);
+        }
+        Ok(orders)
+    }
+
+    /// Turn the paginator into a [`Stream`] of pages.
+    pub fn into_stream(self) -> impl Stream<Item = EconiaResult<OrdersView>> + 'a {
+        futures::stream::try_unfold(self, |mut paginator| async move {
+            Ok(paginator.next_page().await?.map(|page| (page, paginator)))
+        })
+    }
+}
+
+/// Walks the pages of the `get_price_levels_paginated` view function.
+///
+/// The paginator keeps the cursor of each side and pins every page to the ledger version of the
+/// first one, so that the pages form a consistent snapshot of the order book.
+pub struct PriceLevelsPaginator<'a> {
+    view_client: EconiaViewClient<'a>,
+    market_id: u64,
+    n_asks_per_page: u64,
+    n_bids_per_page: u64,
+    next_ask_order_id: u128,
+    next_bid_order_id: u128,
+    asks_done: bool,
+    bids_done: bool,
+    ledger_version: Option<u64>,
+}
+
+impl<'a> PriceLevelsPaginator<'a> {
+    pub fn new(
+        view_client: EconiaViewClient<'a>,
+        market_id: u64,
+        n_asks_per_page: u64,
+        n_bids_per_page: u64,
+    ) -> Self {
+        Self {
+            view_client,
+            market_id,
+            n_asks_per_page,
+            n_bids_per_page,
+            next_ask_order_id: NIL as u128,
+            next_bid_order_id: NIL as u128,
+            asks_done: n_asks_per_page == 0,
+            bids_done: n_bids_per_page == 0,
+            ledger_version: None,
+        }
+    }
+
+    /// Ledger version the pages are indexed at, `None` until the first page is fetched.
+    pub fn ledger_version(&self) -> Option<u64> {
+        self.ledger_version
+    }
+
+    /// Whether every page has been fetched.
+    pub fn is_done(&self) -> bool {
+        self.asks_done && self.bids_done
+    }
+
+    /// Fetch the next page, returning `None` once every page has been fetched.
+    ///
+    /// The last price level of a page may be continued on the next one, use
+    /// [`collect_all`](Self::collect_all) to get merged price levels.
+    pub async fn next_page(&mut self) -> EconiaResult<Option<PriceLevels>> {
+        if self.is_done() {
+            return Ok(None);
+        }
+        // A side that is done must not be indexed again, as a `NIL` starting order id would
+        // restart it from the best order.
+        let page = self
+            .view_client
+            .get_price_levels_paginated(
+                self.market_id,
+                if self.asks_done {
+                    0
+                } else {
+                    self.n_asks_per_page
+                },
+                if self.bids_done {
+                    0
+                } else {
+                    self.n_bids_per_page
+                },
+                self.next_ask_order_id,
+                self.next_bid_order_id,
+                self.ledger_version,
+            )
+            .await?;
+        self.ledger_version = Some(page.ledger_version);
+        self.next_ask_order_id = page.next_ask_order_id;
+        self.next_bid_order_id = page.next_bid_order_id;
+        self.asks_done |= page.next_ask_order_id == NIL as u128;
+        self.bids_done |= page.next_bid_order_id == NIL as u128;
+        Ok(Some(page.levels))
+    }
+
+    /// Fetch every remaining page and merge them into a single [`PriceLevels`].
+    pub async fn collect_all(mut self) -> EconiaResult<PriceLevels> {
+        let mut levels = PriceLevels {
+            market_id: self.market_id,
+            asks: vec![],
+            bids: vec![],
+        };
+        while let Some(page) = self.next_page().await? {
+            merge_price_levels(&mut levels.asks, page.asks);
+            merge_price_levels(&mut levels.bids, page.bids);
+        }
+        Ok(levels)
+    }
+
+    /// Turn the paginator into a [`Stream`] of pages.
+    pub fn into_stream(self) -> impl Stream<Item = EconiaResult<PriceLevels>> + 'a {
+        futures::stream::try_unfold(self, |mut paginator| async move {
+            Ok(paginator.next_page().await?.map(|page| (page, paginator)))
+        })
+    }
+}
+
+/// Append the levels of a page, merging a level split across the page boundary.
+fn merge_price_levels(levels: &mut Vec<PriceLevel>, page: Vec<PriceLevel>) {
+    let mut page = page.into_iter();
+    if let (Some(last), Some(first)) = (levels.last_mut(), page.as_slice().first()) {
+        if last.price == first.price {
+            last.size += first.size;
+            page.next();
+        }
+    }
+    levels.extend(page);
+}
+
+fn parse_u64(value: Option<&Value>) -> EconiaResult<u64> {
+    value
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse().ok())
+        .ok_or(EconiaError::InvalidResponse)
+}
+
+fn parse_u128(value: Option<&Value>) -> EconiaResult<u128> {
+    value
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse().ok())
+        .ok_or(EconiaError::InvalidResponse)
+}
+
 /// Call `did_order_post` view function.
 ///
 /// This will not actually reach out to the blockchain. It will compute the result locally as it
@@ -1138,3 +1954,164 @@ pub fn get_market_account_id(market_id: u64, custodian_id: u64) -> u128 {
 pub fn get_market_id(market_account_id: u128) -> u64 {
     (market_account_id >> SHIFT_MARKET_ID) as u64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::view;
+    use crate::tests::{mock_client, ECONIA};
+    use crate::transport::MockTransport;
+
+    fn econia() -> AccountAddress {
+        AccountAddress::from_hex_literal(ECONIA).unwrap()
+    }
+
+    fn order(side: Side, order_id: u128, price: u64) -> Value {
+        json!({
+            "market_id": "1",
+            "side": side,
+            "order_id": order_id.to_string(),
+            "remaining_size": "5",
+            "price": price.to_string(),
+            "user": "0xa11ce",
+            "custodian_id": "0",
+        })
+    }
+
+    fn level(price: u64, size: u128) -> Value {
+        json!({ "price": price.to_string(), "size": size.to_string() })
+    }
+
+    /// Bump the ledger version of the mock, as if a transaction committed between two pages.
+    fn commit_something(transport: &MockTransport) {
+        transport
+            .push_event(econia(), 0, "0x1::account::CoinRegisterEvent", json!({}))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn walk_open_orders_pages() {
+        let (transport, client) = mock_client().await;
+        let nil = NIL as u128;
+        let page = |n_asks, n_bids, ask, bid| {
+            view::market::get_open_orders_paginated(econia(), 1, n_asks, n_bids, ask, bid).unwrap()
+        };
+        transport
+            .push_view(
+                &page(2, 2, nil, nil),
+                vec![
+                    json!({
+                        "asks": [order(Side::Ask, 11, 100), order(Side::Ask, 12, 101)],
+                        "bids": [order(Side::Bid, 21, 99)],
+                    }),
+                    json!("13"),
+                    json!(nil.to_string()),
+                ],
+            )
+            .unwrap();
+        // Bids are done after the first page, so they are not indexed again from the best bid.
+        transport
+            .push_view(
+                &page(2, 0, 13, nil),
+                vec![
+                    json!({ "asks": [order(Side::Ask, 13, 102)], "bids": [] }),
+                    json!(nil.to_string()),
+                    json!(nil.to_string()),
+                ],
+            )
+            .unwrap();
+
+        let view_client = client.view_client();
+        let mut paginator = view_client.open_orders_paginator(1, 2, 2);
+        assert_eq!(paginator.ledger_version(), None);
+        let first = paginator.next_page().await.unwrap().unwrap();
+        assert_eq!((first.asks.len(), first.bids.len()), (2, 1));
+        let pinned = paginator.ledger_version().unwrap();
+        assert!(!paginator.is_done());
+
+        commit_something(&transport);
+        let second = paginator.next_page().await.unwrap().unwrap();
+        assert_eq!(second.asks[0].order_id, 13);
+        // The second page is indexed at the version of the first, not at the new latest one.
+        assert_eq!(paginator.ledger_version(), Some(pinned));
+        assert!(paginator.is_done());
+        assert!(paginator.next_page().await.unwrap().is_none());
+
+        let orders = view_client
+            .get_open_orders_all_paginated(1, 2)
+            .await
+            .unwrap();
+        let ids = |orders: &[OrderView]| orders.iter().map(|o| o.order_id).collect::<Vec<_>>();
+        assert_eq!(ids(&orders.asks), vec![11, 12, 13]);
+        assert_eq!(ids(&orders.bids), vec![21]);
+    }
+
+    #[tokio::test]
+    async fn walk_and_merge_price_levels_pages() {
+        let (transport, client) = mock_client().await;
+        let nil = NIL as u128;
+        let page = |n_asks, n_bids, ask, bid| {
+            view::market::get_price_levels_paginated(econia(), 1, n_asks, n_bids, ask, bid).unwrap()
+        };
+        let levels = |asks: Vec<Value>, bids: Vec<Value>| json!({ "market_id": "1", "asks": asks, "bids": bids });
+        transport
+            .push_view(
+                &page(2, 2, nil, nil),
+                vec![
+                    levels(
+                        vec![level(100, 3), level(101, 4)],
+                        vec![level(99, 2), level(98, 1)],
+                    ),
+                    json!("13"),
+                    json!("23"),
+                ],
+            )
+            .unwrap();
+        // The price level of 101 and the one of 98 are split across the page boundary.
+        transport
+            .push_view(
+                &page(2, 2, 13, 23),
+                vec![
+                    levels(vec![level(101, 6)], vec![level(98, 5), level(97, 1)]),
+                    json!(nil.to_string()),
+                    json!("25"),
+                ],
+            )
+            .unwrap();
+        transport
+            .push_view(
+                &page(0, 2, nil, 25),
+                vec![
+                    levels(vec![], vec![level(96, 7)]),
+                    json!(nil.to_string()),
+                    json!(nil.to_string()),
+                ],
+            )
+            .unwrap();
+
+        let mut paginator = client.view_client().price_levels_paginator(1, 2, 2);
+        paginator.next_page().await.unwrap();
+        let pinned = paginator.ledger_version().unwrap();
+        commit_something(&transport);
+        let mut pages = 1;
+        while paginator.next_page().await.unwrap().is_some() {
+            pages += 1;
+            assert_eq!(paginator.ledger_version(), Some(pinned));
+        }
+        assert_eq!(pages, 3);
+
+        let merged = client
+            .view_client()
+            .get_price_levels_all_paginated(1, 2)
+            .await
+            .unwrap();
+        let sizes =
+            |levels: &[PriceLevel]| levels.iter().map(|l| (l.price, l.size)).collect::<Vec<_>>();
+        assert_eq!(merged.market_id, 1);
+        assert_eq!(sizes(&merged.asks), vec![(100, 3), (101, 10)]);
+        assert_eq!(
+            sizes(&merged.bids),
+            vec![(99, 2), (98, 6), (97, 1), (96, 7)]
+        );
+    }
+}
@@ -0,0 +1,6 @@
+// Generated by the Econia SDK from a batch of entry functions, called in order.
+script {
+    fun main{{type_parameters}}(user: &signer{{parameters}}) {
+{{calls}}
+    }
+}
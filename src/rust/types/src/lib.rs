@@ -1,3 +1,4 @@
 pub mod error;
 pub mod events;
+pub mod notif;
 pub mod order;
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::order::Side;
+
+// Payloads published by the `mqtt-publisher` of the DSS. Event payloads are the rows of the
+// matching DSS tables, as notified by PostgreSQL.
+
+/// Payload of the `place_limit_order/MARKET_ID/USER_ADDRESS/CUSTODIAN_ID/INTEGRATOR` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaceLimitOrderNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub time: DateTime<Utc>,
+    pub market_id: u128,
+    pub user: String,
+    pub custodian_id: u128,
+    pub order_id: u128,
+    pub side: Side,
+    pub integrator: String,
+    pub initial_size: u128,
+    pub price: u128,
+    pub restriction: i16,
+    pub self_match_behavior: i16,
+    pub size: u128,
+}
+
+/// Payload of the `place_market_order/MARKET_ID/USER_ADDRESS/CUSTODIAN_ID/INTEGRATOR` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaceMarketOrderNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub time: DateTime<Utc>,
+    pub market_id: u128,
+    pub user: String,
+    pub custodian_id: u128,
+    pub order_id: u128,
+    pub direction: Side,
+    pub integrator: String,
+    pub self_match_behavior: i16,
+    pub size: u128,
+}
+
+/// Payload of the `place_swap_order/MARKET_ID/INTEGRATOR/SIGNING_ACCOUNT` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlaceSwapOrderNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub time: DateTime<Utc>,
+    pub market_id: u128,
+    pub order_id: u128,
+    pub direction: Side,
+    pub signing_account: String,
+    pub integrator: String,
+    pub min_base: u128,
+    pub max_base: u128,
+    pub min_quote: u128,
+    pub max_quote: u128,
+    pub limit_price: u128,
+}
+
+/// Payload of the `change_order_size/MARKET_ID/USER_ADDRESS/CUSTODIAN_ID` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChangeOrderSizeNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub time: DateTime<Utc>,
+    pub market_id: u128,
+    pub user: String,
+    pub custodian_id: u128,
+    pub order_id: u128,
+    pub side: Side,
+    pub new_size: u128,
+}
+
+/// Payload of the `cancel_order/MARKET_ID/USER_ADDRESS/CUSTODIAN_ID` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CancelOrderNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub time: DateTime<Utc>,
+    pub market_id: u128,
+    pub user: String,
+    pub custodian_id: u128,
+    pub order_id: u128,
+    pub reason: i16,
+}
+
+/// Payload of the `fill/MARKET_ID/USER_ADDRESS/CUSTODIAN_ID` topic, published once for the maker
+/// and once for the taker.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FillNotif {
+    pub txn_version: u128,
+    pub event_idx: u128,
+    pub emit_address: String,
+    pub time: DateTime<Utc>,
+    pub maker_address: String,
+    pub maker_custodian_id: u128,
+    pub maker_order_id: u128,
+    pub maker_side: Side,
+    pub market_id: u128,
+    pub price: u128,
+    pub sequence_number_for_trade: u128,
+    pub size: u128,
+    pub taker_address: String,
+    pub taker_custodian_id: u128,
+    pub taker_order_id: u128,
+    pub taker_quote_fees_paid: u128,
+}
+
+/// Payload of the `levels/MARKET_ID/DIRECTION/LEVEL` topic.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PriceLevelNotif {
+    pub price: u128,
+    pub size: u128,
+    /// Last transaction version indexed when the level was computed.
+    pub txn_version: u128,
+}